@@ -205,4 +205,22 @@ fn print_config_summary(config: &Config) {
         "  indexer.max_file_size       = {}",
         config.indexer.max_file_size
     );
+    println!(
+        "  indexer.max_key_depth       = {}",
+        config.indexer.max_key_depth
+    );
+    println!(
+        "  indexer.queries.dir         = {}",
+        config.indexer.queries.dir
+    );
+    println!(
+        "  indexer.queries.languages   = {:?}",
+        config
+            .indexer
+            .queries
+            .languages
+            .iter()
+            .map(|l| &l.name)
+            .collect::<Vec<_>>()
+    );
 }
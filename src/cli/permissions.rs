@@ -3,30 +3,34 @@ use serde_json::Value;
 use std::fs;
 use std::path::Path;
 
-pub const TOOL_PERMISSIONS: [&str; 10] = [
+pub const TOOL_PERMISSIONS: [&str; 12] = [
     "mcp__ctxhelpr__index_repository",
     "mcp__ctxhelpr__get_overview",
     "mcp__ctxhelpr__get_file_symbols",
     "mcp__ctxhelpr__get_symbol_detail",
     "mcp__ctxhelpr__search_symbols",
+    "mcp__ctxhelpr__find_symbols",
     "mcp__ctxhelpr__get_references",
     "mcp__ctxhelpr__get_dependencies",
+    "mcp__ctxhelpr__get_uncertain_refs",
     "mcp__ctxhelpr__index_status",
     "mcp__ctxhelpr__list_repos",
     "mcp__ctxhelpr__delete_repos",
 ];
 
-pub const TOOL_LABELS: [&str; 10] = [
-    "index_repository  - Full index/re-index",
-    "get_overview      - High-level repo structure",
-    "get_file_symbols  - All symbols in a file",
-    "get_symbol_detail - Full symbol details",
-    "search_symbols    - Full-text search",
-    "get_references    - Who references a symbol",
-    "get_dependencies  - What a symbol depends on",
-    "index_status      - Check index freshness",
-    "list_repos        - List all indexed repositories",
-    "delete_repos      - Delete repository index data",
+pub const TOOL_LABELS: [&str; 12] = [
+    "index_repository   - Full index/re-index",
+    "get_overview       - High-level repo structure",
+    "get_file_symbols   - All symbols in a file",
+    "get_symbol_detail  - Full symbol details",
+    "search_symbols     - Full-text search",
+    "find_symbols       - Filter symbols by metadata",
+    "get_references     - Who references a symbol",
+    "get_dependencies   - What a symbol depends on",
+    "get_uncertain_refs - Ambiguous and unresolved refs",
+    "index_status       - Check index freshness",
+    "list_repos         - List all indexed repositories",
+    "delete_repos       - Delete repository index data",
 ];
 
 pub fn read_settings(path: &Path) -> Result<Value> {
@@ -71,11 +75,11 @@ pub fn set_grants(path: &Path, grants: &[bool]) -> Result<()> {
 }
 
 pub fn grant_all(path: &Path) -> Result<()> {
-    set_grants(path, &[true; 10])
+    set_grants(path, &[true; 12])
 }
 
 pub fn revoke_all(path: &Path) -> Result<()> {
-    set_grants(path, &[false; 10])
+    set_grants(path, &[false; 12])
 }
 
 fn apply_grants(settings: &mut Value, grants: &[bool]) -> Result<()> {
@@ -120,10 +124,10 @@ mod tests {
     #[test]
     fn grant_all_to_empty_settings() {
         let mut settings = json!({});
-        apply_grants(&mut settings, &[true; 10]).unwrap();
+        apply_grants(&mut settings, &[true; 12]).unwrap();
 
         let allow = settings["permissions"]["allow"].as_array().unwrap();
-        assert_eq!(allow.len(), 10);
+        assert_eq!(allow.len(), 12);
         for perm in &TOOL_PERMISSIONS {
             assert!(allow.contains(&json!(perm)));
         }
@@ -138,10 +142,10 @@ mod tests {
             },
             "other_key": true
         });
-        apply_grants(&mut settings, &[true; 10]).unwrap();
+        apply_grants(&mut settings, &[true; 12]).unwrap();
 
         let allow = settings["permissions"]["allow"].as_array().unwrap();
-        assert_eq!(allow.len(), 12); // 2 existing + 10 ctxhelpr
+        assert_eq!(allow.len(), 14); // 2 existing + 12 ctxhelpr
         assert!(allow.contains(&json!("mcp__other__tool")));
         assert!(allow.contains(&json!("some_permission")));
         assert_eq!(settings["permissions"]["deny"][0], "something");
@@ -160,7 +164,7 @@ mod tests {
                 ]
             }
         });
-        apply_grants(&mut settings, &[false; 10]).unwrap();
+        apply_grants(&mut settings, &[false; 12]).unwrap();
 
         let allow = settings["permissions"]["allow"].as_array().unwrap();
         assert_eq!(allow.len(), 2);
@@ -171,12 +175,12 @@ mod tests {
     #[test]
     fn selective_grants() {
         let mut settings = json!({});
-        let mut grants = [false; 10];
+        let mut grants = [false; 12];
         grants[0] = true; // index_repository
         grants[1] = true; // get_overview
         grants[4] = true; // search_symbols
-        grants[6] = true; // get_dependencies
-        grants[7] = true; // index_status
+        grants[7] = true; // get_dependencies
+        grants[9] = true; // index_status
 
         apply_grants(&mut settings, &grants).unwrap();
 
@@ -192,17 +196,17 @@ mod tests {
     #[test]
     fn idempotent_grant_no_duplicates() {
         let mut settings = json!({});
-        apply_grants(&mut settings, &[true; 10]).unwrap();
-        apply_grants(&mut settings, &[true; 10]).unwrap();
+        apply_grants(&mut settings, &[true; 12]).unwrap();
+        apply_grants(&mut settings, &[true; 12]).unwrap();
 
         let allow = settings["permissions"]["allow"].as_array().unwrap();
-        assert_eq!(allow.len(), 10);
+        assert_eq!(allow.len(), 12);
     }
 
     #[test]
     fn apply_grants_rejects_non_object_settings() {
         let mut settings = json!("not an object");
-        let result = apply_grants(&mut settings, &[true; 10]);
+        let result = apply_grants(&mut settings, &[true; 12]);
         assert!(result.is_err());
         assert!(
             result
@@ -215,7 +219,7 @@ mod tests {
     #[test]
     fn apply_grants_rejects_non_object_permissions() {
         let mut settings = json!({"permissions": "not an object"});
-        let result = apply_grants(&mut settings, &[true; 10]);
+        let result = apply_grants(&mut settings, &[true; 12]);
         assert!(result.is_err());
         assert!(
             result
@@ -228,7 +232,7 @@ mod tests {
     #[test]
     fn apply_grants_rejects_non_array_allow() {
         let mut settings = json!({"permissions": {"allow": "not an array"}});
-        let result = apply_grants(&mut settings, &[true; 10]);
+        let result = apply_grants(&mut settings, &[true; 12]);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not a JSON array"));
     }
@@ -274,13 +278,13 @@ mod tests {
         });
         write_settings(&path, &initial).unwrap();
 
-        set_grants(&path, &[true; 10]).unwrap();
+        set_grants(&path, &[true; 12]).unwrap();
 
         let settings = read_settings(&path).unwrap();
         let allow = settings["permissions"]["allow"].as_array().unwrap();
         assert!(allow.contains(&json!("other_tool_permission")));
         assert_eq!(settings["permissions"]["deny"][0], "something_else");
         assert_eq!(settings["unrelated_key"], 42);
-        assert_eq!(allow.len(), 11); // 1 existing + 10 ctxhelpr
+        assert_eq!(allow.len(), 13); // 1 existing + 12 ctxhelpr
     }
 }
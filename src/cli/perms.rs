@@ -53,7 +53,7 @@ pub fn run(scope: Scope, all: bool, remove: bool) -> Result<()> {
         }
     };
 
-    let mut grants = [false; 11];
+    let mut grants = [false; 12];
     for idx in &selections {
         grants[*idx] = true;
     }
@@ -84,6 +84,49 @@ pub struct IndexerConfig {
     pub ignore: Vec<String>,
     /// Max file size in bytes (files larger are skipped)
     pub max_file_size: u64,
+    /// Max nesting depth of keys indexed from YAML, TOML and JSON files
+    pub max_key_depth: usize,
+    /// Extractors driven by tree-sitter query packs
+    pub queries: QueriesConfig,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QueriesConfig {
+    /// Directory of `<grammar>.scm` query packs, relative to the repo root
+    pub dir: String,
+    /// Languages extracted by query packs, either new or replacing a built-in extractor
+    pub languages: Vec<QueryLanguageConfig>,
+    /// Load shared-library grammars named by `grammar_path`. Loading one runs its
+    /// code, so this is only honored in the global config.
+    pub allow_native_grammars: bool,
+    /// Absolute directories outside the repo that `grammar_path` may point into.
+    /// Only honored in the global config.
+    pub grammar_dirs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct QueryLanguageConfig {
+    /// Language name recorded for matched files
+    pub name: String,
+    /// Grammar to parse with (defaults to `name`): a built-in one, or the name
+    /// a `grammar_path` library exports as `tree_sitter_<grammar>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grammar: Option<String>,
+    /// Compiled grammar to load instead of a built-in one, relative to the repo
+    /// root: a shared library (`.so`, `.dylib`, `.dll`) or a `.wasm` file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grammar_path: Option<String>,
+    /// File extensions, without the dot
+    #[serde(default)]
+    pub extensions: Vec<String>,
+    /// File names matched without an extension (`BUILD`)
+    #[serde(default)]
+    pub filenames: Vec<String>,
+    /// Query file relative to the repo root (defaults to `<dir>/<name>.scm`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
 }
 
 impl Default for OutputConfig {
@@ -107,6 +150,19 @@ impl Default for IndexerConfig {
         Self {
             ignore: vec![],
             max_file_size: 1_048_576, // 1 MiB
+            max_key_depth: crate::indexer::languages::data::DEFAULT_MAX_KEY_DEPTH,
+            queries: QueriesConfig::default(),
+        }
+    }
+}
+
+impl Default for QueriesConfig {
+    fn default() -> Self {
+        Self {
+            dir: ".ctxhelpr/queries".to_string(),
+            languages: vec![],
+            allow_native_grammars: false,
+            grammar_dirs: vec![],
         }
     }
 }
@@ -131,6 +187,30 @@ fn load_json_file(path: &Path) -> Result<Value, ConfigError> {
     })
 }
 
+/// `indexer.queries` settings a repo's own config can't set: they decide whether
+/// cloning a repo and indexing it may run code from it.
+const GLOBAL_ONLY_QUERY_SETTINGS: &[&str] = &["allow_native_grammars", "grammar_dirs"];
+
+/// Drops the global-only settings from a repo's config, with a warning.
+fn strip_global_only(local: &mut Value, path: &Path) {
+    let Some(queries) = local
+        .get_mut("indexer")
+        .and_then(|indexer| indexer.get_mut("queries"))
+        .and_then(Value::as_object_mut)
+    else {
+        return;
+    };
+    for key in GLOBAL_ONLY_QUERY_SETTINGS {
+        if queries.remove(*key).is_some() {
+            tracing::warn!(
+                path = %path.display(),
+                setting = %format!("indexer.queries.{key}"),
+                "Setting is only honored in the global config, ignoring"
+            );
+        }
+    }
+}
+
 /// Recursively merges two JSON values. Objects merge key-by-key; arrays and scalars
 /// in `overlay` replace whatever is in `base`.
 pub fn deep_merge(base: Value, overlay: Value) -> Value {
@@ -166,7 +246,8 @@ pub fn load_and_merge(
         None => Value::Object(serde_json::Map::new()),
     };
 
-    let local_value = load_json_file(local_path)?;
+    let mut local_value = load_json_file(local_path)?;
+    strip_global_only(&mut local_value, local_path);
     let merged = deep_merge(global_value, local_value);
 
     serde_json::from_value(merged).map_err(|e| ConfigError::InvalidJson {
@@ -255,6 +336,7 @@ mod tests {
         let config = Config::default();
         assert_eq!(config.search.max_results, 20);
         assert_eq!(config.indexer.max_file_size, 1_048_576);
+        assert_eq!(config.indexer.max_key_depth, 3);
         assert_eq!(config.output.truncate_signatures, 120);
     }
 
@@ -279,7 +361,8 @@ mod tests {
   },
   "indexer": {
     "ignore": ["generated/", "*.min.js"],
-    "max_file_size": 524288
+    "max_file_size": 524288,
+    "max_key_depth": 1
   }
 }"#;
         fs::write(dir.path().join(CONFIG_FILENAME), config_content).unwrap();
@@ -290,6 +373,7 @@ mod tests {
         assert_eq!(config.search.max_results, 10);
         assert_eq!(config.indexer.ignore, vec!["generated/", "*.min.js"]);
         assert_eq!(config.indexer.max_file_size, 524288);
+        assert_eq!(config.indexer.max_key_depth, 1);
     }
 
     #[test]
@@ -339,6 +423,54 @@ mod tests {
         assert!(err.to_string().contains("typo_field"));
     }
 
+    #[test]
+    fn test_load_query_languages() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_content = r#"{
+  "indexer": {
+    "queries": {
+      "languages": [
+        { "name": "starlark", "grammar": "python", "extensions": ["bzl"], "filenames": ["BUILD"] }
+      ]
+    }
+  }
+}"#;
+        fs::write(dir.path().join(CONFIG_FILENAME), config_content).unwrap();
+
+        let config = Config::load(dir.path().to_str().unwrap()).unwrap();
+        let queries = &config.indexer.queries;
+        assert_eq!(queries.dir, ".ctxhelpr/queries");
+        assert_eq!(queries.languages.len(), 1);
+        assert_eq!(queries.languages[0].name, "starlark");
+        assert_eq!(queries.languages[0].grammar.as_deref(), Some("python"));
+        assert_eq!(queries.languages[0].extensions, vec!["bzl"]);
+        assert_eq!(queries.languages[0].filenames, vec!["BUILD"]);
+        assert!(queries.languages[0].query.is_none());
+    }
+
+    #[test]
+    fn test_native_grammars_only_from_global_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let global_path = dir.path().join("global.json");
+        let local_path = dir.path().join(CONFIG_FILENAME);
+        let allow = r#"{"indexer": {"queries": {"allow_native_grammars": true, "grammar_dirs": ["/opt/grammars"]}}}"#;
+
+        fs::write(&local_path, allow).unwrap();
+        let config = load_and_merge(None, &local_path).unwrap();
+        assert!(!config.indexer.queries.allow_native_grammars);
+        assert!(config.indexer.queries.grammar_dirs.is_empty());
+
+        fs::write(&global_path, allow).unwrap();
+        fs::write(
+            &local_path,
+            r#"{"indexer": {"queries": {"allow_native_grammars": false, "grammar_dirs": []}}}"#,
+        )
+        .unwrap();
+        let config = load_and_merge(Some(&global_path), &local_path).unwrap();
+        assert!(config.indexer.queries.allow_native_grammars);
+        assert_eq!(config.indexer.queries.grammar_dirs, vec!["/opt/grammars"]);
+    }
+
     #[test]
     fn test_validate_missing_file() {
         let dir = tempfile::tempdir().unwrap();
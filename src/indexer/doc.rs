@@ -0,0 +1,490 @@
+use serde::{Deserialize, Serialize};
+
+/// A doc comment split into the parts tools show separately.
+///
+/// Extractors store doc comments as plain text. This recognizes the conventions
+/// found in that text: JSDoc/Javadoc/KDoc/PHPDoc tags (`@param`, `@returns`,
+/// `@throws`), Sphinx fields (`:param x:`, `:returns:`, `:raises E:`), Google and
+/// NumPy docstring sections (`Args:`, `Parameters` over a `----` line), and rustdoc
+/// headings (`# Arguments`, `# Returns`, `# Errors`, `# Panics`). Anything else is
+/// description. The index stores the parts next to the text, so tools don't parse
+/// it again on every call.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocComment {
+    /// First sentence of the first paragraph.
+    pub summary: String,
+    /// The rest of the prose, without the parts below.
+    pub description: Option<String>,
+    pub params: Vec<DocEntry>,
+    pub returns: Option<String>,
+    /// Errors thrown, raised or returned, and panics. `name` is the error type
+    /// when the comment gives one.
+    pub throws: Vec<DocEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocEntry {
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Prose,
+    Params,
+    Returns,
+    Throws,
+    /// `:type x:` and `:rtype:` — types are already in the signature.
+    Skipped,
+}
+
+/// Where continuation lines go.
+#[derive(Clone, Copy)]
+enum Open {
+    None,
+    Param,
+    Returns,
+    Throw,
+}
+
+impl DocComment {
+    pub fn parse(doc: &str) -> Self {
+        let mut parsed = DocComment::default();
+        let mut prose: Vec<&str> = Vec::new();
+        let mut section = Section::Prose;
+        let mut open = Open::None;
+        let mut in_code = false;
+        let mut throw_name = "";
+
+        let lines: Vec<&str> = doc.lines().map(str::trim).collect();
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i];
+            i += 1;
+
+            if line.starts_with("```") || in_code {
+                in_code ^= line.starts_with("```");
+                prose.push(line);
+                continue;
+            }
+
+            // NumPy: a section name underlined with dashes
+            if lines.get(i).is_some_and(|next| is_underline(next)) {
+                if let Some(s) = section_named(line) {
+                    section = s;
+                    open = Open::None;
+                    i += 1;
+                    continue;
+                }
+            }
+
+            if let Some(heading) = section_heading(line) {
+                section = section_named(heading).unwrap_or(Section::Prose);
+                open = Open::None;
+                throw_name = if heading.eq_ignore_ascii_case("panics") {
+                    "panic"
+                } else {
+                    ""
+                };
+                if section == Section::Prose {
+                    prose.push(line);
+                }
+                continue;
+            }
+
+            if let Some(tag) = line.strip_prefix('@') {
+                section = Section::Prose;
+                open = parsed.push_tag(tag);
+                if matches!(open, Open::None) {
+                    prose.push(line);
+                }
+                continue;
+            }
+
+            if let Some((field, rest)) = sphinx_field(line) {
+                (section, open) = parsed.push_field(field, rest);
+                continue;
+            }
+
+            if line.is_empty() {
+                open = Open::None;
+                if section == Section::Prose {
+                    prose.push(line);
+                }
+                continue;
+            }
+
+            let entry = match section {
+                Section::Params | Section::Throws => entry_line(line),
+                _ => None,
+            };
+            match (section, open) {
+                (Section::Skipped, _) => {}
+                (Section::Prose, Open::None) => prose.push(line),
+                (Section::Returns, _) => {
+                    append(parsed.returns.get_or_insert_with(String::new), line);
+                }
+                (Section::Params, _) if entry.is_some() => {
+                    parsed.params.extend(entry);
+                    open = Open::Param;
+                }
+                (Section::Throws, _) if entry.is_some() => {
+                    parsed.throws.extend(entry);
+                    open = Open::Throw;
+                }
+                (Section::Throws, Open::None) => {
+                    // `# Errors` and `# Panics` usually explain in prose
+                    open = parsed.new_entry(Open::Throw, throw_name, line);
+                }
+                (Section::Params, Open::None) => prose.push(line),
+                _ => parsed.continue_open(open, line),
+            }
+        }
+
+        parsed.set_prose(&prose);
+        parsed
+    }
+
+    fn new_entry(&mut self, open: Open, name: &str, desc: &str) -> Open {
+        match open {
+            Open::Param => self.params.push(DocEntry::new(name, desc)),
+            Open::Throw => self.throws.push(DocEntry::new(name, desc)),
+            Open::Returns => {
+                let returns = self.returns.get_or_insert_with(String::new);
+                append(returns, desc);
+            }
+            Open::None => {}
+        }
+        open
+    }
+
+    fn continue_open(&mut self, open: Open, line: &str) {
+        let target = match open {
+            Open::Param => self.params.last_mut().map(|e| &mut e.description),
+            Open::Throw => self.throws.last_mut().map(|e| &mut e.description),
+            Open::Returns => self.returns.as_mut(),
+            Open::None => None,
+        };
+        if let Some(target) = target {
+            append(target, line);
+        }
+    }
+
+    /// `@param {T} name desc`, `@param string $name desc`, `@returns {T} desc`,
+    /// `@throws {E} desc`, `@throws IOException desc`. Other tags stay in the
+    /// description.
+    fn push_tag(&mut self, tag: &str) -> Open {
+        let (name, rest) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        let rest = skip_braced_type(rest.trim());
+        match name {
+            "param" | "arg" | "argument" | "property" | "prop" => {
+                let mut words = rest.splitn(2, char::is_whitespace);
+                let mut first = words.next().unwrap_or("");
+                let mut desc = words.next().unwrap_or("").trim();
+                // PHPDoc puts the type before `$name`
+                if !first.starts_with('$') && desc.starts_with('$') {
+                    let mut words = desc.splitn(2, char::is_whitespace);
+                    first = words.next().unwrap_or("");
+                    desc = words.next().unwrap_or("").trim();
+                }
+                let param = first
+                    .trim_start_matches('$')
+                    .trim_start_matches('[')
+                    .trim_end_matches(']');
+                let param = param.split('=').next().unwrap_or(param);
+                self.new_entry(Open::Param, param, strip_separator(desc))
+            }
+            "returns" | "return" | "yields" | "yield" => {
+                self.new_entry(Open::Returns, "", strip_separator(rest))
+            }
+            "throws" | "throw" | "exception" | "raises" => {
+                let (error, desc) = if tag.contains('{') {
+                    let error = tag
+                        .split_once('{')
+                        .and_then(|(_, t)| t.split_once('}'))
+                        .map_or("", |(t, _)| t.trim());
+                    (error, rest)
+                } else {
+                    let (first, desc) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                    if looks_like_error_type(first) || name == "exception" {
+                        (first, desc.trim())
+                    } else {
+                        ("", rest)
+                    }
+                };
+                self.new_entry(Open::Throw, error, strip_separator(desc))
+            }
+            _ => Open::None,
+        }
+    }
+
+    /// `:param x:`, `:param int x:`, `:returns:`, `:raises ValueError:`.
+    fn push_field(&mut self, field: &str, desc: &str) -> (Section, Open) {
+        let mut words = field.split_whitespace();
+        let kind = words.next().unwrap_or("");
+        let name = words.last().unwrap_or("");
+        let open = match kind {
+            "param" | "parameter" | "arg" | "argument" | "key" | "keyword" => {
+                self.new_entry(Open::Param, name, desc)
+            }
+            "returns" | "return" | "yields" | "yield" => self.new_entry(Open::Returns, "", desc),
+            "raises" | "raise" | "except" | "exception" | "throws" => {
+                self.new_entry(Open::Throw, name, desc)
+            }
+            _ => return (Section::Skipped, Open::None),
+        };
+        (Section::Prose, open)
+    }
+
+    fn set_prose(&mut self, prose: &[&str]) {
+        let text = prose.join("\n");
+        let text = text.trim();
+        let paragraph_end = text.find("\n\n").unwrap_or(text.len());
+        let paragraph = &text[..paragraph_end];
+        let summary_end = paragraph
+            .find(". ")
+            .or_else(|| paragraph.find(".\n"))
+            .map_or(paragraph.len(), |dot| dot + 1);
+        self.summary = paragraph[..summary_end]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let rest = text[summary_end..].trim();
+        self.description = (!rest.is_empty()).then(|| rest.to_string());
+    }
+}
+
+impl DocEntry {
+    fn new(name: &str, description: &str) -> Self {
+        DocEntry {
+            name: name.trim_matches('`').to_string(),
+            description: description.to_string(),
+        }
+    }
+}
+
+fn append(target: &mut String, line: &str) {
+    if line.is_empty() {
+        return;
+    }
+    if !target.is_empty() {
+        target.push(' ');
+    }
+    target.push_str(line);
+}
+
+fn is_underline(line: &str) -> bool {
+    line.len() >= 3 && line.chars().all(|c| c == '-')
+}
+
+/// `Args:`, `Returns:` (Google) or `# Arguments` (rustdoc), without the markup.
+fn section_heading(line: &str) -> Option<&str> {
+    if let Some(heading) = line.strip_prefix('#') {
+        let heading = heading.trim_start_matches('#');
+        return heading.starts_with(' ').then(|| heading.trim());
+    }
+    let heading = line.strip_suffix(':')?;
+    let is_title = heading.len() <= 30
+        && heading.starts_with(|c: char| c.is_ascii_uppercase())
+        && heading.chars().all(|c| c.is_ascii_alphabetic() || c == ' ');
+    is_title.then_some(heading)
+}
+
+fn section_named(name: &str) -> Option<Section> {
+    let section = match name.to_ascii_lowercase().as_str() {
+        "args" | "arguments" | "parameters" | "params" | "keyword args" | "keyword arguments"
+        | "other parameters" => Section::Params,
+        "returns" | "return" | "yields" => Section::Returns,
+        "raises" | "throws" | "exceptions" | "errors" | "panics" => Section::Throws,
+        _ => return None,
+    };
+    Some(section)
+}
+
+/// A parameter or error in a section: `` * `x` - desc ``, `x (int): desc`,
+/// `ValueError: desc`, or NumPy's `x : int` with the description below it.
+fn entry_line(line: &str) -> Option<DocEntry> {
+    let line = line
+        .strip_prefix(['*', '-', '+'])
+        .map_or(line, str::trim_start);
+    let name_end = line
+        .find(|c: char| !(c.is_alphanumeric() || "_.$`*".contains(c)))
+        .unwrap_or(line.len());
+    let name = line[..name_end]
+        .trim_matches(['`', '*'])
+        .trim_start_matches('$');
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut rest = line[name_end..].trim_start();
+    let mut spaced = rest.len() < line.len() - name_end;
+    if rest.starts_with('(') {
+        rest = rest.split_once(')').map_or("", |(_, r)| r.trim_start());
+        spaced = false;
+    }
+    if rest.is_empty() {
+        // NumPy puts each name on its own line
+        return Some(DocEntry::new(name, ""));
+    }
+    if let Some(after) = rest.strip_prefix(':') {
+        // NumPy `x : int` — the type isn't the description
+        let desc = if spaced { "" } else { after.trim() };
+        return Some(DocEntry::new(name, desc));
+    }
+    let desc = rest.strip_prefix(['-', '–'])?.trim();
+    Some(DocEntry::new(name, desc))
+}
+
+fn sphinx_field(line: &str) -> Option<(&str, &str)> {
+    let (field, desc) = line.strip_prefix(':')?.split_once(':')?;
+    (!field.is_empty() && !field.starts_with(' ')).then(|| (field, desc.trim()))
+}
+
+/// Drops a JSDoc `{Type}` from the start of a tag's text.
+fn skip_braced_type(text: &str) -> &str {
+    if !text.starts_with('{') {
+        return text;
+    }
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return text[i + 1..].trim_start();
+                }
+            }
+            _ => {}
+        }
+    }
+    ""
+}
+
+/// JSDoc allows `@param name - desc`.
+fn strip_separator(desc: &str) -> &str {
+    desc.strip_prefix("- ").unwrap_or(desc).trim()
+}
+
+/// `@throws` is followed by either an error type (Javadoc, KDoc) or prose (JSDoc).
+fn looks_like_error_type(word: &str) -> bool {
+    let last = word.rsplit(['.', ':', '\\']).next().unwrap_or(word);
+    word.contains(['.', '\\'])
+        || ["Error", "Exception", "Err", "Fault"]
+            .iter()
+            .any(|suffix| last.ends_with(suffix) && last.starts_with(|c: char| c.is_uppercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, description: &str) -> DocEntry {
+        DocEntry::new(name, description)
+    }
+
+    #[test]
+    fn test_plain_doc_is_summary_and_description() {
+        let doc = DocComment::parse("Adds two numbers. Returns the sum.\n\nNever overflows.");
+        assert_eq!(doc.summary, "Adds two numbers.");
+        assert_eq!(
+            doc.description.as_deref(),
+            Some("Returns the sum.\n\nNever overflows.")
+        );
+        assert!(doc.params.is_empty() && doc.returns.is_none() && doc.throws.is_empty());
+    }
+
+    #[test]
+    fn test_jsdoc_tags() {
+        let doc = DocComment::parse(
+            "Find a user by id.\n@param {string} id - The user id\n@param {Options} [opts={}] Lookup\noptions\n@returns {Promise<User>} The user\n@throws {NotFoundError} When missing\n@example\nfind('1')",
+        );
+        assert_eq!(doc.summary, "Find a user by id.");
+        assert_eq!(
+            doc.params,
+            vec![entry("id", "The user id"), entry("opts", "Lookup options")]
+        );
+        assert_eq!(doc.returns.as_deref(), Some("The user"));
+        assert_eq!(doc.throws, vec![entry("NotFoundError", "When missing")]);
+        assert_eq!(doc.description.as_deref(), Some("@example\nfind('1')"));
+    }
+
+    #[test]
+    fn test_javadoc_and_phpdoc_tags() {
+        let doc = DocComment::parse(
+            "Saves it.\n@param string $path Where to\n@return bool\n@throws IOException if the disk is full\n@throws Will throw on bad input",
+        );
+        assert_eq!(doc.params, vec![entry("path", "Where to")]);
+        assert_eq!(doc.returns.as_deref(), Some("bool"));
+        assert_eq!(
+            doc.throws,
+            vec![
+                entry("IOException", "if the disk is full"),
+                entry("", "Will throw on bad input"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sphinx_fields() {
+        let doc = DocComment::parse(
+            "Load a file.\n:param str path: File to load\n:type path: str\n:returns: The contents\n:rtype: str\n:raises OSError: If unreadable",
+        );
+        assert_eq!(doc.params, vec![entry("path", "File to load")]);
+        assert_eq!(doc.returns.as_deref(), Some("The contents"));
+        assert_eq!(doc.throws, vec![entry("OSError", "If unreadable")]);
+        assert_eq!(doc.description, None);
+    }
+
+    #[test]
+    fn test_google_sections() {
+        let doc = DocComment::parse(
+            "Fetch a user.\nArgs:\nuser_id: The id.\nstrict (bool): Whether to fail\nwhen missing.\nReturns:\nThe user, or None.\nRaises:\nKeyError: If missing.\nExample:\nfetch(1)",
+        );
+        assert_eq!(doc.summary, "Fetch a user.");
+        assert_eq!(
+            doc.params,
+            vec![
+                entry("user_id", "The id."),
+                entry("strict", "Whether to fail when missing."),
+            ]
+        );
+        assert_eq!(doc.returns.as_deref(), Some("The user, or None."));
+        assert_eq!(doc.throws, vec![entry("KeyError", "If missing.")]);
+        assert_eq!(doc.description.as_deref(), Some("Example:\nfetch(1)"));
+    }
+
+    #[test]
+    fn test_numpy_sections() {
+        let doc = DocComment::parse(
+            "Scale values.\nParameters\n----------\nx : ndarray\nValues to scale.\nfactor : float\nReturns\n-------\nndarray\nRaises\n------\nValueError\nIf factor is zero.",
+        );
+        assert_eq!(
+            doc.params,
+            vec![entry("x", "Values to scale."), entry("factor", "")]
+        );
+        assert_eq!(doc.returns.as_deref(), Some("ndarray"));
+        assert_eq!(doc.throws, vec![entry("ValueError", "If factor is zero.")]);
+    }
+
+    #[test]
+    fn test_rustdoc_headings() {
+        let doc = DocComment::parse(
+            "Parses a config.\n\n# Arguments\n\n* `path` - Where the file is\n\n# Errors\n\nReturns an error if the file is missing.\n\n# Panics\n\nIf the lock is poisoned.\n\n# Examples\n\n```\n# use foo::parse;\nparse(\"a.toml\");\n```",
+        );
+        assert_eq!(doc.summary, "Parses a config.");
+        assert_eq!(doc.params, vec![entry("path", "Where the file is")]);
+        assert_eq!(
+            doc.throws,
+            vec![
+                entry("", "Returns an error if the file is missing."),
+                entry("panic", "If the lock is poisoned."),
+            ]
+        );
+        assert_eq!(
+            doc.description.as_deref(),
+            Some("# Examples\n\n```\n# use foo::parse;\nparse(\"a.toml\");\n```")
+        );
+    }
+}
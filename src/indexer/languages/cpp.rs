@@ -0,0 +1,832 @@
+use tree_sitter::{Node, Tree, TreeCursor};
+
+use super::{LanguageExtractor, detect_language};
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+/// Handles both C and C++. Headers are parsed with the C++ grammar, which accepts
+/// nearly all C and also copes with `extern "C"` blocks and classes in `.h` files.
+pub struct CppExtractor;
+
+impl LanguageExtractor for CppExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_cpp::LANGUAGE.into()
+    }
+
+    fn language_for_ext(&self, ext: &str) -> tree_sitter::Language {
+        match detect_language(ext) {
+            Some("c") => tree_sitter_c::LANGUAGE.into(),
+            _ => tree_sitter_cpp::LANGUAGE.into(),
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["c", "h", "cc", "cpp", "hpp"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let mut symbols = Vec::new();
+        let mut includes = Vec::new();
+        extract_block(
+            tree.root_node(),
+            source,
+            &Scope::default(),
+            &mut includes,
+            &mut symbols,
+        );
+
+        if !includes.is_empty() {
+            let start_line = includes.iter().map(|r| r.line).min().unwrap_or(1);
+            let end_line = includes.iter().map(|r| r.line).max().unwrap_or(1);
+            symbols.insert(
+                0,
+                ExtractedSymbol {
+                    name: "_imports".to_string(),
+                    kind: SymbolKind::Mod,
+                    signature: None,
+                    doc_comment: None,
+                    start_line,
+                    end_line,
+                    children: Vec::new(),
+                    references: includes,
+                    metadata: Vec::new(),
+                },
+            );
+        }
+        symbols
+    }
+}
+
+/// Where a declaration sits: enclosing namespaces/classes and template parameters in scope.
+#[derive(Clone, Default)]
+struct Scope {
+    path: Vec<String>,
+    generics: Vec<String>,
+    in_class: bool,
+}
+
+impl Scope {
+    fn enter(&self, name: &str, in_class: bool) -> Scope {
+        let mut path = self.path.clone();
+        path.push(name.to_string());
+        Scope {
+            path,
+            generics: self.generics.clone(),
+            in_class,
+        }
+    }
+
+    fn with_generics(&self, names: Vec<String>) -> Scope {
+        let mut scope = self.clone();
+        scope.generics.extend(names);
+        scope
+    }
+
+    /// `area` declared in `geo::Circle` → `geo::Circle::area`.
+    fn qualify(&self, name: &str) -> String {
+        if self.path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}::{name}", self.path.join("::"))
+        }
+    }
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+/// The comment block directly above a declaration: consecutive `//`/`///` lines or a
+/// single `/* */` block. Templates carry the comment above the `template <...>` line.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let node = match node.parent() {
+        Some(p) if p.kind() == "template_declaration" => p,
+        _ => node,
+    };
+
+    let mut lines = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut sibling = node.prev_sibling();
+    while let Some(s) = sibling {
+        if s.kind() != "comment" || s.end_position().row + 1 != expected_row {
+            break;
+        }
+        let t = text(s, source);
+        if t.starts_with("/*") {
+            if lines.is_empty() {
+                let cleaned = t
+                    .trim_start_matches("/**")
+                    .trim_start_matches("/*")
+                    .trim_end_matches("*/")
+                    .lines()
+                    .map(|l| l.trim().trim_start_matches('*').trim())
+                    .filter(|l| !l.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                if !cleaned.is_empty() {
+                    return Some(cleaned);
+                }
+            }
+            break;
+        }
+        let stripped = t.trim_start_matches('/').trim_start_matches('!').trim();
+        lines.push(stripped.to_string());
+        expected_row = s.start_position().row;
+        sibling = s.prev_sibling();
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
+fn extract_block(
+    node: Node,
+    source: &[u8],
+    scope: &Scope,
+    includes: &mut Vec<ExtractedRef>,
+    symbols: &mut Vec<ExtractedSymbol>,
+) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        extract_item(child, source, scope, includes, symbols);
+    }
+}
+
+fn extract_item(
+    node: Node,
+    source: &[u8],
+    scope: &Scope,
+    includes: &mut Vec<ExtractedRef>,
+    symbols: &mut Vec<ExtractedSymbol>,
+) {
+    match node.kind() {
+        "preproc_include" => {
+            if let Some(r) = extract_include(node, source) {
+                includes.push(r);
+            }
+        }
+        "preproc_ifdef" => {
+            // `#ifndef FOO_H / #define FOO_H` include guards aren't worth a symbol
+            let guard = node.child_by_field_name("name").map(|n| text(n, source));
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                let is_guard = child.kind() == "preproc_def"
+                    && child.child_by_field_name("value").is_none()
+                    && child.child_by_field_name("name").map(|n| text(n, source)) == guard;
+                if !is_guard {
+                    extract_item(child, source, scope, includes, symbols);
+                }
+            }
+        }
+        "preproc_if" | "preproc_else" | "preproc_elif" | "preproc_elifdef" | "declaration_list" => {
+            extract_block(node, source, scope, includes, symbols);
+        }
+        "linkage_specification" => {
+            // `extern "C" { ... }` or `extern "C" int f(void);`
+            if let Some(body) = node.child_by_field_name("body") {
+                extract_item(body, source, scope, includes, symbols);
+            }
+        }
+        "namespace_definition" => match node.child_by_field_name("name") {
+            Some(name_node) => {
+                let name = text(name_node, source);
+                let mut children = Vec::new();
+                if let Some(body) = node.child_by_field_name("body") {
+                    let inner = scope.enter(&name, false);
+                    extract_block(body, source, &inner, includes, &mut children);
+                }
+                symbols.push(ExtractedSymbol {
+                    name,
+                    kind: SymbolKind::Mod,
+                    signature: None,
+                    doc_comment: get_doc_comment(node, source),
+                    start_line: node.start_position().row + 1,
+                    end_line: node.end_position().row + 1,
+                    children,
+                    references: Vec::new(),
+                    metadata: Vec::new(),
+                });
+            }
+            // Anonymous namespaces only limit linkage; their members stay in the enclosing scope
+            None => {
+                if let Some(body) = node.child_by_field_name("body") {
+                    extract_block(body, source, scope, includes, symbols);
+                }
+            }
+        },
+        "template_declaration" => {
+            let inner_scope = scope.with_generics(template_parameter_names(node, source));
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                if child.kind() != "template_parameter_list" {
+                    extract_item(child, source, &inner_scope, includes, symbols);
+                }
+            }
+        }
+        "function_definition" => {
+            if let Some(sym) = extract_function(node, source, scope, true) {
+                symbols.push(sym);
+            }
+        }
+        "declaration" | "field_declaration" => {
+            extract_declaration(node, source, scope, symbols);
+        }
+        "struct_specifier" | "union_specifier" | "class_specifier" | "enum_specifier" => {
+            if let Some(sym) = extract_type_specifier(node, source, scope, None) {
+                symbols.push(sym);
+            }
+        }
+        "type_definition" => {
+            extract_typedef(node, source, scope, symbols);
+        }
+        "alias_declaration" => {
+            if let Some(sym) = extract_alias(node, source, scope) {
+                symbols.push(sym);
+            }
+        }
+        "preproc_def" | "preproc_function_def" => {
+            if let Some(sym) = extract_macro(node, source) {
+                symbols.push(sym);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `#include <stdio.h>` → `stdio.h`, `#include "shapes.h"` → `shapes.h`.
+fn extract_include(node: Node, source: &[u8]) -> Option<ExtractedRef> {
+    let path = node.child_by_field_name("path")?;
+    let name = text(path, source)
+        .trim_matches(|c| c == '"' || c == '<' || c == '>')
+        .to_string();
+    if name.is_empty() {
+        return None;
+    }
+    Some(ExtractedRef {
+        name,
+        kind: RefKind::Import,
+        line: node.start_position().row + 1,
+    })
+}
+
+fn extract_macro(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let signature = match node.child_by_field_name("parameters") {
+        Some(params) => Some(text(params, source)),
+        None => node
+            .child_by_field_name("value")
+            .map(|v| text(v, source).trim().to_string()),
+    };
+
+    // Preprocessor lines include their trailing newline
+    let start_line = node.start_position().row + 1;
+    let end_line = node.end_position().row.max(start_line);
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Macro,
+        signature,
+        doc_comment: get_doc_comment(node, source),
+        start_line,
+        end_line,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+/// Strips pointer/reference/parenthesis wrappers: `*(*name)` → `name`.
+fn unwrap_declarator(mut node: Node) -> Node {
+    while matches!(
+        node.kind(),
+        "pointer_declarator" | "reference_declarator" | "parenthesized_declarator"
+    ) {
+        match node
+            .child_by_field_name("declarator")
+            .or_else(|| node.named_child(node.named_child_count().saturating_sub(1) as u32))
+        {
+            Some(inner) => node = inner,
+            None => break,
+        }
+    }
+    node
+}
+
+/// The `*`/`&` a declarator adds to its base type: `char *name()` returns `char *`.
+fn declarator_suffix(mut node: Node) -> String {
+    let mut suffix = String::new();
+    loop {
+        match node.kind() {
+            "pointer_declarator" => suffix.push('*'),
+            "reference_declarator" => suffix.push('&'),
+            "parenthesized_declarator" | "init_declarator" => {}
+            _ => break,
+        }
+        match node
+            .child_by_field_name("declarator")
+            .or_else(|| node.named_child(node.named_child_count().saturating_sub(1) as u32))
+        {
+            Some(inner) => node = inner,
+            None => break,
+        }
+    }
+    suffix
+}
+
+fn declarator_name(node: Node, source: &[u8]) -> Option<String> {
+    let node = unwrap_declarator(node);
+    match node.kind() {
+        "identifier"
+        | "field_identifier"
+        | "type_identifier"
+        | "qualified_identifier"
+        | "destructor_name"
+        | "operator_name"
+        | "template_function" => Some(text(node, source)),
+        "init_declarator" | "array_declarator" | "function_declarator" => {
+            declarator_name(node.child_by_field_name("declarator")?, source)
+        }
+        _ => None,
+    }
+}
+
+/// `(const Shape *shape) const double`: parameters and trailing qualifiers,
+/// then the return type (constructors and destructors have none).
+fn build_fn_signature(node: Node, declarator: Node, func_decl: Node, source: &[u8]) -> String {
+    let name_end = func_decl
+        .child_by_field_name("declarator")
+        .map(|n| n.end_byte())
+        .unwrap_or(func_decl.start_byte());
+    let params = source
+        .get(name_end..func_decl.end_byte())
+        .map(|b| String::from_utf8_lossy(b).trim().to_string())
+        .unwrap_or_default();
+
+    let mut sig = String::new();
+    if let Some(template) = node.parent().filter(|p| p.kind() == "template_declaration") {
+        if let Some(tp) = template.child_by_field_name("parameters") {
+            sig.push_str(&format!("template {} ", text(tp, source)));
+        }
+    }
+    sig.push_str(&params);
+    if let Some(ret) = node.child_by_field_name("type") {
+        sig.push_str(&format!(
+            " {}{}",
+            text(ret, source),
+            declarator_suffix(declarator)
+        ));
+    }
+    sig
+}
+
+/// Function definitions and prototypes. A prototype gets a `Definition` ref to its
+/// qualified name, so it can be linked to the body in another file.
+fn extract_function(
+    node: Node,
+    source: &[u8],
+    scope: &Scope,
+    has_body: bool,
+) -> Option<ExtractedSymbol> {
+    let declarator = node.child_by_field_name("declarator")?;
+    let func_decl = unwrap_declarator(declarator);
+    if func_decl.kind() != "function_declarator" {
+        return None;
+    }
+    let name = declarator_name(func_decl, source)?;
+    let qualified_name = name.contains("::");
+
+    let mut refs = Vec::new();
+    if let Some(ret) = node.child_by_field_name("type") {
+        collect_type_refs(ret, source, &scope.generics, &mut refs);
+    }
+    if let Some(params) = func_decl.child_by_field_name("parameters") {
+        collect_type_refs(params, source, &scope.generics, &mut refs);
+    }
+    if let Some(body) = node.child_by_field_name("body") {
+        extract_calls(body, source, &mut refs);
+    }
+    let is_pure_or_defaulted = node.child_by_field_name("default_value").is_some()
+        || has_child_kind(node, "default_method_clause")
+        || has_child_kind(node, "delete_method_clause");
+    if !has_body && !is_pure_or_defaulted {
+        refs.push(ExtractedRef {
+            name: scope.qualify(&name),
+            kind: RefKind::Definition,
+            line: node.start_position().row + 1,
+        });
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: if scope.in_class || qualified_name {
+            SymbolKind::Method
+        } else {
+            SymbolKind::Fn
+        },
+        signature: Some(build_fn_signature(node, declarator, func_decl, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+fn has_child_kind(node: Node, kind: &str) -> bool {
+    let mut cursor = node.walk();
+    node.children(&mut cursor).any(|c| c.kind() == kind)
+}
+
+fn has_specifier(node: Node, source: &[u8], specifier: &str) -> bool {
+    let mut cursor = node.walk();
+    node.children(&mut cursor).any(|c| {
+        matches!(c.kind(), "storage_class_specifier" | "type_qualifier")
+            && text(c, source) == specifier
+    })
+}
+
+/// Prototypes, variables and data members. One declaration may introduce several names
+/// (`int x, y;`) and may also define a tagged type (`struct T { ... } t;`).
+fn extract_declaration(
+    node: Node,
+    source: &[u8],
+    scope: &Scope,
+    symbols: &mut Vec<ExtractedSymbol>,
+) {
+    let type_node = node.child_by_field_name("type");
+    if let Some(t) = type_node {
+        if t.child_by_field_name("body").is_some() {
+            if let Some(sym) = extract_type_specifier(t, source, scope, None) {
+                symbols.push(sym);
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    let declarators: Vec<Node> = node
+        .children_by_field_name("declarator", &mut cursor)
+        .collect();
+
+    if let [single] = declarators.as_slice() {
+        if unwrap_declarator(*single).kind() == "function_declarator" {
+            if let Some(sym) = extract_function(node, source, scope, false) {
+                symbols.push(sym);
+            }
+            return;
+        }
+    }
+
+    let is_extern = has_specifier(node, source, "extern");
+    let is_const = has_specifier(node, source, "const") || has_specifier(node, source, "constexpr");
+    let doc = get_doc_comment(node, source);
+
+    let mut type_refs = Vec::new();
+    if let Some(t) = type_node {
+        collect_type_refs(t, source, &scope.generics, &mut type_refs);
+    }
+
+    for declarator in declarators {
+        let Some(name) = declarator_name(declarator, source) else {
+            continue;
+        };
+        let mut refs = type_refs.clone();
+        if let Some(value) = unwrap_declarator(declarator).child_by_field_name("value") {
+            extract_calls(value, source, &mut refs);
+        }
+        if is_extern {
+            refs.push(ExtractedRef {
+                name: scope.qualify(&name),
+                kind: RefKind::Definition,
+                line: node.start_position().row + 1,
+            });
+        }
+        symbols.push(ExtractedSymbol {
+            name,
+            kind: if is_const {
+                SymbolKind::Const
+            } else {
+                SymbolKind::Var
+            },
+            signature: type_node
+                .map(|t| format!("{}{}", text(t, source), declarator_suffix(declarator))),
+            doc_comment: doc.clone(),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            children: Vec::new(),
+            references: refs,
+            metadata: Vec::new(),
+        });
+    }
+}
+
+/// `struct`/`union`/`class`/`enum` with a body. `typedef_name` names an anonymous
+/// type declared through `typedef struct { ... } Name;`.
+fn extract_type_specifier(
+    node: Node,
+    source: &[u8],
+    scope: &Scope,
+    typedef_name: Option<String>,
+) -> Option<ExtractedSymbol> {
+    let body = node.child_by_field_name("body")?;
+    let name = node
+        .child_by_field_name("name")
+        .map(|n| text(n, source))
+        .or(typedef_name)?;
+    let kind = match node.kind() {
+        "class_specifier" => SymbolKind::Class,
+        "enum_specifier" => SymbolKind::Enum,
+        _ => SymbolKind::Struct,
+    };
+
+    let mut refs = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "base_class_clause" {
+            let mut base_cursor = child.walk();
+            for base in child.named_children(&mut base_cursor) {
+                if let Some(base_name) = base_type_name(base, source) {
+                    refs.push(ExtractedRef {
+                        name: base_name,
+                        kind: RefKind::Extends,
+                        line: base.start_position().row + 1,
+                    });
+                }
+            }
+        }
+    }
+
+    let mut children = Vec::new();
+    if kind == SymbolKind::Enum {
+        let mut body_cursor = body.walk();
+        for enumerator in body.named_children(&mut body_cursor) {
+            if enumerator.kind() != "enumerator" {
+                continue;
+            }
+            if let Some(n) = enumerator.child_by_field_name("name") {
+                children.push(ExtractedSymbol {
+                    name: text(n, source),
+                    kind: SymbolKind::Const,
+                    signature: enumerator
+                        .child_by_field_name("value")
+                        .map(|v| text(v, source)),
+                    doc_comment: get_doc_comment(enumerator, source),
+                    start_line: enumerator.start_position().row + 1,
+                    end_line: enumerator.end_position().row + 1,
+                    children: Vec::new(),
+                    references: Vec::new(),
+                    metadata: Vec::new(),
+                });
+            }
+        }
+    } else {
+        let inner = scope.enter(&name, true);
+        let mut includes = Vec::new();
+        extract_block(body, source, &inner, &mut includes, &mut children);
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind,
+        signature: None,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children,
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// `typedef struct { ... } Point;` names the struct itself; `typedef struct Shape Shape;`
+/// adds nothing new; anything else is a type alias.
+fn extract_typedef(node: Node, source: &[u8], scope: &Scope, symbols: &mut Vec<ExtractedSymbol>) {
+    let Some(type_node) = node.child_by_field_name("type") else {
+        return;
+    };
+    let mut cursor = node.walk();
+    let declarators: Vec<Node> = node
+        .children_by_field_name("declarator", &mut cursor)
+        .collect();
+    let names: Vec<String> = declarators
+        .iter()
+        .filter_map(|d| declarator_name(*d, source))
+        .collect();
+    let tag = type_node
+        .child_by_field_name("name")
+        .map(|n| text(n, source));
+
+    if type_node.child_by_field_name("body").is_some() {
+        let typedef_name = if tag.is_none() {
+            names.first().cloned()
+        } else {
+            None
+        };
+        if let Some(mut sym) = extract_type_specifier(type_node, source, scope, typedef_name) {
+            sym.doc_comment = sym.doc_comment.or_else(|| get_doc_comment(node, source));
+            sym.start_line = node.start_position().row + 1;
+            sym.end_line = node.end_position().row + 1;
+            symbols.push(sym);
+        }
+    }
+
+    for (declarator, name) in declarators.iter().zip(names) {
+        if tag.as_deref() == Some(name.as_str()) {
+            continue;
+        }
+        if tag.is_none() && type_node.child_by_field_name("body").is_some() {
+            // Already emitted as the struct/enum itself
+            continue;
+        }
+        let mut refs = Vec::new();
+        collect_type_refs(type_node, source, &scope.generics, &mut refs);
+        let signature = if unwrap_declarator(*declarator).kind() == "type_identifier" {
+            format!(
+                "{}{}",
+                text(type_node, source),
+                declarator_suffix(*declarator)
+            )
+        } else {
+            text(node, source)
+                .trim_start_matches("typedef")
+                .trim_end_matches(';')
+                .trim()
+                .to_string()
+        };
+        symbols.push(ExtractedSymbol {
+            name,
+            kind: SymbolKind::Type,
+            signature: Some(signature),
+            doc_comment: get_doc_comment(node, source),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            children: Vec::new(),
+            references: refs,
+            metadata: Vec::new(),
+        });
+    }
+}
+
+/// C++ `using Name = Type;`
+fn extract_alias(node: Node, source: &[u8], scope: &Scope) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let value = node.child_by_field_name("type")?;
+
+    let mut refs = Vec::new();
+    collect_type_refs(value, source, &scope.generics, &mut refs);
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Type,
+        signature: Some(text(value, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+fn template_parameter_names(node: Node, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    if let Some(list) = node.child_by_field_name("parameters") {
+        let mut cursor = list.walk();
+        for param in list.named_children(&mut cursor) {
+            let mut param_cursor = param.walk();
+            let name = param
+                .named_children(&mut param_cursor)
+                .find(|c| matches!(c.kind(), "type_identifier" | "identifier"))
+                .or_else(|| param.child_by_field_name("name"));
+            if let Some(n) = name {
+                names.push(text(n, source));
+            }
+        }
+    }
+    names
+}
+
+/// The named type behind qualification and template arguments: `geo::Shape<T>` → `Shape`.
+fn base_type_name(node: Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "type_identifier" => Some(text(node, source)),
+        "qualified_identifier" => base_type_name(node.child_by_field_name("name")?, source),
+        "template_type" => base_type_name(node.child_by_field_name("name")?, source),
+        "struct_specifier" | "union_specifier" | "class_specifier" | "enum_specifier" => {
+            node.child_by_field_name("name").map(|n| text(n, source))
+        }
+        _ => None,
+    }
+}
+
+fn collect_type_refs(node: Node, source: &[u8], generics: &[String], refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "type_identifier" => {
+            let name = text(node, source);
+            if !generics.contains(&name) {
+                push_type_ref(name, node, refs);
+            }
+        }
+        // Standard library types never resolve in-repo; their template arguments might
+        "qualified_identifier"
+            if node
+                .child_by_field_name("scope")
+                .is_some_and(|s| text(s, source) == "std") =>
+        {
+            if let Some(args) = node
+                .child_by_field_name("name")
+                .and_then(|n| n.child_by_field_name("arguments"))
+            {
+                collect_type_refs(args, source, generics, refs);
+            }
+        }
+        "struct_specifier" | "union_specifier" | "class_specifier" | "enum_specifier" => {
+            if node.child_by_field_name("body").is_none() {
+                if let Some(name) = node.child_by_field_name("name") {
+                    push_type_ref(text(name, source), node, refs);
+                }
+            }
+        }
+        // Default argument values aren't types
+        "optional_parameter_declaration" => {
+            if let Some(t) = node.child_by_field_name("type") {
+                collect_type_refs(t, source, generics, refs);
+            }
+        }
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, source, generics, refs);
+            }
+        }
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
+
+fn extract_calls(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    extract_calls_recursive(&mut cursor, source, refs);
+}
+
+fn extract_calls_recursive(cursor: &mut TreeCursor, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    loop {
+        let node = cursor.node();
+        let name = match node.kind() {
+            "call_expression" => node.child_by_field_name("function").map(|func| {
+                match func.kind() {
+                    // `shape->area()` and `shape.area()` → `shape.area`, `this->f()` → `this.f`
+                    "field_expression" => {
+                        let object = func
+                            .child_by_field_name("argument")
+                            .map(|n| text(n, source));
+                        let field = func.child_by_field_name("field").map(|n| text(n, source));
+                        match (object, field) {
+                            (Some(o), Some(f)) => format!("{o}.{f}"),
+                            (None, Some(f)) => f,
+                            _ => text(func, source),
+                        }
+                    }
+                    "template_function" => func
+                        .child_by_field_name("name")
+                        .map(|n| text(n, source))
+                        .unwrap_or_default(),
+                    _ => text(func, source),
+                }
+            }),
+            "new_expression" => node
+                .child_by_field_name("type")
+                .and_then(|t| base_type_name(t, source)),
+            _ => None,
+        };
+        if let Some(name) = name.filter(|n| !n.is_empty()) {
+            refs.push(ExtractedRef {
+                name,
+                kind: RefKind::Call,
+                line: node.start_position().row + 1,
+            });
+        }
+
+        if cursor.goto_first_child() {
+            extract_calls_recursive(cursor, source, refs);
+            cursor.goto_parent();
+        }
+
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,819 @@
+use tree_sitter::{Node, Tree, TreeCursor};
+
+use super::LanguageExtractor;
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+pub struct CSharpExtractor;
+
+impl LanguageExtractor for CSharpExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_c_sharp::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["cs"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let mut symbols = Vec::new();
+        let mut usings = Vec::new();
+        extract_declarations(tree.root_node(), source, &mut usings, &mut symbols);
+
+        if !usings.is_empty() {
+            let start_line = usings.iter().map(|r| r.line).min().unwrap_or(1);
+            let end_line = usings.iter().map(|r| r.line).max().unwrap_or(1);
+            symbols.insert(
+                0,
+                ExtractedSymbol {
+                    name: "_imports".to_string(),
+                    kind: SymbolKind::Mod,
+                    signature: None,
+                    doc_comment: None,
+                    start_line,
+                    end_line,
+                    children: Vec::new(),
+                    references: usings,
+                    metadata: Vec::new(),
+                },
+            );
+        }
+        symbols
+    }
+}
+
+/// BCL types that never resolve in-repo — not worth a `TypeRef`.
+/// Keywords like `string` and `int` are `predefined_type` nodes and skipped anyway.
+const BUILTIN_TYPES: &[&str] = &[
+    "Action",
+    "DateTime",
+    "Dictionary",
+    "EventArgs",
+    "Exception",
+    "Func",
+    "Guid",
+    "HashSet",
+    "ICollection",
+    "IDictionary",
+    "IEnumerable",
+    "IList",
+    "List",
+    "Nullable",
+    "Object",
+    "String",
+    "Task",
+    "TimeSpan",
+    "ValueTask",
+];
+
+/// Framework attributes with no in-repo declaration to link to.
+const BUILTIN_ATTRIBUTES: &[&str] = &[
+    "AttributeUsage",
+    "Conditional",
+    "DllImport",
+    "Flags",
+    "Obsolete",
+    "Serializable",
+];
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+/// Declarations in a compilation unit or namespace body. A file-scoped namespace
+/// (`namespace Foo;`) owns every declaration that follows it.
+fn extract_declarations(
+    node: Node,
+    source: &[u8],
+    usings: &mut Vec<ExtractedRef>,
+    symbols: &mut Vec<ExtractedSymbol>,
+) {
+    let mut file_namespace: Option<ExtractedSymbol> = None;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        let target = match file_namespace.as_mut() {
+            Some(ns) => &mut ns.children,
+            None => &mut *symbols,
+        };
+        match child.kind() {
+            "using_directive" => {
+                if let Some(r) = extract_using(child, source) {
+                    usings.push(r);
+                }
+            }
+            "file_scoped_namespace_declaration" => {
+                if let Some(name) = child.child_by_field_name("name") {
+                    file_namespace = Some(ExtractedSymbol {
+                        name: text(name, source),
+                        kind: SymbolKind::Mod,
+                        signature: None,
+                        doc_comment: get_doc_comment(child, source),
+                        start_line: child.start_position().row + 1,
+                        end_line: node.end_position().row + 1,
+                        children: Vec::new(),
+                        references: Vec::new(),
+                        metadata: Vec::new(),
+                    });
+                }
+            }
+            "namespace_declaration" => {
+                let Some(name) = child.child_by_field_name("name") else {
+                    continue;
+                };
+                let mut children = Vec::new();
+                if let Some(body) = child.child_by_field_name("body") {
+                    extract_declarations(body, source, usings, &mut children);
+                }
+                target.push(ExtractedSymbol {
+                    name: text(name, source),
+                    kind: SymbolKind::Mod,
+                    signature: None,
+                    doc_comment: get_doc_comment(child, source),
+                    start_line: child.start_position().row + 1,
+                    end_line: child.end_position().row + 1,
+                    children,
+                    references: Vec::new(),
+                    metadata: Vec::new(),
+                });
+            }
+            _ => {
+                if let Some(sym) = extract_type_declaration(child, source, &[]) {
+                    target.push(sym);
+                }
+            }
+        }
+    }
+
+    if let Some(ns) = file_namespace {
+        symbols.push(ns);
+    }
+}
+
+/// `using System.Text;`, `using static System.Math;` and `using Geo = Example.Geometry;`
+/// become an `Import` ref to the namespace or type. Aliases keep the target.
+fn extract_using(node: Node, source: &[u8]) -> Option<ExtractedRef> {
+    let alias = node.child_by_field_name("name").map(|n| n.id());
+    let mut cursor = node.walk();
+    let target = node
+        .named_children(&mut cursor)
+        .filter(|c| Some(c.id()) != alias)
+        .find(|c| matches!(c.kind(), "qualified_name" | "identifier" | "generic_name"))?;
+    Some(ExtractedRef {
+        name: text(target, source),
+        kind: RefKind::Import,
+        line: node.start_position().row + 1,
+    })
+}
+
+/// The `///` block directly above a declaration, with its XML reduced to plain text.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut sibling = node.prev_sibling();
+    while let Some(s) = sibling {
+        if s.kind() != "comment" || s.end_position().row + 1 != expected_row {
+            break;
+        }
+        let t = text(s, source);
+        match t.strip_prefix("///") {
+            Some(stripped) => lines.push(stripped.trim().to_string()),
+            None => break,
+        }
+        expected_row = s.start_position().row;
+        sibling = s.prev_sibling();
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    xml_doc_to_text(&lines.join("\n"))
+}
+
+/// Reduces an XML doc comment to its `<summary>` (or the whole comment when there is
+/// none), inlining `<see cref="X"/>`/`<paramref name="x"/>` and dropping other markup.
+fn xml_doc_to_text(xml: &str) -> Option<String> {
+    let body = match (xml.find("<summary>"), xml.find("</summary>")) {
+        (Some(start), Some(end)) if start < end => &xml[start + "<summary>".len()..end],
+        _ => xml,
+    };
+
+    let mut out = String::new();
+    let mut rest = body;
+    while let Some(open) = rest.find('<') {
+        out.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('>') else {
+            rest = &rest[open..];
+            break;
+        };
+        let tag = &rest[open + 1..open + close];
+        // Self-closing references carry their target in an attribute
+        if tag.ends_with('/') {
+            if let Some(value) = ["cref", "name", "langword", "href"]
+                .iter()
+                .find_map(|attr| xml_attribute(tag, attr))
+            {
+                // `cref="T:Example.Circle"` → `Example.Circle`
+                let value = match value.split_once(':') {
+                    Some((prefix, v)) if prefix.len() == 1 => v,
+                    _ => value,
+                };
+                out.push_str(value);
+            }
+        }
+        rest = &rest[open + close + 1..];
+    }
+    out.push_str(rest);
+
+    let cleaned = out
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+    let cleaned = cleaned
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if cleaned.is_empty() {
+        None
+    } else {
+        Some(cleaned)
+    }
+}
+
+fn xml_attribute<'a>(tag: &'a str, attr: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{attr}=\""))? + attr.len() + 2;
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+fn has_modifier(node: Node, source: &[u8], modifier: &str) -> bool {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .any(|c| c.kind() == "modifier" && text(c, source) == modifier)
+}
+
+/// Classes, records, structs, interfaces, enums and delegates, at any nesting depth.
+fn extract_type_declaration(
+    node: Node,
+    source: &[u8],
+    outer_generics: &[String],
+) -> Option<ExtractedSymbol> {
+    let kind = match node.kind() {
+        "class_declaration" => SymbolKind::Class,
+        "struct_declaration" | "record_declaration" | "record_struct_declaration" => {
+            SymbolKind::Struct
+        }
+        "interface_declaration" => SymbolKind::Interface,
+        "enum_declaration" => SymbolKind::Enum,
+        "delegate_declaration" => return extract_delegate(node, source, outer_generics),
+        _ => return None,
+    };
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let mut generics = outer_generics.to_vec();
+    generics.extend(type_parameter_names(node, source));
+
+    let mut refs = Vec::new();
+    extract_attribute_refs(node, source, &mut refs);
+    let mut signature = None;
+    let mut children = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "base_list" => extract_base_list(child, source, node.kind(), &mut refs),
+            // Positional records: `record Point(double X, double Y)`
+            "parameter_list" => {
+                signature = Some(text(child, source));
+                extract_record_parameters(child, source, &generics, &mut children, &mut refs);
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(body) = node.child_by_field_name("body") {
+        extract_members(body, source, &name, &generics, &mut children);
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind,
+        signature,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children,
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// C# lists the base class and interfaces together. Interfaces extend whatever they
+/// list; structs can only implement; for classes and records the first entry is the
+/// base class unless it follows the `IName` interface convention.
+fn extract_base_list(
+    node: Node,
+    source: &[u8],
+    declaration_kind: &str,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    let mut cursor = node.walk();
+    for (i, base) in node.named_children(&mut cursor).enumerate() {
+        let Some(name) = base_type_name(base, source) else {
+            continue;
+        };
+        let ref_kind = match declaration_kind {
+            "interface_declaration" => RefKind::Extends,
+            "class_declaration" | "record_declaration" if i == 0 && !is_interface_name(&name) => {
+                RefKind::Extends
+            }
+            _ => RefKind::Implements,
+        };
+        refs.push(ExtractedRef {
+            name,
+            kind: ref_kind,
+            line: base.start_position().row + 1,
+        });
+    }
+}
+
+fn is_interface_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next() == Some('I') && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+fn extract_record_parameters(
+    params: Node,
+    source: &[u8],
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        if param.kind() != "parameter" {
+            continue;
+        }
+        let (Some(name), Some(type_node)) = (
+            param.child_by_field_name("name"),
+            param.child_by_field_name("type"),
+        ) else {
+            continue;
+        };
+        collect_type_refs(type_node, source, generics, refs);
+        children.push(ExtractedSymbol {
+            name: text(name, source),
+            kind: SymbolKind::Var,
+            signature: Some(text(type_node, source)),
+            doc_comment: None,
+            start_line: param.start_position().row + 1,
+            end_line: param.end_position().row + 1,
+            children: Vec::new(),
+            references: Vec::new(),
+            metadata: Vec::new(),
+        });
+    }
+}
+
+fn extract_members(
+    body: Node,
+    source: &[u8],
+    type_name: &str,
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+) {
+    let mut cursor = body.walk();
+    for member in body.children(&mut cursor) {
+        match member.kind() {
+            "method_declaration" | "constructor_declaration" => {
+                if let Some(sym) = extract_method(member, source, type_name, generics) {
+                    children.push(sym);
+                }
+            }
+            "property_declaration" => {
+                if let Some(sym) = extract_property(member, source, generics) {
+                    children.push(sym);
+                }
+            }
+            "field_declaration" | "event_field_declaration" => {
+                extract_field(member, source, generics, children);
+            }
+            "event_declaration" => {
+                if let Some(sym) = extract_event(member, source, generics) {
+                    children.push(sym);
+                }
+            }
+            "enum_member_declaration" => {
+                if let Some(name) = member.child_by_field_name("name") {
+                    children.push(ExtractedSymbol {
+                        name: text(name, source),
+                        kind: SymbolKind::Const,
+                        signature: member.child_by_field_name("value").map(|v| text(v, source)),
+                        doc_comment: get_doc_comment(member, source),
+                        start_line: member.start_position().row + 1,
+                        end_line: member.end_position().row + 1,
+                        children: Vec::new(),
+                        references: Vec::new(),
+                        metadata: Vec::new(),
+                    });
+                }
+            }
+            _ => {
+                if let Some(sym) = extract_type_declaration(member, source, generics) {
+                    children.push(sym);
+                }
+            }
+        }
+    }
+}
+
+fn build_method_signature(node: Node, source: &[u8]) -> String {
+    let type_params = node
+        .child_by_field_name("type_parameters")
+        .map(|n| text(n, source))
+        .unwrap_or_default();
+    let params = node
+        .child_by_field_name("parameters")
+        .map(|n| text(n, source))
+        .unwrap_or_else(|| "()".to_string());
+    let mut sig = format!("{type_params}{params}");
+    if let Some(ret) = node
+        .child_by_field_name("returns")
+        .or_else(|| node.child_by_field_name("type"))
+    {
+        sig.push_str(&format!(" {}", text(ret, source)));
+    }
+    sig
+}
+
+fn extract_method(
+    node: Node,
+    source: &[u8],
+    type_name: &str,
+    type_generics: &[String],
+) -> Option<ExtractedSymbol> {
+    let name = match node.kind() {
+        // Constructors are listed under the type name, as callers see them
+        "constructor_declaration" => type_name.to_string(),
+        _ => node.child_by_field_name("name").map(|n| text(n, source))?,
+    };
+    let mut generics = type_generics.to_vec();
+    generics.extend(type_parameter_names(node, source));
+
+    let mut refs = Vec::new();
+    extract_attribute_refs(node, source, &mut refs);
+    for field in ["returns", "parameters"] {
+        if let Some(n) = node.child_by_field_name(field) {
+            collect_type_refs(n, source, &generics, &mut refs);
+        }
+    }
+    if let Some(body) = node.child_by_field_name("body") {
+        extract_calls(body, source, &mut refs);
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Method,
+        signature: Some(build_method_signature(node, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// `delegate void Handler(object sender)` declares a method-shaped type.
+fn extract_delegate(
+    node: Node,
+    source: &[u8],
+    outer_generics: &[String],
+) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let mut generics = outer_generics.to_vec();
+    generics.extend(type_parameter_names(node, source));
+
+    let mut refs = Vec::new();
+    extract_attribute_refs(node, source, &mut refs);
+    for field in ["type", "parameters"] {
+        if let Some(n) = node.child_by_field_name(field) {
+            collect_type_refs(n, source, &generics, &mut refs);
+        }
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Type,
+        signature: Some(format!("delegate {}", build_method_signature(node, source))),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+fn extract_property(node: Node, source: &[u8], generics: &[String]) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let type_node = node.child_by_field_name("type")?;
+
+    let mut refs = Vec::new();
+    extract_attribute_refs(node, source, &mut refs);
+    collect_type_refs(type_node, source, generics, &mut refs);
+    for field in ["accessors", "value"] {
+        if let Some(n) = node.child_by_field_name(field) {
+            extract_calls(n, source, &mut refs);
+        }
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Var,
+        signature: Some(text(type_node, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// `event Handler Changed { add { ... } remove { ... } }`
+fn extract_event(node: Node, source: &[u8], generics: &[String]) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let type_node = node.child_by_field_name("type")?;
+
+    let mut refs = Vec::new();
+    collect_type_refs(type_node, source, generics, &mut refs);
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Var,
+        signature: Some(format!("event {}", text(type_node, source))),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// Fields and field-like events; one declaration may introduce several names.
+/// `const` and `static readonly` fields are `Const`, the rest `Var`.
+fn extract_field(
+    node: Node,
+    source: &[u8],
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+) {
+    let mut cursor = node.walk();
+    let Some(decl) = node
+        .children(&mut cursor)
+        .find(|c| c.kind() == "variable_declaration")
+    else {
+        return;
+    };
+    let Some(type_node) = decl.child_by_field_name("type") else {
+        return;
+    };
+    let is_event = node.kind() == "event_field_declaration";
+    let kind = if has_modifier(node, source, "const")
+        || (has_modifier(node, source, "static") && has_modifier(node, source, "readonly"))
+    {
+        SymbolKind::Const
+    } else {
+        SymbolKind::Var
+    };
+    let type_sig = text(type_node, source);
+    let signature = if is_event {
+        format!("event {type_sig}")
+    } else {
+        type_sig
+    };
+    let doc = get_doc_comment(node, source);
+
+    let mut refs = Vec::new();
+    extract_attribute_refs(node, source, &mut refs);
+    collect_type_refs(type_node, source, generics, &mut refs);
+
+    let mut decl_cursor = decl.walk();
+    for declarator in decl.named_children(&mut decl_cursor) {
+        if declarator.kind() != "variable_declarator" {
+            continue;
+        }
+        let Some(name) = declarator.child_by_field_name("name") else {
+            continue;
+        };
+        let mut decl_refs = refs.clone();
+        extract_calls(declarator, source, &mut decl_refs);
+        children.push(ExtractedSymbol {
+            name: text(name, source),
+            kind,
+            signature: Some(signature.clone()),
+            doc_comment: doc.clone(),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            children: Vec::new(),
+            references: decl_refs,
+            metadata: Vec::new(),
+        });
+    }
+}
+
+/// Attribute usages (`[Audited]`, `[Table("t")]`) link to the attribute class, which is
+/// declared with the conventional `Attribute` suffix.
+fn extract_attribute_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    for list in node.children(&mut cursor) {
+        if list.kind() != "attribute_list" {
+            continue;
+        }
+        let mut list_cursor = list.walk();
+        for attr in list.named_children(&mut list_cursor) {
+            if attr.kind() != "attribute" {
+                continue;
+            }
+            let Some(name) = attr
+                .child_by_field_name("name")
+                .and_then(|n| base_type_name(n, source))
+            else {
+                continue;
+            };
+            let short = name.strip_suffix("Attribute").unwrap_or(&name);
+            if BUILTIN_ATTRIBUTES.contains(&short) {
+                continue;
+            }
+            push_type_ref(format!("{short}Attribute"), attr, refs);
+        }
+    }
+}
+
+fn type_parameter_names(node: Node, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() != "type_parameter_list" {
+            continue;
+        }
+        let mut list_cursor = child.walk();
+        for param in child.named_children(&mut list_cursor) {
+            if param.kind() != "type_parameter" {
+                continue;
+            }
+            if let Some(name) = param.child_by_field_name("name") {
+                names.push(text(name, source));
+            }
+        }
+    }
+    names
+}
+
+/// The named type behind generics, nullability and qualification: `Geo.Shape<T>?` → `Shape`.
+fn base_type_name(node: Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "identifier" => Some(text(node, source)),
+        "generic_name" => {
+            let mut cursor = node.walk();
+            node.named_children(&mut cursor)
+                .find(|c| c.kind() == "identifier")
+                .map(|n| text(n, source))
+        }
+        "qualified_name" => base_type_name(node.child_by_field_name("name")?, source),
+        "nullable_type" => base_type_name(node.child_by_field_name("type")?, source),
+        "primary_constructor_base_type" => {
+            base_type_name(node.child_by_field_name("type")?, source)
+        }
+        _ => None,
+    }
+}
+
+fn collect_type_refs(node: Node, source: &[u8], generics: &[String], refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "identifier" | "qualified_name" => {
+            if let Some(name) = base_type_name(node, source) {
+                if !BUILTIN_TYPES.contains(&name.as_str()) && !generics.contains(&name) {
+                    push_type_ref(name, node, refs);
+                }
+            }
+        }
+        "generic_name" => {
+            if let Some(name) = base_type_name(node, source) {
+                if !BUILTIN_TYPES.contains(&name.as_str()) && !generics.contains(&name) {
+                    push_type_ref(name, node, refs);
+                }
+            }
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                if child.kind() == "type_argument_list" {
+                    collect_type_refs(child, source, generics, refs);
+                }
+            }
+        }
+        // Only the declared type of a parameter, not its name, default value or attributes
+        "parameter" => {
+            if let Some(t) = node.child_by_field_name("type") {
+                collect_type_refs(t, source, generics, refs);
+            }
+        }
+        "predefined_type" | "attribute_list" => {}
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, source, generics, refs);
+            }
+        }
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
+
+fn extract_calls(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    extract_calls_recursive(&mut cursor, source, refs);
+}
+
+fn extract_calls_recursive(cursor: &mut TreeCursor, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    loop {
+        let node = cursor.node();
+        let name = match node.kind() {
+            "invocation_expression" => node
+                .child_by_field_name("function")
+                .map(|func| callable_name(func, source)),
+            "object_creation_expression" => node
+                .child_by_field_name("type")
+                .and_then(|t| base_type_name(t, source)),
+            _ => None,
+        };
+        if let Some(name) = name.filter(|n| !n.is_empty()) {
+            refs.push(ExtractedRef {
+                name,
+                kind: RefKind::Call,
+                line: node.start_position().row + 1,
+            });
+        }
+
+        if cursor.goto_first_child() {
+            extract_calls_recursive(cursor, source, refs);
+            cursor.goto_parent();
+        }
+
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+}
+
+/// `Square(x)` → `Square`, `this.Area()` → `this.Area`, `Changed?.Invoke()` → `Changed.Invoke`.
+fn callable_name(func: Node, source: &[u8]) -> String {
+    match func.kind() {
+        "identifier" => text(func, source),
+        "generic_name" => base_type_name(func, source).unwrap_or_default(),
+        "member_access_expression" => {
+            let object = func
+                .child_by_field_name("expression")
+                .map(|n| text(n, source));
+            let member = func
+                .child_by_field_name("name")
+                .and_then(|n| base_type_name(n, source));
+            match (object, member) {
+                (Some(o), Some(m)) => format!("{o}.{m}"),
+                (None, Some(m)) => m,
+                _ => text(func, source),
+            }
+        }
+        "conditional_access_expression" => {
+            let object = func
+                .child_by_field_name("condition")
+                .map(|n| text(n, source));
+            let mut cursor = func.walk();
+            let member = func
+                .named_children(&mut cursor)
+                .find(|c| c.kind() == "member_binding_expression")
+                .and_then(|b| b.child_by_field_name("name"))
+                .and_then(|n| base_type_name(n, source));
+            match (object, member) {
+                (Some(o), Some(m)) => format!("{o}.{m}"),
+                _ => text(func, source),
+            }
+        }
+        _ => text(func, source),
+    }
+}
@@ -0,0 +1,326 @@
+use tree_sitter::{Node, Tree};
+
+use super::{FileContext, LanguageExtractor, openapi};
+use crate::indexer::{ExtractedSymbol, SymbolKind};
+
+/// Key depth used when no repo config is at hand.
+pub const DEFAULT_MAX_KEY_DEPTH: usize = 3;
+
+/// YAML, TOML and JSON files. Mapping keys become `Key` symbols nested like the
+/// document, down to `max_key_depth` levels. Arrays are not descended into, so
+/// data-heavy files stay bounded. OpenAPI documents are handed to `openapi`.
+pub struct DataExtractor;
+
+impl LanguageExtractor for DataExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_yaml::LANGUAGE.into()
+    }
+
+    fn language_for_ext(&self, ext: &str) -> tree_sitter::Language {
+        match ext {
+            "json" => tree_sitter_json::LANGUAGE.into(),
+            "toml" => tree_sitter_toml_ng::LANGUAGE.into(),
+            _ => tree_sitter_yaml::LANGUAGE.into(),
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json", "toml", "yaml", "yml"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        extract_keys(source, tree, DEFAULT_MAX_KEY_DEPTH)
+    }
+
+    fn extract_file(&self, file: &FileContext, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        extract_keys(source, tree, file.max_key_depth)
+    }
+}
+
+fn extract_keys(source: &[u8], tree: &Tree, max_depth: usize) -> Vec<ExtractedSymbol> {
+    let root = tree.root_node();
+    let mut symbols = Vec::new();
+    if max_depth == 0 {
+        return symbols;
+    }
+
+    if let Some(api) = openapi_document(root, source) {
+        return openapi::extract(&api);
+    }
+
+    if root.kind() == "stream" {
+        // Every YAML document (`---`) contributes top-level keys
+        let mut cursor = root.walk();
+        for document in root.named_children(&mut cursor) {
+            let mut doc_cursor = document.walk();
+            for node in document.named_children(&mut doc_cursor) {
+                yaml_mapping(node, source, 1, max_depth, &mut symbols);
+            }
+        }
+    } else if is_toml(root) {
+        toml_document(root, source, max_depth, &mut symbols);
+    } else {
+        let mut cursor = root.walk();
+        for value in root.named_children(&mut cursor) {
+            json_object(value, source, 1, max_depth, &mut symbols);
+        }
+    }
+    symbols
+}
+
+/// OpenAPI/Swagger documents are indexed as an API (operations and schemas)
+/// rather than as raw keys. TOML is never used for them.
+fn openapi_document<'t>(root: Node<'t>, source: &[u8]) -> Option<openapi::Value<'t>> {
+    let body = match root.kind() {
+        "stream" => root.named_child(0)?.named_child(0)?,
+        _ if is_toml(root) => return None,
+        _ => root.named_child(0)?,
+    };
+    let value = match root.kind() {
+        "stream" => openapi::from_yaml(body, source),
+        _ => openapi::from_json(body, source),
+    };
+    openapi::is_openapi(&value).then_some(value)
+}
+
+/// JSON and TOML grammars both call their root `document`; only TOML has pairs
+/// and tables directly under it.
+fn is_toml(root: Node) -> bool {
+    let mut cursor = root.walk();
+    root.named_children(&mut cursor)
+        .any(|c| matches!(c.kind(), "pair" | "table" | "table_array_element"))
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+fn key_symbol(name: String, node: Node, signature: Option<String>) -> ExtractedSymbol {
+    ExtractedSymbol {
+        name,
+        kind: SymbolKind::Key,
+        signature,
+        doc_comment: None,
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    }
+}
+
+/// The value shown next to a key: scalars without their quotes, single-line
+/// arrays as written. Nested mappings are represented by child keys instead.
+fn value_signature(value: Node, source: &[u8]) -> Option<String> {
+    let raw = text(value, source);
+    if raw.contains('\n') {
+        return None;
+    }
+    let unquoted = match raw.as_bytes().first() {
+        Some(b'"' | b'\'') if raw.len() >= 2 => raw[1..raw.len() - 1].to_string(),
+        _ => raw,
+    };
+    Some(unquoted)
+}
+
+// ── JSON ──
+
+fn json_object(
+    node: Node,
+    source: &[u8],
+    depth: usize,
+    max_depth: usize,
+    out: &mut Vec<ExtractedSymbol>,
+) {
+    if node.kind() != "object" {
+        return;
+    }
+    let mut cursor = node.walk();
+    for pair in node.named_children(&mut cursor) {
+        if pair.kind() != "pair" {
+            continue;
+        }
+        let (Some(key), Some(value)) = (
+            pair.child_by_field_name("key"),
+            pair.child_by_field_name("value"),
+        ) else {
+            continue;
+        };
+        let name = text(key, source).trim_matches('"').to_string();
+        let signature = match value.kind() {
+            "object" => None,
+            _ => value_signature(value, source),
+        };
+        let mut sym = key_symbol(name, pair, signature);
+        if depth < max_depth {
+            json_object(value, source, depth + 1, max_depth, &mut sym.children);
+        }
+        out.push(sym);
+    }
+}
+
+// ── YAML ──
+
+/// Unwraps `block_node`/`flow_node` to reach a mapping and emits its pairs.
+fn yaml_mapping(
+    node: Node,
+    source: &[u8],
+    depth: usize,
+    max_depth: usize,
+    out: &mut Vec<ExtractedSymbol>,
+) {
+    match node.kind() {
+        "block_node" | "flow_node" => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                yaml_mapping(child, source, depth, max_depth, out);
+            }
+        }
+        "block_mapping" | "flow_mapping" => {
+            let mut cursor = node.walk();
+            for pair in node.named_children(&mut cursor) {
+                if !matches!(pair.kind(), "block_mapping_pair" | "flow_pair") {
+                    continue;
+                }
+                let Some(key) = pair.child_by_field_name("key") else {
+                    continue;
+                };
+                let value = pair.child_by_field_name("value");
+                // Block scalars and sequences span lines; flow values read fine inline
+                let signature = value
+                    .filter(|v| v.kind() == "flow_node" && !yaml_is_mapping(*v))
+                    .and_then(|v| value_signature(v, source));
+                let mut sym = key_symbol(
+                    value_signature(key, source).unwrap_or_default(),
+                    pair,
+                    signature,
+                );
+                if let Some(value) = value.filter(|_| depth < max_depth) {
+                    yaml_mapping(value, source, depth + 1, max_depth, &mut sym.children);
+                }
+                out.push(sym);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn yaml_is_mapping(node: Node) -> bool {
+    match node.kind() {
+        "block_mapping" | "flow_mapping" => true,
+        "block_node" | "flow_node" => node.named_child(0).is_some_and(yaml_is_mapping),
+        _ => false,
+    }
+}
+
+// ── TOML ──
+
+/// Tables (`[a.b]`) and dotted keys (`a.b = 1`) can reopen a path declared
+/// elsewhere in the file, so keys are merged into one tree by path.
+fn toml_document(root: Node, source: &[u8], max_depth: usize, out: &mut Vec<ExtractedSymbol>) {
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        match child.kind() {
+            "pair" => toml_pair(child, source, max_depth, out),
+            "table" | "table_array_element" => {
+                let mut table_cursor = child.walk();
+                let Some(header) = child
+                    .named_children(&mut table_cursor)
+                    .find(|c| is_toml_key(c.kind()))
+                else {
+                    continue;
+                };
+                let path = toml_key_path(header, source);
+                // Each `[[bin]]` is its own entry rather than a reopened table
+                let is_array_element = child.kind() == "table_array_element";
+                let Some(table) = toml_entry(out, &path, child, max_depth, is_array_element) else {
+                    continue;
+                };
+                let mut pair_cursor = child.walk();
+                for pair in child.named_children(&mut pair_cursor) {
+                    if pair.kind() == "pair" {
+                        toml_pair(pair, source, max_depth - path.len(), &mut table.children);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn toml_pair(pair: Node, source: &[u8], max_depth: usize, out: &mut Vec<ExtractedSymbol>) {
+    if max_depth == 0 {
+        return;
+    }
+    let mut cursor = pair.walk();
+    let mut parts = pair.named_children(&mut cursor);
+    let (Some(key), Some(value)) = (parts.next(), parts.next()) else {
+        return;
+    };
+    let path = toml_key_path(key, source);
+    let Some(sym) = toml_entry(out, &path, pair, max_depth, false) else {
+        return;
+    };
+    if value.kind() == "inline_table" {
+        let mut inline_cursor = value.walk();
+        for inner in value.named_children(&mut inline_cursor) {
+            if inner.kind() == "pair" {
+                toml_pair(inner, source, max_depth - path.len(), &mut sym.children);
+            }
+        }
+    } else {
+        sym.signature = value_signature(value, source);
+    }
+}
+
+fn is_toml_key(kind: &str) -> bool {
+    matches!(kind, "bare_key" | "quoted_key" | "dotted_key")
+}
+
+/// `profile.release` → `["profile", "release"]`, `"a.b"` → `["a.b"]`
+fn toml_key_path(key: Node, source: &[u8]) -> Vec<String> {
+    if key.kind() == "dotted_key" {
+        let mut cursor = key.walk();
+        return key
+            .named_children(&mut cursor)
+            .flat_map(|part| toml_key_path(part, source))
+            .collect();
+    }
+    vec![text(key, source).trim_matches(['"', '\'']).to_string()]
+}
+
+/// Walks `path` from `out`, reusing keys that already exist and creating the
+/// rest, and returns the entry for the last segment. `None` once the path goes
+/// deeper than `max_depth`.
+fn toml_entry<'a>(
+    out: &'a mut Vec<ExtractedSymbol>,
+    path: &[String],
+    node: Node,
+    max_depth: usize,
+    always_new: bool,
+) -> Option<&'a mut ExtractedSymbol> {
+    let (first, rest) = path.split_first()?;
+    if max_depth == 0 {
+        return None;
+    }
+    let existing = out
+        .iter()
+        .position(|s| &s.name == first && !(always_new && rest.is_empty()));
+    let index = match existing {
+        Some(i) => {
+            let sym = &mut out[i];
+            sym.end_line = sym.end_line.max(node.end_position().row + 1);
+            i
+        }
+        None => {
+            out.push(key_symbol(first.clone(), node, None));
+            out.len() - 1
+        }
+    };
+    let entry = &mut out[index];
+    if rest.is_empty() {
+        Some(entry)
+    } else {
+        toml_entry(&mut entry.children, rest, node, max_depth - 1, always_new)
+    }
+}
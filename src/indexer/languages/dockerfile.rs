@@ -0,0 +1,188 @@
+use tree_sitter::{Node, Tree};
+
+use super::LanguageExtractor;
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+/// Dockerfiles and Containerfiles. Every `FROM` starts a build stage, named by its
+/// `AS` alias (or, when unnamed, by its index, as `COPY --from=0` names it), with
+/// the stage's `ARG`/`ENV` as variables. Building on an earlier stage is an
+/// `Extends` ref and `COPY --from=<stage>` an `Import` ref.
+pub struct DockerfileExtractor;
+
+impl LanguageExtractor for DockerfileExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_containerfile::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["dockerfile", "containerfile"]
+    }
+
+    fn filenames(&self) -> &[&str] {
+        &["dockerfile", "containerfile"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        extract_stages(tree.root_node(), source)
+    }
+}
+
+fn extract_stages(root: Node, source: &[u8]) -> Vec<ExtractedSymbol> {
+    let mut symbols = Vec::new();
+    let mut stages: Vec<ExtractedSymbol> = Vec::new();
+
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        match child.kind() {
+            "from_instruction" => {
+                if let Some(stage) = extract_stage(child, source, &stages) {
+                    stages.push(stage);
+                }
+            }
+            "comment" => {}
+            kind => {
+                if let Some(stage) = stages.last_mut() {
+                    stage.end_line = child.end_position().row + 1;
+                }
+                match kind {
+                    "arg_instruction" | "env_instruction" => {
+                        // `ARG`s before the first `FROM` are global build args
+                        let target = match stages.last_mut() {
+                            Some(stage) => &mut stage.children,
+                            None => &mut symbols,
+                        };
+                        extract_variables(child, source, target);
+                    }
+                    "copy_instruction" => {
+                        if let Some(stage_ref) = copy_from_ref(child, source, &stages)
+                            && let Some(stage) = stages.last_mut()
+                        {
+                            stage.references.push(stage_ref);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    symbols.extend(stages);
+    symbols
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+fn find_child<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).find(|c| c.kind() == kind)
+}
+
+/// Collects the contiguous `#` comment block directly above an instruction.
+/// Parser directives (`# syntax=...`) are not documentation.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut sibling = node.prev_named_sibling();
+    while let Some(s) = sibling {
+        // Comment nodes include their trailing newline
+        if s.kind() != "comment" || s.start_position().row + 1 != expected_row {
+            break;
+        }
+        let line = text(s, source).trim_start_matches('#').trim().to_string();
+        if is_directive(&line) {
+            break;
+        }
+        lines.push(line);
+        expected_row = s.start_position().row;
+        sibling = s.prev_named_sibling();
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
+fn is_directive(line: &str) -> bool {
+    ["syntax=", "escape=", "check="]
+        .iter()
+        .any(|d| line.to_ascii_lowercase().starts_with(d))
+}
+
+/// `FROM node:20 AS deps` → stage `deps`; `FROM deps AS build` also `Extends` deps.
+/// An unnamed stage is named by its index (`FROM golang` → `0`), so reusing an
+/// image or a stage doesn't repeat its name.
+fn extract_stage(node: Node, source: &[u8], stages: &[ExtractedSymbol]) -> Option<ExtractedSymbol> {
+    let image = find_child(node, "image_spec").map(|n| text(n, source))?;
+    let alias = node.child_by_field_name("as").map(|n| text(n, source));
+    let mut references = Vec::new();
+    // `FROM` names earlier stages by alias only
+    if image.parse::<usize>().is_err() && stages.iter().any(|s| s.name == image) {
+        references.push(ExtractedRef {
+            name: image.clone(),
+            kind: RefKind::Extends,
+            line: node.start_position().row + 1,
+        });
+    }
+
+    Some(ExtractedSymbol {
+        name: alias.unwrap_or_else(|| stages.len().to_string()),
+        kind: SymbolKind::Mod,
+        signature: Some(format!("FROM {image}")),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references,
+        metadata: Vec::new(),
+    })
+}
+
+/// `ARG NAME=default` and `ENV NAME=value` as variables, the value as signature.
+fn extract_variables(node: Node, source: &[u8], out: &mut Vec<ExtractedSymbol>) {
+    let mut cursor = node.walk();
+    for pair in node.named_children(&mut cursor) {
+        if !matches!(pair.kind(), "arg_pair" | "env_pair") {
+            continue;
+        }
+        let Some(name) = pair.child_by_field_name("name") else {
+            continue;
+        };
+        let value = pair
+            .child_by_field_name("default")
+            .or_else(|| pair.child_by_field_name("value"))
+            .map(|v| text(v, source));
+        out.push(ExtractedSymbol {
+            name: text(name, source),
+            kind: SymbolKind::Var,
+            signature: value,
+            doc_comment: get_doc_comment(node, source),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            children: Vec::new(),
+            references: Vec::new(),
+            metadata: Vec::new(),
+        });
+    }
+}
+
+/// `COPY --from=build ...` referencing an earlier stage by name or index.
+/// Copies from external images (`--from=alpine:3`) have no in-repo target.
+fn copy_from_ref(node: Node, source: &[u8], stages: &[ExtractedSymbol]) -> Option<ExtractedRef> {
+    let mut cursor = node.walk();
+    let from = node
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() == "param")
+        .find_map(|p| text(p, source).strip_prefix("--from=").map(str::to_string))?;
+    let stage = match from.parse::<usize>() {
+        Ok(index) => stages.get(index)?,
+        Err(_) => stages.iter().find(|s| s.name == from)?,
+    };
+    Some(ExtractedRef {
+        name: stage.name.clone(),
+        kind: RefKind::Import,
+        line: node.start_position().row + 1,
+    })
+}
@@ -0,0 +1,647 @@
+use tree_sitter::{Node, Tree, TreeCursor};
+
+use super::LanguageExtractor;
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+pub struct GoExtractor;
+
+impl LanguageExtractor for GoExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_go::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["go"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let mut symbols = Vec::new();
+        extract_top_level(tree.root_node(), source, &mut symbols);
+        symbols
+    }
+}
+
+/// Predeclared Go types — never worth a `TypeRef`.
+const BUILTIN_TYPES: &[&str] = &[
+    "any",
+    "bool",
+    "byte",
+    "comparable",
+    "complex64",
+    "complex128",
+    "error",
+    "float32",
+    "float64",
+    "int",
+    "int8",
+    "int16",
+    "int32",
+    "int64",
+    "rune",
+    "string",
+    "uint",
+    "uint8",
+    "uint16",
+    "uint32",
+    "uint64",
+    "uintptr",
+];
+
+/// A method waiting to be nested under its receiver type.
+struct PendingMethod {
+    receiver: String,
+    symbol: ExtractedSymbol,
+}
+
+fn extract_top_level(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbol>) {
+    let mut package: Option<ExtractedSymbol> = None;
+    let mut methods = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "package_clause" => {
+                package = extract_package(child, source);
+            }
+            "import_declaration" => {
+                if let Some(pkg) = package.as_mut() {
+                    extract_import_refs(child, source, &mut pkg.references);
+                    pkg.end_line = child.end_position().row + 1;
+                }
+            }
+            "function_declaration" => {
+                if let Some(sym) = extract_function(child, source) {
+                    symbols.push(sym);
+                }
+            }
+            "method_declaration" => {
+                if let Some(method) = extract_method(child, source) {
+                    methods.push(method);
+                }
+            }
+            "type_declaration" => {
+                extract_type_declaration(child, source, symbols);
+            }
+            "const_declaration" => {
+                extract_value_declaration(child, "const_spec", SymbolKind::Const, source, symbols);
+            }
+            "var_declaration" => {
+                extract_value_declaration(child, "var_spec", SymbolKind::Var, source, symbols);
+            }
+            _ => {}
+        }
+    }
+
+    attach_methods(methods, symbols);
+
+    if let Some(pkg) = package {
+        symbols.insert(0, pkg);
+    }
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+/// Collects the contiguous `//` comment block directly above a declaration.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut sibling = node.prev_sibling();
+    while let Some(s) = sibling {
+        if s.kind() != "comment" || s.end_position().row + 1 != expected_row {
+            break;
+        }
+        let t = text(s, source);
+        match t.strip_prefix("//") {
+            Some(stripped) => lines.push(stripped.trim().to_string()),
+            None => break,
+        }
+        expected_row = s.start_position().row;
+        sibling = s.prev_sibling();
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
+fn extract_package(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let mut cursor = node.walk();
+    let name = node
+        .children(&mut cursor)
+        .find(|c| c.kind() == "package_identifier")
+        .map(|n| text(n, source))?;
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Mod,
+        signature: None,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+/// `import "fmt"` and `import ( "fmt"; str "strings" )` become `Import` refs to the
+/// package path. Aliased imports keep the path, since that's what identifies the package.
+fn extract_import_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "import_spec" => {
+                if let Some(path) = child.child_by_field_name("path") {
+                    let name = text(path, source)
+                        .trim_matches('"')
+                        .trim_matches('`')
+                        .to_string();
+                    if !name.is_empty() {
+                        refs.push(ExtractedRef {
+                            name,
+                            kind: RefKind::Import,
+                            line: child.start_position().row + 1,
+                        });
+                    }
+                }
+            }
+            "import_spec_list" => extract_import_refs(child, source, refs),
+            _ => {}
+        }
+    }
+}
+
+fn build_fn_signature(node: Node, source: &[u8]) -> String {
+    let type_params = node
+        .child_by_field_name("type_parameters")
+        .map(|n| text(n, source))
+        .unwrap_or_default();
+    let params = node
+        .child_by_field_name("parameters")
+        .map(|n| text(n, source))
+        .unwrap_or_default();
+    let result = node
+        .child_by_field_name("result")
+        .map(|n| text(n, source))
+        .unwrap_or_default();
+    let mut sig = format!("{type_params}{params}");
+    if !result.is_empty() {
+        sig.push_str(&format!(" {result}"));
+    }
+    sig
+}
+
+fn extract_function(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let generics = type_parameter_names(node, source);
+
+    let mut refs = Vec::new();
+    extract_signature_type_refs(node, source, &generics, &mut refs);
+    if let Some(body) = node.child_by_field_name("body") {
+        extract_calls(body, source, &mut refs);
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Fn,
+        signature: Some(build_fn_signature(node, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+fn extract_method(node: Node, source: &[u8]) -> Option<PendingMethod> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let receiver_list = node.child_by_field_name("receiver")?;
+    let receiver = receiver_type_name(receiver_list, source)?;
+    let generics = receiver_type_arguments(receiver_list, source);
+
+    let mut refs = Vec::new();
+    extract_signature_type_refs(node, source, &generics, &mut refs);
+    if let Some(body) = node.child_by_field_name("body") {
+        extract_calls(body, source, &mut refs);
+    }
+
+    let signature = format!(
+        "{} {}",
+        text(receiver_list, source),
+        build_fn_signature(node, source)
+    );
+
+    Some(PendingMethod {
+        receiver,
+        symbol: ExtractedSymbol {
+            name,
+            kind: SymbolKind::Method,
+            signature: Some(signature),
+            doc_comment: get_doc_comment(node, source),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            children: Vec::new(),
+            references: refs,
+            metadata: Vec::new(),
+        },
+    })
+}
+
+/// The receiver's base type: `(c *Circle)` → `Circle`, `(s *Stack[T])` → `Stack`.
+fn receiver_type_name(receiver_list: Node, source: &[u8]) -> Option<String> {
+    let mut cursor = receiver_list.walk();
+    let param = receiver_list
+        .children(&mut cursor)
+        .find(|c| c.kind() == "parameter_declaration")?;
+    let mut type_node = param.child_by_field_name("type")?;
+    loop {
+        match type_node.kind() {
+            "pointer_type" => type_node = type_node.named_child(0)?,
+            "generic_type" => type_node = type_node.child_by_field_name("type")?,
+            "type_identifier" => return Some(text(type_node, source)),
+            _ => return None,
+        }
+    }
+}
+
+/// Type parameters bound by a generic receiver, e.g. `T` in `(s *Stack[T])`.
+fn receiver_type_arguments(receiver_list: Node, source: &[u8]) -> Vec<String> {
+    let mut cursor = receiver_list.walk();
+    let Some(mut type_node) = receiver_list
+        .children(&mut cursor)
+        .find(|c| c.kind() == "parameter_declaration")
+        .and_then(|p| p.child_by_field_name("type"))
+    else {
+        return Vec::new();
+    };
+    while type_node.kind() == "pointer_type" {
+        match type_node.named_child(0) {
+            Some(inner) => type_node = inner,
+            None => return Vec::new(),
+        }
+    }
+    let Some(args) = type_node.child_by_field_name("type_arguments") else {
+        return Vec::new();
+    };
+    let mut args_cursor = args.walk();
+    args.named_children(&mut args_cursor)
+        .map(|arg| text(arg, source))
+        .collect()
+}
+
+/// Nests each method under the type it's declared on. Methods whose receiver
+/// type lives in another file of the package stay at the top level.
+fn attach_methods(methods: Vec<PendingMethod>, symbols: &mut Vec<ExtractedSymbol>) {
+    for method in methods {
+        let owner = symbols.iter_mut().find(|s| {
+            s.name == method.receiver
+                && matches!(
+                    s.kind,
+                    SymbolKind::Struct | SymbolKind::Interface | SymbolKind::Type
+                )
+        });
+        match owner {
+            Some(owner) => owner.children.push(method.symbol),
+            None => symbols.push(method.symbol),
+        }
+    }
+}
+
+fn extract_type_declaration(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbol>) {
+    let mut cursor = node.walk();
+    let specs: Vec<Node> = node
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "type_spec" || c.kind() == "type_alias")
+        .collect();
+    let single = specs.len() == 1;
+
+    for spec in specs {
+        // A lone spec is documented by the comment above `type`; grouped specs by their own.
+        let doc_target = if single { node } else { spec };
+        if let Some(sym) = extract_type_spec(spec, doc_target, source) {
+            symbols.push(sym);
+        }
+    }
+}
+
+fn extract_type_spec(spec: Node, doc_target: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = spec.child_by_field_name("name").map(|n| text(n, source))?;
+    let type_node = spec.child_by_field_name("type")?;
+    let generics = type_parameter_names(spec, source);
+    let type_params = spec
+        .child_by_field_name("type_parameters")
+        .map(|n| text(n, source));
+
+    let mut children = Vec::new();
+    let mut refs = Vec::new();
+    let (kind, signature) = match type_node.kind() {
+        "struct_type" => {
+            extract_struct_fields(type_node, source, &generics, &mut children, &mut refs);
+            (SymbolKind::Struct, type_params)
+        }
+        "interface_type" => {
+            extract_interface_elems(type_node, source, &generics, &mut children, &mut refs);
+            (SymbolKind::Interface, type_params)
+        }
+        _ => {
+            collect_type_refs(type_node, source, &generics, &mut refs);
+            let value = text(type_node, source);
+            let sig = match type_params {
+                Some(tp) => format!("{tp} {value}"),
+                None => value,
+            };
+            (SymbolKind::Type, Some(sig))
+        }
+    };
+
+    Some(ExtractedSymbol {
+        name,
+        kind,
+        signature,
+        doc_comment: get_doc_comment(doc_target, source),
+        start_line: spec.start_position().row + 1,
+        end_line: spec.end_position().row + 1,
+        children,
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+fn extract_struct_fields(
+    struct_node: Node,
+    source: &[u8],
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    let mut cursor = struct_node.walk();
+    let Some(list) = struct_node
+        .children(&mut cursor)
+        .find(|c| c.kind() == "field_declaration_list")
+    else {
+        return;
+    };
+
+    let mut list_cursor = list.walk();
+    for field in list.children(&mut list_cursor) {
+        if field.kind() != "field_declaration" {
+            continue;
+        }
+        let Some(type_node) = field.child_by_field_name("type") else {
+            continue;
+        };
+
+        let mut name_cursor = field.walk();
+        let names: Vec<String> = field
+            .children_by_field_name("name", &mut name_cursor)
+            .map(|n| text(n, source))
+            .collect();
+
+        if names.is_empty() {
+            // Embedded field: `struct { Point }` promotes Point's fields and methods
+            if let Some(embedded) = base_type_name(type_node, source) {
+                refs.push(ExtractedRef {
+                    name: embedded,
+                    kind: RefKind::Extends,
+                    line: field.start_position().row + 1,
+                });
+            }
+            continue;
+        }
+
+        collect_type_refs(type_node, source, generics, refs);
+        let type_sig = text(type_node, source);
+        for name in names {
+            children.push(ExtractedSymbol {
+                name,
+                kind: SymbolKind::Var,
+                signature: Some(type_sig.clone()),
+                doc_comment: None,
+                start_line: field.start_position().row + 1,
+                end_line: field.end_position().row + 1,
+                children: Vec::new(),
+                references: Vec::new(),
+                metadata: Vec::new(),
+            });
+        }
+    }
+}
+
+fn extract_interface_elems(
+    iface_node: Node,
+    source: &[u8],
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    let mut cursor = iface_node.walk();
+    for elem in iface_node.children(&mut cursor) {
+        match elem.kind() {
+            "method_elem" => {
+                if let Some(name) = elem.child_by_field_name("name") {
+                    extract_signature_type_refs(elem, source, generics, refs);
+                    children.push(ExtractedSymbol {
+                        name: text(name, source),
+                        kind: SymbolKind::Method,
+                        signature: Some(build_fn_signature(elem, source)),
+                        doc_comment: None,
+                        start_line: elem.start_position().row + 1,
+                        end_line: elem.end_position().row + 1,
+                        children: Vec::new(),
+                        references: Vec::new(),
+                        metadata: Vec::new(),
+                    });
+                }
+            }
+            "type_elem" => {
+                // Embedded interfaces (`io.Reader`) extend the method set;
+                // unions of concrete types (`~int | ~float64`) are constraints only.
+                let mut elem_cursor = elem.walk();
+                let types: Vec<Node> = elem.named_children(&mut elem_cursor).collect();
+                if let [single] = types.as_slice() {
+                    if let Some(embedded) = base_type_name(*single, source) {
+                        if !is_builtin(&embedded) {
+                            refs.push(ExtractedRef {
+                                name: embedded,
+                                kind: RefKind::Extends,
+                                line: elem.start_position().row + 1,
+                            });
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `const`/`var` declarations, single or grouped. Each spec may declare several names.
+fn extract_value_declaration(
+    node: Node,
+    spec_kind: &str,
+    kind: SymbolKind,
+    source: &[u8],
+    symbols: &mut Vec<ExtractedSymbol>,
+) {
+    let mut cursor = node.walk();
+    let specs: Vec<Node> = node
+        .children(&mut cursor)
+        .filter(|c| c.kind() == spec_kind)
+        .collect();
+    let single = specs.len() == 1;
+
+    for spec in specs {
+        let doc_target = if single { node } else { spec };
+        let doc = get_doc_comment(doc_target, source);
+        let type_sig = spec.child_by_field_name("type").map(|n| text(n, source));
+
+        let mut refs = Vec::new();
+        if let Some(value) = spec.child_by_field_name("value") {
+            extract_calls(value, source, &mut refs);
+        }
+
+        let mut name_cursor = spec.walk();
+        for name in spec.children_by_field_name("name", &mut name_cursor) {
+            symbols.push(ExtractedSymbol {
+                name: text(name, source),
+                kind,
+                signature: type_sig.clone(),
+                doc_comment: doc.clone(),
+                start_line: spec.start_position().row + 1,
+                end_line: spec.end_position().row + 1,
+                children: Vec::new(),
+                references: refs.clone(),
+                metadata: Vec::new(),
+            });
+        }
+    }
+}
+
+fn type_parameter_names(node: Node, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    if let Some(list) = node.child_by_field_name("type_parameters") {
+        let mut cursor = list.walk();
+        for decl in list.children(&mut cursor) {
+            if decl.kind() == "type_parameter_declaration" {
+                let mut name_cursor = decl.walk();
+                for name in decl.children_by_field_name("name", &mut name_cursor) {
+                    names.push(text(name, source));
+                }
+            }
+        }
+    }
+    names
+}
+
+fn is_builtin(name: &str) -> bool {
+    BUILTIN_TYPES.contains(&name)
+}
+
+/// The named type behind pointers and generic arguments: `*pkg.Thing` → `Thing`.
+fn base_type_name(node: Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "type_identifier" => Some(text(node, source)),
+        "qualified_type" => node.child_by_field_name("name").map(|n| text(n, source)),
+        "generic_type" => base_type_name(node.child_by_field_name("type")?, source),
+        "pointer_type" => base_type_name(node.named_child(0)?, source),
+        _ => None,
+    }
+}
+
+/// `TypeRef`s for every named type in a function's parameters and results.
+fn extract_signature_type_refs(
+    node: Node,
+    source: &[u8],
+    generics: &[String],
+    refs: &mut Vec<ExtractedRef>,
+) {
+    for field in ["parameters", "result"] {
+        if let Some(n) = node.child_by_field_name(field) {
+            collect_type_refs(n, source, generics, refs);
+        }
+    }
+}
+
+fn collect_type_refs(node: Node, source: &[u8], generics: &[String], refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "type_identifier" => {
+            let name = text(node, source);
+            if !is_builtin(&name) && !generics.contains(&name) {
+                push_type_ref(name, node, refs);
+            }
+        }
+        "qualified_type" => {
+            if let Some(name) = node.child_by_field_name("name") {
+                push_type_ref(text(name, source), node, refs);
+            }
+        }
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, source, generics, refs);
+            }
+        }
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
+
+fn extract_calls(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    extract_calls_recursive(&mut cursor, source, refs);
+}
+
+fn extract_calls_recursive(cursor: &mut TreeCursor, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    loop {
+        let node = cursor.node();
+        if node.kind() == "call_expression" {
+            if let Some(func) = node.child_by_field_name("function") {
+                let name = match func.kind() {
+                    "identifier" => text(func, source),
+                    "selector_expression" => {
+                        let field = func.child_by_field_name("field").map(|n| text(n, source));
+                        let operand = func.child_by_field_name("operand").map(|n| text(n, source));
+                        match (operand, field) {
+                            (Some(o), Some(f)) => format!("{o}.{f}"),
+                            (None, Some(f)) => f,
+                            _ => text(func, source),
+                        }
+                    }
+                    _ => text(func, source),
+                };
+                if !name.is_empty() {
+                    refs.push(ExtractedRef {
+                        name,
+                        kind: RefKind::Call,
+                        line: node.start_position().row + 1,
+                    });
+                }
+            }
+        }
+
+        if cursor.goto_first_child() {
+            extract_calls_recursive(cursor, source, refs);
+            cursor.goto_parent();
+        }
+
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use tree_sitter::{Node, Tree};
+
+use super::LanguageExtractor;
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+pub struct GraphqlExtractor;
+
+impl LanguageExtractor for GraphqlExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_graphql::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["graphql", "gql"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let mut symbols = Vec::new();
+        collect_definitions(tree.root_node(), source, &mut symbols);
+        symbols
+    }
+}
+
+/// Built-in scalars — never worth a `TypeRef`.
+const BUILTIN_TYPES: &[&str] = &["Boolean", "Float", "ID", "Int", "String"];
+
+/// Root operation types, whose fields are the API's entry points.
+const ROOT_TYPES: &[&str] = &["Mutation", "Query", "Subscription"];
+
+/// Definitions sit under a few layers of wrapper nodes (`document`, `definition`,
+/// `type_system_definition`, ...), so they are found by kind rather than position.
+fn collect_definitions(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbol>) {
+    let symbol = match node.kind() {
+        "object_type_definition" | "object_type_extension" => {
+            extract_type(node, SymbolKind::Class, source)
+        }
+        "interface_type_definition" | "interface_type_extension" => {
+            extract_type(node, SymbolKind::Interface, source)
+        }
+        "input_object_type_definition" | "input_object_type_extension" => {
+            extract_type(node, SymbolKind::Struct, source)
+        }
+        "enum_type_definition" | "enum_type_extension" => extract_enum(node, source),
+        "union_type_definition" | "union_type_extension" => extract_union(node, source),
+        "scalar_type_definition" => extract_scalar(node, source),
+        "operation_definition" => extract_operation(node, source),
+        "fragment_definition" => extract_fragment(node, source),
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_definitions(child, source, symbols);
+            }
+            return;
+        }
+    };
+    symbols.extend(symbol);
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+fn find_child<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).find(|c| c.kind() == kind)
+}
+
+fn name_of(node: Node, source: &[u8]) -> Option<String> {
+    find_child(node, "name").map(|n| text(n, source))
+}
+
+/// A `"description"` / `"""block description"""` string, falling back to the
+/// `#` comment block directly above the definition.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    if let Some(description) = find_child(node, "description") {
+        let raw = text(description, source);
+        let doc = raw
+            .trim_start_matches("\"\"\"")
+            .trim_end_matches("\"\"\"")
+            .trim_matches('"')
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        return (!doc.is_empty()).then_some(doc);
+    }
+    comment_block(node, source)
+}
+
+/// Comments are extras, so they end up as siblings of whichever wrapper encloses
+/// the definition; climb the wrappers that start on the same position first.
+fn comment_block(node: Node, source: &[u8]) -> Option<String> {
+    let mut anchor = node;
+    while anchor.prev_sibling().is_none()
+        && let Some(parent) = anchor.parent()
+    {
+        anchor = parent;
+    }
+
+    let mut lines = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut sibling = anchor.prev_sibling();
+    while let Some(s) = sibling {
+        if s.kind() != "comment" || s.end_position().row + 1 != expected_row {
+            break;
+        }
+        lines.push(text(s, source).trim_start_matches('#').trim().to_string());
+        expected_row = s.start_position().row;
+        sibling = s.prev_sibling();
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
+fn symbol(
+    name: String,
+    kind: SymbolKind,
+    signature: Option<String>,
+    node: Node,
+    source: &[u8],
+) -> ExtractedSymbol {
+    ExtractedSymbol {
+        name,
+        kind,
+        signature,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    }
+}
+
+/// Object, interface and input types. Plain fields become `Var` children whose
+/// types are `TypeRef`s from the type; fields taking arguments — and every field
+/// of `Query`/`Mutation`/`Subscription` — become methods with their own refs.
+fn extract_type(node: Node, kind: SymbolKind, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = name_of(node, source)?;
+    let is_root = ROOT_TYPES.contains(&name.as_str());
+    let mut sym = symbol(name, kind, None, node, source);
+
+    if let Some(interfaces) = find_child(node, "implements_interfaces") {
+        let ref_kind = match kind {
+            SymbolKind::Interface => RefKind::Extends,
+            _ => RefKind::Implements,
+        };
+        collect_named_types(interfaces, source, &mut |name, at| {
+            sym.references.push(ExtractedRef {
+                name,
+                kind: ref_kind,
+                line: at.start_position().row + 1,
+            });
+        });
+    }
+
+    let fields = find_child(node, "fields_definition")
+        .or_else(|| find_child(node, "input_fields_definition"));
+    if let Some(fields) = fields {
+        let mut cursor = fields.walk();
+        for field in fields.named_children(&mut cursor) {
+            if !matches!(field.kind(), "field_definition" | "input_value_definition") {
+                continue;
+            }
+            if let Some(child) = extract_field(field, is_root, source, &mut sym.references) {
+                sym.children.push(child);
+            }
+        }
+    }
+    Some(sym)
+}
+
+fn extract_field(
+    field: Node,
+    is_root: bool,
+    source: &[u8],
+    type_refs: &mut Vec<ExtractedRef>,
+) -> Option<ExtractedSymbol> {
+    let name = name_of(field, source)?;
+    let type_node = find_child(field, "type")?;
+    let type_text = text(type_node, source);
+    let arguments = find_child(field, "arguments_definition");
+
+    if arguments.is_none() && !is_root {
+        collect_type_refs(type_node, source, type_refs);
+        return Some(symbol(
+            name,
+            SymbolKind::Var,
+            Some(type_text),
+            field,
+            source,
+        ));
+    }
+
+    let mut refs = Vec::new();
+    let params = match arguments {
+        Some(args) => {
+            collect_type_refs(args, source, &mut refs);
+            text(args, source)
+        }
+        None => "()".to_string(),
+    };
+    collect_type_refs(type_node, source, &mut refs);
+    let mut method = symbol(
+        name,
+        SymbolKind::Method,
+        Some(format!("{params}: {type_text}")),
+        field,
+        source,
+    );
+    method.references = refs;
+    Some(method)
+}
+
+fn extract_enum(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let mut sym = symbol(name_of(node, source)?, SymbolKind::Enum, None, node, source);
+    if let Some(values) = find_child(node, "enum_values_definition") {
+        let mut cursor = values.walk();
+        for value in values.named_children(&mut cursor) {
+            let Some(value_name) = find_child(value, "enum_value") else {
+                continue;
+            };
+            sym.children.push(symbol(
+                text(value_name, source),
+                SymbolKind::Const,
+                None,
+                value,
+                source,
+            ));
+        }
+    }
+    Some(sym)
+}
+
+/// `union SearchResult = User | Post` → a type alias referencing each member.
+fn extract_union(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = name_of(node, source)?;
+    let members = find_child(node, "union_member_types");
+    let signature = members.map(|m| {
+        let mut names = Vec::new();
+        collect_named_types(m, source, &mut |name, _| names.push(name));
+        names.join(" | ")
+    });
+    let mut sym = symbol(name, SymbolKind::Type, signature, node, source);
+    if let Some(members) = members {
+        collect_type_refs(members, source, &mut sym.references);
+    }
+    Some(sym)
+}
+
+fn extract_scalar(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    Some(symbol(
+        name_of(node, source)?,
+        SymbolKind::Type,
+        Some("scalar".to_string()),
+        node,
+        source,
+    ))
+}
+
+/// Named client operations (`query GetProfile($id: ID!) { ... }`) become functions
+/// whose signature is the operation type and variables. Anonymous ones are skipped.
+fn extract_operation(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = name_of(node, source)?;
+    let operation = find_child(node, "operation_type")
+        .map(|n| text(n, source))
+        .unwrap_or_else(|| "query".to_string());
+    let variables = find_child(node, "variable_definitions");
+    let signature = match variables {
+        Some(vars) => format!("{operation} {}", text(vars, source)),
+        None => operation,
+    };
+
+    let mut sym = symbol(name, SymbolKind::Fn, Some(signature), node, source);
+    if let Some(vars) = variables {
+        collect_type_refs(vars, source, &mut sym.references);
+    }
+    if let Some(selections) = find_child(node, "selection_set") {
+        collect_fragment_spreads(selections, source, &mut sym.references);
+    }
+    Some(sym)
+}
+
+/// `fragment UserFields on User` → a type with a `TypeRef` to `User`.
+fn extract_fragment(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = find_child(node, "fragment_name").map(|n| text(n, source))?;
+    let condition = find_child(node, "type_condition");
+    let signature = condition.map(|c| format!("fragment {}", text(c, source)));
+    let mut sym = symbol(name, SymbolKind::Type, signature, node, source);
+    if let Some(condition) = condition {
+        collect_type_refs(condition, source, &mut sym.references);
+    }
+    if let Some(selections) = find_child(node, "selection_set") {
+        collect_fragment_spreads(selections, source, &mut sym.references);
+    }
+    Some(sym)
+}
+
+/// `...UserFields` inside a selection set is a call-like use of the fragment.
+fn collect_fragment_spreads(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    if node.kind() == "fragment_spread" {
+        if let Some(name) = find_child(node, "fragment_name") {
+            refs.push(ExtractedRef {
+                name: text(name, source),
+                kind: RefKind::Call,
+                line: node.start_position().row + 1,
+            });
+        }
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_fragment_spreads(child, source, refs);
+    }
+}
+
+fn collect_named_types(node: Node, source: &[u8], found: &mut impl FnMut(String, Node)) {
+    if node.kind() == "named_type" {
+        if let Some(name) = name_of(node, source) {
+            found(name, node);
+        }
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_named_types(child, source, found);
+    }
+}
+
+fn collect_type_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    collect_named_types(node, source, &mut |name, at| {
+        if !BUILTIN_TYPES.contains(&name.as_str()) {
+            push_type_ref(name, at, refs);
+        }
+    });
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
@@ -0,0 +1,565 @@
+use tree_sitter::{Node, Tree, TreeCursor};
+
+use super::LanguageExtractor;
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+pub struct JavaExtractor;
+
+impl LanguageExtractor for JavaExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_java::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["java"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let mut symbols = Vec::new();
+        extract_top_level(tree.root_node(), source, &mut symbols);
+        symbols
+    }
+}
+
+/// `java.lang` types that are always in scope — never worth a `TypeRef`.
+const BUILTIN_TYPES: &[&str] = &[
+    "Boolean",
+    "Byte",
+    "Character",
+    "Double",
+    "Float",
+    "Integer",
+    "Long",
+    "Number",
+    "Object",
+    "Short",
+    "String",
+    "Void",
+];
+
+/// `java.lang` annotations with no in-repo declaration to link to.
+const BUILTIN_ANNOTATIONS: &[&str] = &[
+    "Deprecated",
+    "FunctionalInterface",
+    "Override",
+    "SafeVarargs",
+    "SuppressWarnings",
+];
+
+fn extract_top_level(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbol>) {
+    let mut package: Option<ExtractedSymbol> = None;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "package_declaration" => {
+                package = extract_package(child, source);
+            }
+            "import_declaration" => {
+                // Files in the default package still get a holder for their imports
+                let pkg = package.get_or_insert_with(|| ExtractedSymbol {
+                    name: "_imports".to_string(),
+                    kind: SymbolKind::Mod,
+                    signature: None,
+                    doc_comment: None,
+                    start_line: child.start_position().row + 1,
+                    end_line: child.end_position().row + 1,
+                    children: Vec::new(),
+                    references: Vec::new(),
+                    metadata: Vec::new(),
+                });
+                if let Some(r) = extract_import_ref(child, source) {
+                    pkg.references.push(r);
+                }
+                pkg.end_line = child.end_position().row + 1;
+            }
+            _ => {
+                if let Some(sym) = extract_type_declaration(child, source) {
+                    symbols.push(sym);
+                }
+            }
+        }
+    }
+
+    if let Some(pkg) = package {
+        symbols.insert(0, pkg);
+    }
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+/// Javadoc (`/** ... */`) directly preceding a declaration, with the `*` gutter stripped.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let prev = node.prev_sibling()?;
+    if prev.kind() != "block_comment" {
+        return None;
+    }
+    let t = text(prev, source);
+    if !t.starts_with("/**") {
+        return None;
+    }
+    let cleaned = t
+        .trim_start_matches("/**")
+        .trim_end_matches("*/")
+        .lines()
+        .map(|l| l.trim().trim_start_matches('*').trim())
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if cleaned.is_empty() {
+        None
+    } else {
+        Some(cleaned)
+    }
+}
+
+fn extract_package(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let mut cursor = node.walk();
+    let name = node
+        .named_children(&mut cursor)
+        .find(|c| matches!(c.kind(), "scoped_identifier" | "identifier"))
+        .map(|n| text(n, source))?;
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Mod,
+        signature: None,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+/// `import a.b.C;`, `import static a.b.C.m;` and `import a.b.*;` become an `Import`
+/// ref to the imported path (wildcards keep their trailing `.*`).
+fn extract_import_ref(node: Node, source: &[u8]) -> Option<ExtractedRef> {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).collect();
+    let path = children
+        .iter()
+        .find(|c| matches!(c.kind(), "scoped_identifier" | "identifier"))
+        .map(|n| text(*n, source))?;
+    let name = if children.iter().any(|c| c.kind() == "asterisk") {
+        format!("{path}.*")
+    } else {
+        path
+    };
+    Some(ExtractedRef {
+        name,
+        kind: RefKind::Import,
+        line: node.start_position().row + 1,
+    })
+}
+
+/// Classes, interfaces, enums, records and annotation types, at any nesting depth.
+fn extract_type_declaration(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let (kind, signature) = match node.kind() {
+        "class_declaration" => (SymbolKind::Class, None),
+        "interface_declaration" => (SymbolKind::Interface, None),
+        "enum_declaration" => (SymbolKind::Enum, None),
+        "record_declaration" => (
+            SymbolKind::Struct,
+            node.child_by_field_name("parameters")
+                .map(|n| text(n, source)),
+        ),
+        "annotation_type_declaration" => (SymbolKind::Interface, Some("@interface".to_string())),
+        _ => return None,
+    };
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let generics = type_parameter_names(node, source);
+
+    let mut refs = Vec::new();
+    extract_annotation_refs(node, source, &mut refs);
+    extract_supertypes(node, source, &mut refs);
+
+    let mut children = Vec::new();
+    if node.kind() == "record_declaration" {
+        extract_record_components(node, source, &generics, &mut children, &mut refs);
+    }
+    if let Some(body) = node.child_by_field_name("body") {
+        extract_members(body, source, &name, &generics, &mut children);
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind,
+        signature,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children,
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// `extends` on a class (or between interfaces) is `Extends`; `implements` is `Implements`.
+fn extract_supertypes(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    if let Some(superclass) = node.child_by_field_name("superclass") {
+        push_supertypes(superclass, source, RefKind::Extends, refs);
+    }
+    if let Some(interfaces) = node.child_by_field_name("interfaces") {
+        push_supertypes(interfaces, source, RefKind::Implements, refs);
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "extends_interfaces" {
+            push_supertypes(child, source, RefKind::Extends, refs);
+        }
+    }
+}
+
+fn push_supertypes(node: Node, source: &[u8], kind: RefKind, refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if child.kind() == "type_list" {
+            push_supertypes(child, source, kind, refs);
+        } else if let Some(name) = base_type_name(child, source) {
+            refs.push(ExtractedRef {
+                name,
+                kind,
+                line: child.start_position().row + 1,
+            });
+        }
+    }
+}
+
+fn extract_members(
+    body: Node,
+    source: &[u8],
+    class_name: &str,
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+) {
+    let mut cursor = body.walk();
+    for member in body.children(&mut cursor) {
+        match member.kind() {
+            "method_declaration"
+            | "constructor_declaration"
+            | "compact_constructor_declaration"
+            | "annotation_type_element_declaration" => {
+                if let Some(sym) = extract_method(member, source, class_name, generics) {
+                    children.push(sym);
+                }
+            }
+            "field_declaration" | "constant_declaration" => {
+                extract_field(member, source, generics, children);
+            }
+            "enum_constant" => {
+                if let Some(name) = member.child_by_field_name("name") {
+                    children.push(ExtractedSymbol {
+                        name: text(name, source),
+                        kind: SymbolKind::Const,
+                        signature: None,
+                        doc_comment: get_doc_comment(member, source),
+                        start_line: member.start_position().row + 1,
+                        end_line: member.end_position().row + 1,
+                        children: Vec::new(),
+                        references: Vec::new(),
+                        metadata: Vec::new(),
+                    });
+                }
+            }
+            // Methods and fields of an enum follow its constants
+            "enum_body_declarations" => {
+                extract_members(member, source, class_name, generics, children);
+            }
+            _ => {
+                if let Some(sym) = extract_type_declaration(member, source) {
+                    children.push(sym);
+                }
+            }
+        }
+    }
+}
+
+fn build_method_signature(node: Node, source: &[u8]) -> String {
+    let type_params = node
+        .child_by_field_name("type_parameters")
+        .map(|n| format!("{} ", text(n, source)))
+        .unwrap_or_default();
+    let params = node
+        .child_by_field_name("parameters")
+        .map(|n| text(n, source))
+        .unwrap_or_else(|| "()".to_string());
+    let mut sig = format!("{type_params}{params}");
+    if let Some(ret) = node.child_by_field_name("type") {
+        sig.push_str(&format!(" {}", text(ret, source)));
+    }
+    sig
+}
+
+fn extract_method(
+    node: Node,
+    source: &[u8],
+    class_name: &str,
+    class_generics: &[String],
+) -> Option<ExtractedSymbol> {
+    let name = match node.kind() {
+        // Constructors are listed under the class name, as callers see them
+        "constructor_declaration" | "compact_constructor_declaration" => class_name.to_string(),
+        _ => node.child_by_field_name("name").map(|n| text(n, source))?,
+    };
+    let mut generics = class_generics.to_vec();
+    generics.extend(type_parameter_names(node, source));
+
+    let mut refs = Vec::new();
+    extract_annotation_refs(node, source, &mut refs);
+    for field in ["type", "parameters"] {
+        if let Some(n) = node.child_by_field_name(field) {
+            collect_type_refs(n, source, &generics, &mut refs);
+        }
+    }
+    if let Some(body) = node.child_by_field_name("body") {
+        extract_calls(body, source, &mut refs);
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Method,
+        signature: Some(build_method_signature(node, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// A field declaration may declare several names: `int x, y;`.
+/// `static final` fields (and interface constants) are `Const`, the rest `Var`.
+fn extract_field(
+    node: Node,
+    source: &[u8],
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+) {
+    let Some(type_node) = node.child_by_field_name("type") else {
+        return;
+    };
+    let kind = if node.kind() == "constant_declaration" || is_static_final(node, source) {
+        SymbolKind::Const
+    } else {
+        SymbolKind::Var
+    };
+    let doc = get_doc_comment(node, source);
+    let type_sig = text(type_node, source);
+
+    let mut refs = Vec::new();
+    extract_annotation_refs(node, source, &mut refs);
+    collect_type_refs(type_node, source, generics, &mut refs);
+
+    let mut cursor = node.walk();
+    for declarator in node.children_by_field_name("declarator", &mut cursor) {
+        let Some(name) = declarator.child_by_field_name("name") else {
+            continue;
+        };
+        let mut decl_refs = refs.clone();
+        if let Some(value) = declarator.child_by_field_name("value") {
+            extract_calls(value, source, &mut decl_refs);
+        }
+        children.push(ExtractedSymbol {
+            name: text(name, source),
+            kind,
+            signature: Some(type_sig.clone()),
+            doc_comment: doc.clone(),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            children: Vec::new(),
+            references: decl_refs,
+            metadata: Vec::new(),
+        });
+    }
+}
+
+fn is_static_final(node: Node, source: &[u8]) -> bool {
+    let mut cursor = node.walk();
+    let Some(modifiers) = node.children(&mut cursor).find(|c| c.kind() == "modifiers") else {
+        return false;
+    };
+    let mut mod_cursor = modifiers.walk();
+    let words: Vec<String> = modifiers
+        .children(&mut mod_cursor)
+        .map(|m| text(m, source))
+        .collect();
+    words.iter().any(|w| w == "static") && words.iter().any(|w| w == "final")
+}
+
+/// Record components are the record's fields: `record Point(double x, double y)`.
+fn extract_record_components(
+    node: Node,
+    source: &[u8],
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    let Some(params) = node.child_by_field_name("parameters") else {
+        return;
+    };
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        if param.kind() != "formal_parameter" {
+            continue;
+        }
+        let (Some(name), Some(type_node)) = (
+            param.child_by_field_name("name"),
+            param.child_by_field_name("type"),
+        ) else {
+            continue;
+        };
+        collect_type_refs(type_node, source, generics, refs);
+        children.push(ExtractedSymbol {
+            name: text(name, source),
+            kind: SymbolKind::Var,
+            signature: Some(text(type_node, source)),
+            doc_comment: None,
+            start_line: param.start_position().row + 1,
+            end_line: param.end_position().row + 1,
+            children: Vec::new(),
+            references: Vec::new(),
+            metadata: Vec::new(),
+        });
+    }
+}
+
+/// Annotation usages (`@Entity`, `@Table(name = "t")`) link to the annotation type.
+fn extract_annotation_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    let Some(modifiers) = node.children(&mut cursor).find(|c| c.kind() == "modifiers") else {
+        return;
+    };
+    let mut mod_cursor = modifiers.walk();
+    for m in modifiers.named_children(&mut mod_cursor) {
+        if !matches!(m.kind(), "marker_annotation" | "annotation") {
+            continue;
+        }
+        let Some(name_node) = m.child_by_field_name("name") else {
+            continue;
+        };
+        // `@javax.persistence.Entity` → `Entity`
+        let full = text(name_node, source);
+        let name = full.rsplit('.').next().unwrap_or(&full).to_string();
+        if BUILTIN_ANNOTATIONS.contains(&name.as_str()) {
+            continue;
+        }
+        push_type_ref(name, m, refs);
+    }
+}
+
+fn type_parameter_names(node: Node, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    if let Some(list) = node.child_by_field_name("type_parameters") {
+        let mut cursor = list.walk();
+        for param in list.named_children(&mut cursor) {
+            if param.kind() != "type_parameter" {
+                continue;
+            }
+            let mut param_cursor = param.walk();
+            if let Some(name) = param
+                .named_children(&mut param_cursor)
+                .find(|c| matches!(c.kind(), "type_identifier" | "identifier"))
+            {
+                names.push(text(name, source));
+            }
+        }
+    }
+    names
+}
+
+/// The named type behind generics and qualification: `java.util.List<T>` → `List`.
+fn base_type_name(node: Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "type_identifier" => Some(text(node, source)),
+        "scoped_type_identifier" => {
+            let mut cursor = node.walk();
+            node.named_children(&mut cursor)
+                .filter(|c| c.kind() == "type_identifier")
+                .last()
+                .map(|n| text(n, source))
+        }
+        "generic_type" => base_type_name(node.named_child(0)?, source),
+        _ => None,
+    }
+}
+
+fn collect_type_refs(node: Node, source: &[u8], generics: &[String], refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "type_identifier" | "scoped_type_identifier" => {
+            if let Some(name) = base_type_name(node, source) {
+                if !BUILTIN_TYPES.contains(&name.as_str()) && !generics.contains(&name) {
+                    push_type_ref(name, node, refs);
+                }
+            }
+        }
+        // Parameter names and annotations aren't types
+        "identifier" | "marker_annotation" | "annotation" => {}
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, source, generics, refs);
+            }
+        }
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
+
+fn extract_calls(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    extract_calls_recursive(&mut cursor, source, refs);
+}
+
+fn extract_calls_recursive(cursor: &mut TreeCursor, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    loop {
+        let node = cursor.node();
+        let name = match node.kind() {
+            "method_invocation" => {
+                let method = node.child_by_field_name("name").map(|n| text(n, source));
+                let object = node.child_by_field_name("object").map(|n| text(n, source));
+                match (object, method) {
+                    (Some(o), Some(m)) => Some(format!("{o}.{m}")),
+                    (None, Some(m)) => Some(m),
+                    _ => None,
+                }
+            }
+            "object_creation_expression" => node
+                .child_by_field_name("type")
+                .and_then(|t| base_type_name(t, source)),
+            _ => None,
+        };
+        if let Some(name) = name.filter(|n| !n.is_empty()) {
+            refs.push(ExtractedRef {
+                name,
+                kind: RefKind::Call,
+                line: node.start_position().row + 1,
+            });
+        }
+
+        if cursor.goto_first_child() {
+            extract_calls_recursive(cursor, source, refs);
+            cursor.goto_parent();
+        }
+
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+}
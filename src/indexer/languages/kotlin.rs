@@ -0,0 +1,691 @@
+use tree_sitter::{Node, Tree, TreeCursor};
+
+use super::LanguageExtractor;
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+pub struct KotlinExtractor;
+
+impl LanguageExtractor for KotlinExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_kotlin_ng::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["kt", "kts"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let mut symbols = Vec::new();
+        extract_top_level(tree.root_node(), source, &mut symbols);
+        symbols
+    }
+}
+
+/// Types from the implicitly imported `kotlin` packages — never worth a `TypeRef`.
+const BUILTIN_TYPES: &[&str] = &[
+    "Any",
+    "Array",
+    "Boolean",
+    "Byte",
+    "Char",
+    "Collection",
+    "Double",
+    "Float",
+    "Int",
+    "Iterable",
+    "List",
+    "Long",
+    "Map",
+    "MutableList",
+    "MutableMap",
+    "MutableSet",
+    "Nothing",
+    "Pair",
+    "Sequence",
+    "Set",
+    "Short",
+    "String",
+    "Unit",
+];
+
+/// Annotations from the standard library with no in-repo declaration to link to.
+const BUILTIN_ANNOTATIONS: &[&str] = &[
+    "Deprecated",
+    "JvmField",
+    "JvmOverloads",
+    "JvmStatic",
+    "Suppress",
+    "Throws",
+];
+
+fn extract_top_level(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbol>) {
+    let mut package: Option<ExtractedSymbol> = None;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "package_header" => {
+                package = extract_package(child, source);
+            }
+            "import" => {
+                // Files without a package header still get a holder for their imports
+                let pkg = package.get_or_insert_with(|| ExtractedSymbol {
+                    name: "_imports".to_string(),
+                    kind: SymbolKind::Mod,
+                    signature: None,
+                    doc_comment: None,
+                    start_line: child.start_position().row + 1,
+                    end_line: child.end_position().row + 1,
+                    children: Vec::new(),
+                    references: Vec::new(),
+                    metadata: Vec::new(),
+                });
+                if let Some(r) = extract_import_ref(child, source) {
+                    pkg.references.push(r);
+                }
+                pkg.end_line = child.end_position().row + 1;
+            }
+            _ => extract_declaration(child, source, &[], false, symbols),
+        }
+    }
+
+    if let Some(pkg) = package {
+        symbols.insert(0, pkg);
+    }
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+/// KDoc (`/** ... */`) directly preceding a declaration, with the `*` gutter stripped.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let prev = node.prev_sibling()?;
+    if prev.kind() != "block_comment" {
+        return None;
+    }
+    let t = text(prev, source);
+    if !t.starts_with("/**") {
+        return None;
+    }
+    let cleaned = t
+        .trim_start_matches("/**")
+        .trim_end_matches("*/")
+        .lines()
+        .map(|l| l.trim().trim_start_matches('*').trim())
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if cleaned.is_empty() {
+        None
+    } else {
+        Some(cleaned)
+    }
+}
+
+fn extract_package(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let mut cursor = node.walk();
+    let name = node
+        .named_children(&mut cursor)
+        .find(|c| matches!(c.kind(), "qualified_identifier" | "identifier"))
+        .map(|n| text(n, source))?;
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Mod,
+        signature: None,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+/// `import a.b.C`, `import a.b.C as D` and `import a.b.*` become an `Import` ref to
+/// the imported path. Aliases keep the path, since that's what identifies the symbol.
+fn extract_import_ref(node: Node, source: &[u8]) -> Option<ExtractedRef> {
+    let mut cursor = node.walk();
+    let path = node
+        .named_children(&mut cursor)
+        .find(|c| matches!(c.kind(), "qualified_identifier" | "identifier"))
+        .map(|n| text(n, source))?;
+    let mut tok_cursor = node.walk();
+    let wildcard = node.children(&mut tok_cursor).any(|c| c.kind() == "*");
+    Some(ExtractedRef {
+        name: if wildcard { format!("{path}.*") } else { path },
+        kind: RefKind::Import,
+        line: node.start_position().row + 1,
+    })
+}
+
+/// Any declaration that can appear at the top level or in a class body.
+fn extract_declaration(
+    node: Node,
+    source: &[u8],
+    generics: &[String],
+    in_class: bool,
+    symbols: &mut Vec<ExtractedSymbol>,
+) {
+    match node.kind() {
+        "class_declaration" | "object_declaration" | "companion_object" => {
+            if let Some(sym) = extract_class(node, source) {
+                symbols.push(sym);
+            }
+        }
+        "function_declaration" => {
+            if let Some(sym) = extract_function(node, source, generics, in_class) {
+                symbols.push(sym);
+            }
+        }
+        "secondary_constructor" => {
+            if let Some(sym) = extract_constructor(node, source, generics) {
+                symbols.push(sym);
+            }
+        }
+        "property_declaration" => {
+            extract_property(node, source, generics, symbols);
+        }
+        "type_alias" => {
+            if let Some(sym) = extract_type_alias(node, source) {
+                symbols.push(sym);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn has_token(node: Node, token: &str) -> bool {
+    let mut cursor = node.walk();
+    node.children(&mut cursor).any(|c| c.kind() == token)
+}
+
+fn modifiers(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    node.children(&mut cursor).find(|c| c.kind() == "modifiers")
+}
+
+fn has_modifier(node: Node, source: &[u8], modifier: &str) -> bool {
+    let Some(mods) = modifiers(node) else {
+        return false;
+    };
+    let mut cursor = mods.walk();
+    mods.named_children(&mut cursor)
+        .any(|m| m.kind() != "annotation" && text(m, source) == modifier)
+}
+
+/// Classes, interfaces, enum/annotation classes, objects and companion objects.
+fn extract_class(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = match node.child_by_field_name("name") {
+        Some(n) => text(n, source),
+        // `companion object { ... }` is reachable as `Owner.Companion`
+        None if node.kind() == "companion_object" => "Companion".to_string(),
+        None => return None,
+    };
+    let is_interface = has_token(node, "interface");
+
+    let (kind, mut signature) = match node.kind() {
+        "object_declaration" => (SymbolKind::Class, Some("object".to_string())),
+        "companion_object" => (SymbolKind::Class, Some("companion object".to_string())),
+        _ if is_interface => (SymbolKind::Interface, None),
+        _ if has_modifier(node, source, "enum") => (SymbolKind::Enum, None),
+        _ if has_modifier(node, source, "annotation") => {
+            (SymbolKind::Interface, Some("annotation class".to_string()))
+        }
+        _ => (SymbolKind::Class, None),
+    };
+    let generics = type_parameter_names(node, source);
+
+    let mut refs = Vec::new();
+    extract_annotation_refs(node, source, &mut refs);
+    let mut children = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "primary_constructor" => {
+                if let Some(params) = named_child_of_kind(child, "class_parameters") {
+                    signature = Some(text(params, source));
+                    extract_class_parameters(params, source, &generics, &mut children, &mut refs);
+                }
+            }
+            "delegation_specifiers" => {
+                extract_supertypes(child, source, is_interface, &mut refs);
+            }
+            "class_body" | "enum_class_body" => {
+                extract_members(child, source, &generics, &mut children);
+            }
+            _ => {}
+        }
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind,
+        signature,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children,
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+fn named_child_of_kind<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).find(|c| c.kind() == kind)
+}
+
+/// Supertypes after `:`. A constructor call (`Base()`) is the superclass; bare types
+/// are interfaces, as are `by` delegations. Everything an interface lists extends it.
+fn extract_supertypes(node: Node, source: &[u8], is_interface: bool, refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    for spec in node.named_children(&mut cursor) {
+        let Some(inner) = spec.named_child(0) else {
+            continue;
+        };
+        let (type_node, kind) = match inner.kind() {
+            "constructor_invocation" => (inner.named_child(0), RefKind::Extends),
+            "explicit_delegation" => (inner.named_child(0), RefKind::Implements),
+            _ if is_interface => (Some(inner), RefKind::Extends),
+            _ => (Some(inner), RefKind::Implements),
+        };
+        if let Some(name) = type_node.and_then(|t| base_type_name(t, source)) {
+            refs.push(ExtractedRef {
+                name,
+                kind,
+                line: spec.start_position().row + 1,
+            });
+        }
+    }
+}
+
+/// `val`/`var` primary constructor parameters declare properties.
+fn extract_class_parameters(
+    params: Node,
+    source: &[u8],
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        if param.kind() != "class_parameter" {
+            continue;
+        }
+        let type_node = param_type(param);
+        if let Some(t) = type_node {
+            collect_type_refs(t, source, generics, refs);
+        }
+        if !has_token(param, "val") && !has_token(param, "var") {
+            continue;
+        }
+        let Some(name) = named_child_of_kind(param, "identifier") else {
+            continue;
+        };
+        children.push(ExtractedSymbol {
+            name: text(name, source),
+            kind: SymbolKind::Var,
+            signature: type_node.map(|t| text(t, source)),
+            doc_comment: None,
+            start_line: param.start_position().row + 1,
+            end_line: param.end_position().row + 1,
+            children: Vec::new(),
+            references: Vec::new(),
+            metadata: Vec::new(),
+        });
+    }
+}
+
+fn extract_members(
+    body: Node,
+    source: &[u8],
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+) {
+    let mut cursor = body.walk();
+    for member in body.children(&mut cursor) {
+        if member.kind() == "enum_entry" {
+            if let Some(name) = named_child_of_kind(member, "identifier") {
+                children.push(ExtractedSymbol {
+                    name: text(name, source),
+                    kind: SymbolKind::Const,
+                    signature: None,
+                    doc_comment: get_doc_comment(member, source),
+                    start_line: member.start_position().row + 1,
+                    end_line: member.end_position().row + 1,
+                    children: Vec::new(),
+                    references: Vec::new(),
+                    metadata: Vec::new(),
+                });
+            }
+        } else {
+            extract_declaration(member, source, generics, true, children);
+        }
+    }
+}
+
+/// The type annotation of a parameter or variable: the first type node among its children.
+fn param_type(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).find(|c| is_type_node(*c))
+}
+
+fn is_type_node(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        "user_type"
+            | "nullable_type"
+            | "function_type"
+            | "parenthesized_type"
+            | "non_nullable_type"
+    )
+}
+
+/// `(x: Double): Double`, with any receiver (`String.`) and type parameters in front.
+fn build_fn_signature(node: Node, source: &[u8]) -> String {
+    let mut sig = String::new();
+    let mut seen_params = false;
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "type_parameters" => sig.push_str(&format!("{} ", text(child, source))),
+            "function_value_parameters" => {
+                sig.push_str(&text(child, source));
+                seen_params = true;
+            }
+            // A type before the parameters is the extension receiver, after them the return type
+            _ if is_type_node(child) => {
+                if seen_params {
+                    sig.push_str(&format!(": {}", text(child, source)));
+                } else {
+                    sig.push_str(&format!("{}.", text(child, source)));
+                }
+            }
+            _ => {}
+        }
+    }
+    sig
+}
+
+fn extract_function(
+    node: Node,
+    source: &[u8],
+    class_generics: &[String],
+    is_method: bool,
+) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let mut generics = class_generics.to_vec();
+    generics.extend(type_parameter_names(node, source));
+
+    let mut refs = Vec::new();
+    extract_annotation_refs(node, source, &mut refs);
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "function_value_parameters" => collect_type_refs(child, source, &generics, &mut refs),
+            "function_body" => extract_calls(child, source, &mut refs),
+            _ if is_type_node(child) => collect_type_refs(child, source, &generics, &mut refs),
+            _ => {}
+        }
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: if is_method {
+            SymbolKind::Method
+        } else {
+            SymbolKind::Fn
+        },
+        signature: Some(build_fn_signature(node, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+fn extract_constructor(node: Node, source: &[u8], generics: &[String]) -> Option<ExtractedSymbol> {
+    let params = named_child_of_kind(node, "function_value_parameters")?;
+
+    let mut refs = Vec::new();
+    collect_type_refs(params, source, generics, &mut refs);
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "block" {
+            extract_calls(child, source, &mut refs);
+        }
+    }
+
+    Some(ExtractedSymbol {
+        name: "constructor".to_string(),
+        kind: SymbolKind::Method,
+        signature: Some(text(params, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// `const val` is a `Const`; other `val`/`var` properties are `Var`.
+fn extract_property(
+    node: Node,
+    source: &[u8],
+    generics: &[String],
+    symbols: &mut Vec<ExtractedSymbol>,
+) {
+    // Destructuring declarations (`val (a, b) = pair`) don't name a single property
+    let Some(decl) = named_child_of_kind(node, "variable_declaration") else {
+        return;
+    };
+    let Some(name) = named_child_of_kind(decl, "identifier") else {
+        return;
+    };
+    let type_node = param_type(decl);
+
+    let mut refs = Vec::new();
+    extract_annotation_refs(node, source, &mut refs);
+    if let Some(t) = type_node {
+        collect_type_refs(t, source, generics, &mut refs);
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if !matches!(child.kind(), "modifiers" | "variable_declaration") {
+            extract_calls(child, source, &mut refs);
+        }
+    }
+
+    symbols.push(ExtractedSymbol {
+        name: text(name, source),
+        kind: if has_modifier(node, source, "const") {
+            SymbolKind::Const
+        } else {
+            SymbolKind::Var
+        },
+        signature: type_node.map(|t| text(t, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    });
+}
+
+fn extract_type_alias(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("type").map(|n| text(n, source))?;
+    let generics = type_parameter_names(node, source);
+    let value = param_type(node)?;
+
+    let mut refs = Vec::new();
+    collect_type_refs(value, source, &generics, &mut refs);
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Type,
+        signature: Some(text(value, source)),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// Annotation usages (`@Serializable`, `@Table("t")`) link to the annotation class.
+fn extract_annotation_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let Some(mods) = modifiers(node) else {
+        return;
+    };
+    let mut cursor = mods.walk();
+    for m in mods.named_children(&mut cursor) {
+        if m.kind() != "annotation" {
+            continue;
+        }
+        let mut inner_cursor = m.walk();
+        let type_node = m
+            .named_children(&mut inner_cursor)
+            .find_map(|c| match c.kind() {
+                "user_type" => Some(c),
+                "constructor_invocation" => c.named_child(0),
+                _ => None,
+            });
+        let Some(name) = type_node.and_then(|t| base_type_name(t, source)) else {
+            continue;
+        };
+        if !BUILTIN_ANNOTATIONS.contains(&name.as_str()) {
+            push_type_ref(name, m, refs);
+        }
+    }
+}
+
+fn type_parameter_names(node: Node, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    if let Some(list) = named_child_of_kind(node, "type_parameters") {
+        let mut cursor = list.walk();
+        for param in list.named_children(&mut cursor) {
+            if param.kind() != "type_parameter" {
+                continue;
+            }
+            if let Some(name) = named_child_of_kind(param, "identifier") {
+                names.push(text(name, source));
+            }
+        }
+    }
+    names
+}
+
+/// The named type behind nullability and qualification: `a.b.Thing<T>?` → `Thing`.
+fn base_type_name(node: Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "user_type" => {
+            let mut cursor = node.walk();
+            node.named_children(&mut cursor)
+                .filter(|c| c.kind() == "identifier")
+                .last()
+                .map(|n| text(n, source))
+        }
+        "nullable_type" | "non_nullable_type" | "parenthesized_type" => {
+            base_type_name(node.named_child(0)?, source)
+        }
+        _ => None,
+    }
+}
+
+fn collect_type_refs(node: Node, source: &[u8], generics: &[String], refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "user_type" => {
+            if let Some(name) = base_type_name(node, source) {
+                if !BUILTIN_TYPES.contains(&name.as_str()) && !generics.contains(&name) {
+                    push_type_ref(name, node, refs);
+                }
+            }
+            // Type arguments: `List<Shape>` also references `Shape`
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                if child.kind() == "type_arguments" {
+                    collect_type_refs(child, source, generics, refs);
+                }
+            }
+        }
+        // Parameter names, default values and annotations aren't types
+        "identifier" | "annotation" | "modifiers" => {}
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                if child.kind() == "parameter" || child.kind().contains("type") {
+                    collect_type_refs(child, source, generics, refs);
+                }
+            }
+        }
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
+
+fn extract_calls(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    extract_calls_recursive(&mut cursor, source, refs);
+}
+
+fn extract_calls_recursive(cursor: &mut TreeCursor, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    loop {
+        let node = cursor.node();
+        if node.kind() == "call_expression" {
+            if let Some(callee) = node.named_child(0) {
+                let name = match callee.kind() {
+                    "identifier" => text(callee, source),
+                    // `shape.area()` → `shape.area`, `this.g()` → `this.g`
+                    "navigation_expression" => {
+                        let receiver = callee.named_child(0).map(|n| text(n, source));
+                        let member = callee
+                            .named_child((callee.named_child_count() as u32).saturating_sub(1))
+                            .filter(|n| n.kind() == "identifier")
+                            .map(|n| text(n, source));
+                        match (receiver, member) {
+                            (Some(r), Some(m)) => format!("{r}.{m}"),
+                            _ => text(callee, source),
+                        }
+                    }
+                    _ => String::new(),
+                };
+                if !name.is_empty() {
+                    refs.push(ExtractedRef {
+                        name,
+                        kind: RefKind::Call,
+                        line: node.start_position().row + 1,
+                    });
+                }
+            }
+        }
+
+        if cursor.goto_first_child() {
+            extract_calls_recursive(cursor, source, refs);
+            cursor.goto_parent();
+        }
+
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::{Context, Result, bail};
+use tree_sitter::{Language, Parser};
+
+use crate::config::QueriesConfig;
+
+/// Loads grammars that aren't compiled into the binary: shared libraries
+/// (`.so`, `.dylib`, `.dll`) exporting `tree_sitter_<name>`, and `.wasm` grammar
+/// files when built with the `wasm` feature. WASM languages only parse with a
+/// parser holding the store they were loaded into, so call [`install`] on the
+/// parser once every grammar is loaded.
+///
+/// A grammar must live inside the repo or one of `indexer.queries.grammar_dirs`,
+/// and shared libraries, which run native code when loaded, are only loaded with
+/// `indexer.queries.allow_native_grammars`. Both are global-config settings.
+///
+/// [`install`]: GrammarLoader::install
+pub struct GrammarLoader {
+    repo_root: PathBuf,
+    /// Canonical directories grammars may be loaded from
+    allowed_dirs: Vec<PathBuf>,
+    allow_native: bool,
+    #[cfg(feature = "wasm")]
+    wasm_store: Option<tree_sitter::WasmStore>,
+}
+
+impl GrammarLoader {
+    pub fn new(repo_root: &Path, config: &QueriesConfig) -> Self {
+        let allowed_dirs = std::iter::once(repo_root.to_path_buf())
+            .chain(config.grammar_dirs.iter().map(PathBuf::from))
+            .filter_map(|dir| std::fs::canonicalize(dir).ok())
+            .collect();
+        Self {
+            repo_root: repo_root.to_path_buf(),
+            allowed_dirs,
+            allow_native: config.allow_native_grammars,
+            #[cfg(feature = "wasm")]
+            wasm_store: None,
+        }
+    }
+
+    /// Loads the grammar at `grammar_path`, relative to the repo root.
+    pub fn load(&mut self, grammar_path: &str, name: &str) -> Result<Language> {
+        let joined = self.repo_root.join(grammar_path);
+        let path = std::fs::canonicalize(&joined)
+            .with_context(|| format!("grammar {} not found", joined.display()))?;
+        if !self.allowed_dirs.iter().any(|dir| path.starts_with(dir)) {
+            bail!(
+                "grammar {} is outside the repository and `indexer.queries.grammar_dirs`",
+                path.display()
+            );
+        }
+        let name = name.replace('-', "_");
+        if path.extension().is_some_and(|ext| ext == "wasm") {
+            self.load_wasm(&path, &name)
+        } else if self.allow_native {
+            load_library(&path, &name)
+        } else {
+            bail!(
+                "not loading {}: shared-library grammars need `indexer.queries.allow_native_grammars` in the global config",
+                path.display()
+            )
+        }
+    }
+
+    #[cfg(feature = "wasm")]
+    fn load_wasm(&mut self, path: &Path, name: &str) -> Result<Language> {
+        let bytes =
+            std::fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+        let store = match &mut self.wasm_store {
+            Some(store) => store,
+            None => {
+                let engine = tree_sitter::wasmtime::Engine::default();
+                let store = tree_sitter::WasmStore::new(&engine)
+                    .map_err(|e| anyhow::anyhow!("failed to create WASM store: {}", e.message))?;
+                self.wasm_store.insert(store)
+            }
+        };
+        store
+            .load_language(name, &bytes)
+            .map_err(|e| anyhow::anyhow!("failed to load {}: {}", path.display(), e.message))
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    fn load_wasm(&mut self, path: &Path, _name: &str) -> Result<Language> {
+        bail!(
+            "cannot load {}: ctxhelpr was built without the `wasm` feature",
+            path.display()
+        )
+    }
+
+    /// Hands the WASM store, if any grammar needed one, to the parser.
+    #[cfg(feature = "wasm")]
+    pub fn install(self, parser: &mut Parser) {
+        if let Some(store) = self.wasm_store
+            && let Err(e) = parser.set_wasm_store(store)
+        {
+            tracing::warn!(error = %e, "Failed to attach WASM grammars to parser");
+        }
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    pub fn install(self, _parser: &mut Parser) {}
+}
+
+/// Languages from shared libraries, by canonical path and grammar name. A library
+/// is loaded once per process and stays loaded.
+fn libraries() -> &'static Mutex<HashMap<(PathBuf, String), Language>> {
+    static LIBRARIES: OnceLock<Mutex<HashMap<(PathBuf, String), Language>>> = OnceLock::new();
+    LIBRARIES.get_or_init(Default::default)
+}
+
+fn load_library(path: &Path, name: &str) -> Result<Language> {
+    let mut libraries = libraries().lock().unwrap_or_else(|e| e.into_inner());
+    let key = (path.to_path_buf(), name.to_string());
+    if let Some(language) = libraries.get(&key) {
+        return Ok(language.clone());
+    }
+    if !path.is_file() {
+        bail!("grammar library {} not found", path.display());
+    }
+    let symbol = format!("tree_sitter_{name}");
+    // SAFETY: loading runs the library's initializers. The user opted in to that
+    // in their global config, and the library is in the repo or a directory they
+    // allowed. Generated parsers export `tree_sitter_<name>` with this signature.
+    let language = unsafe {
+        let library = libloading::Library::new(path)
+            .with_context(|| format!("failed to load {}", path.display()))?;
+        let constructor: libloading::Symbol<unsafe extern "C" fn() -> *const ()> = library
+            .get(symbol.as_bytes())
+            .with_context(|| format!("{} does not export `{symbol}`", path.display()))?;
+        let language = Language::new(tree_sitter_language::LanguageFn::from_raw(*constructor));
+        // Languages point into the library's static data and outlive any one
+        // extractor (parsers and trees keep them too), so it stays loaded.
+        std::mem::forget(library);
+        language
+    };
+    libraries.insert(key, language.clone());
+    Ok(language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A repo with a `grammars/json.so` that isn't a real library, next to a
+    /// `grammars` directory outside it. Loading either one gets as far as
+    /// `libloading` only when the opt-in and location checks pass.
+    fn layout() -> (tempfile::TempDir, PathBuf, String) {
+        let outer = tempfile::tempdir().unwrap();
+        let repo = outer.path().join("repo");
+        for dir in [repo.join("grammars"), outer.path().join("grammars")] {
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("json.so"), "not a library").unwrap();
+        }
+        let outside = outer.path().join("grammars").to_str().unwrap().to_string();
+        (outer, repo, outside)
+    }
+
+    fn load_error(repo: &Path, config: QueriesConfig, grammar_path: &str) -> String {
+        let mut loader = GrammarLoader::new(repo, &config);
+        match loader.load(grammar_path, "json") {
+            Ok(_) => panic!("{grammar_path} is not a grammar"),
+            Err(e) => format!("{e:#}"),
+        }
+    }
+
+    #[test]
+    fn test_native_grammars_need_opt_in() {
+        let (_outer, repo, _) = layout();
+        let err = load_error(&repo, QueriesConfig::default(), "grammars/json.so");
+        assert!(err.contains("allow_native_grammars"), "{err}");
+
+        let allowed = QueriesConfig {
+            allow_native_grammars: true,
+            ..QueriesConfig::default()
+        };
+        let err = load_error(&repo, allowed, "grammars/json.so");
+        assert!(err.starts_with("failed to load"), "{err}");
+    }
+
+    #[test]
+    fn test_grammars_outside_repo_need_allowed_dir() {
+        let (_outer, repo, outside) = layout();
+        let config = |grammar_dirs: Vec<String>| QueriesConfig {
+            allow_native_grammars: true,
+            grammar_dirs,
+            ..QueriesConfig::default()
+        };
+
+        for path in ["../grammars/json.so", &format!("{outside}/json.so")] {
+            let err = load_error(&repo, config(vec![]), path);
+            assert!(err.contains("outside the repository"), "{err}");
+            let err = load_error(&repo, config(vec![outside.clone()]), path);
+            assert!(err.starts_with("failed to load"), "{err}");
+        }
+        let err = load_error(&repo, config(vec![]), "grammars/missing.so");
+        assert!(err.contains("not found"), "{err}");
+    }
+}
@@ -1,7 +1,9 @@
+use std::path::{Component, Path};
+
 use tree_sitter::{Node, Tree};
 
-use super::LanguageExtractor;
-use crate::indexer::{ExtractedSymbol, SymbolKind};
+use super::{FileContext, LanguageExtractor};
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
 
 pub struct MarkdownExtractor;
 
@@ -15,28 +17,159 @@ impl LanguageExtractor for MarkdownExtractor {
     }
 
     fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
-        let mut flat_headings = Vec::new();
-        collect_headings(tree.root_node(), source, &mut flat_headings);
-        build_hierarchy(flat_headings)
+        extract_document("", source, tree)
+    }
+
+    fn extract_file(&self, file: &FileContext, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        extract_document(file.rel_path, source, tree)
     }
 }
 
+/// What a section holds besides its subsections
+#[derive(Default)]
+struct Content {
+    code_blocks: Vec<ExtractedSymbol>,
+    refs: Vec<ExtractedRef>,
+}
+
 struct FlatHeading {
     name: String,
     level: usize,
     start_line: usize,
     end_line: usize,
+    content: Content,
+    metadata: Vec<(String, String)>,
+}
+
+struct Document<'a> {
+    /// Links resolve relative to the document's directory
+    rel_path: &'a str,
+    headings: Vec<FlatHeading>,
+    /// Anything above the first heading
+    preamble: Content,
+    front_matter: Vec<(String, String)>,
+}
+
+impl Document<'_> {
+    /// Where the block being walked belongs: the last heading seen so far
+    fn content(&mut self) -> &mut Content {
+        match self.headings.last_mut() {
+            Some(heading) => &mut heading.content,
+            None => &mut self.preamble,
+        }
+    }
+}
+
+fn extract_document(rel_path: &str, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+    let mut doc = Document {
+        rel_path,
+        headings: Vec::new(),
+        preamble: Content::default(),
+        front_matter: Vec::new(),
+    };
+    collect_sections(tree.root_node(), source, &mut doc);
+
+    let Document {
+        mut headings,
+        preamble,
+        front_matter,
+        ..
+    } = doc;
+
+    // The text above the first heading introduces the document, and the front matter
+    // describes it. Without any heading they make a section of their own, named by
+    // the front matter's `title` or the file name.
+    if let Some(first) = headings.first_mut() {
+        first.content.code_blocks.splice(0..0, preamble.code_blocks);
+        first.content.refs.splice(0..0, preamble.refs);
+        first.metadata = front_matter;
+    } else if !preamble.code_blocks.is_empty()
+        || !preamble.refs.is_empty()
+        || !front_matter.is_empty()
+    {
+        let name = front_matter
+            .iter()
+            .find(|(key, _)| key == "title")
+            .map(|(_, title)| title.clone())
+            .or_else(|| {
+                Path::new(rel_path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+            });
+        if let Some(name) = name {
+            headings.push(FlatHeading {
+                name,
+                level: 1,
+                start_line: 1,
+                end_line: last_line(tree.root_node()),
+                content: preamble,
+                metadata: front_matter,
+            });
+        }
+    }
+
+    build_hierarchy(headings)
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+/// The last line a block covers; blocks own their trailing newline.
+fn last_line(node: Node) -> usize {
+    let end = node.end_position();
+    if end.column == 0 && end.row > node.start_position().row {
+        end.row
+    } else {
+        end.row + 1
+    }
 }
 
-fn collect_headings(node: Node, source: &[u8], headings: &mut Vec<FlatHeading>) {
+fn collect_sections(node: Node, source: &[u8], doc: &mut Document) {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        if child.kind() == "atx_heading" {
-            if let Some(heading) = parse_heading(child, source) {
-                headings.push(heading);
+        match child.kind() {
+            "atx_heading" => {
+                if let Some(heading) = parse_heading(child, source) {
+                    doc.headings.push(heading);
+                }
+                collect_content(child, source, doc);
+            }
+            "section" => collect_sections(child, source, doc),
+            "minus_metadata" => doc.front_matter = parse_front_matter(&text(child, source)),
+            _ => collect_content(child, source, doc),
+        }
+    }
+}
+
+/// Code blocks and mentions inside a block, credited to the section it sits in.
+fn collect_content(node: Node, source: &[u8], doc: &mut Document) {
+    match node.kind() {
+        "fenced_code_block" => {
+            let block = code_block(node, source);
+            doc.content().code_blocks.push(block);
+        }
+        "inline" => {
+            let rel_path = doc.rel_path;
+            let line = node.start_position().row + 1;
+            scan_inline(&text(node, source), line, rel_path, &mut doc.content().refs);
+        }
+        // `[guide]: ../docs/guide.md`
+        "link_destination" => {
+            let rel_path = doc.rel_path;
+            if let Some(path) = link_target(&text(node, source), rel_path) {
+                doc.content().refs.push(ExtractedRef {
+                    name: path,
+                    kind: RefKind::Mention,
+                    line: node.start_position().row + 1,
+                });
+            }
+        }
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_content(child, source, doc);
             }
-        } else if child.kind() == "section" {
-            collect_headings(child, source, headings);
         }
     }
 }
@@ -80,9 +213,206 @@ fn parse_heading(node: Node, source: &[u8]) -> Option<FlatHeading> {
         level,
         start_line: node.start_position().row + 1,
         end_line: node.end_position().row + 1,
+        content: Content::default(),
+        metadata: Vec::new(),
     })
 }
 
+/// A fenced code block, named by its info string's language (`code` without one)
+/// and signed with its first line.
+fn code_block(node: Node, source: &[u8]) -> ExtractedSymbol {
+    let mut language = None;
+    let mut first_line = None;
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "info_string" => {
+                language = text(child, source)
+                    .split_whitespace()
+                    .next()
+                    .map(|l| {
+                        l.trim_matches(|c| c == '{' || c == '}' || c == '.')
+                            .to_string()
+                    })
+                    .filter(|l| !l.is_empty());
+            }
+            "code_fence_content" => {
+                first_line = text(child, source)
+                    .lines()
+                    .map(str::trim)
+                    .find(|l| !l.is_empty())
+                    .map(String::from);
+            }
+            _ => {}
+        }
+    }
+
+    ExtractedSymbol {
+        name: language.unwrap_or_else(|| "code".to_string()),
+        kind: SymbolKind::Code,
+        signature: first_line,
+        doc_comment: None,
+        start_line: node.start_position().row + 1,
+        end_line: last_line(node),
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    }
+}
+
+/// Mentions in a run of inline text: identifiers in backticks and links to files
+/// in the repo. Whether a mention names an indexed symbol is up to resolution.
+fn scan_inline(inline: &str, first_line: usize, rel_path: &str, refs: &mut Vec<ExtractedRef>) {
+    let line_at = |offset: usize| first_line + inline[..offset].matches('\n').count();
+    let bytes = inline.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'`' => {
+                let ticks = bytes[i..].iter().take_while(|&&b| b == b'`').count();
+                let start = i + ticks;
+                let Some(len) = inline[start..].find(&inline[i..start]) else {
+                    i = start;
+                    continue;
+                };
+                if let Some(name) = mentioned_identifier(inline[start..start + len].trim()) {
+                    refs.push(ExtractedRef {
+                        name,
+                        kind: RefKind::Mention,
+                        line: line_at(i),
+                    });
+                }
+                i = start + len + ticks;
+            }
+            b']' if bytes.get(i + 1) == Some(&b'(') => {
+                let start = i + 2;
+                let Some(len) = inline[start..].find(')') else {
+                    i = start;
+                    continue;
+                };
+                if !is_image(bytes, i) {
+                    if let Some(path) = link_target(&inline[start..start + len], rel_path) {
+                        refs.push(ExtractedRef {
+                            name: path,
+                            kind: RefKind::Mention,
+                            line: line_at(i),
+                        });
+                    }
+                }
+                i = start + len + 1;
+            }
+            _ => i += 1,
+        }
+    }
+}
+
+/// Whether the link text closed at `close` opened with `![`.
+fn is_image(bytes: &[u8], close: usize) -> bool {
+    let mut depth = 0;
+    for j in (0..close).rev() {
+        match bytes[j] {
+            b']' => depth += 1,
+            b'[' if depth > 0 => depth -= 1,
+            b'[' => return j > 0 && bytes[j - 1] == b'!',
+            _ => {}
+        }
+    }
+    false
+}
+
+/// The name a code span refers to when it looks like one: `distance`, `helper()`,
+/// `Shape::area` → `area`, `self.cache` → `cache`. Commands and expressions aren't.
+fn mentioned_identifier(span: &str) -> Option<String> {
+    let path = span.strip_suffix("()").unwrap_or(span);
+    let mut segments = path.split(['.', ':']);
+    let name = path.rsplit(['.', ':']).next()?;
+    if !is_identifier(name) || !segments.all(|s| s.is_empty() || is_identifier(s)) {
+        return None;
+    }
+    Some(name.to_string())
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// A link's target as a repo-relative path when it points inside the repo:
+/// `../src/foo.rs#L10` from `docs/a.md` → `src/foo.rs`. URLs, in-page anchors and
+/// paths climbing out of the repo yield nothing.
+fn link_target(target: &str, rel_path: &str) -> Option<String> {
+    // `<path with spaces>` and `path "title"`
+    let target = match target.trim().strip_prefix('<') {
+        Some(bracketed) => bracketed.split('>').next()?,
+        None => target.split_whitespace().next()?,
+    };
+    let target = target.split(['#', '?']).next()?;
+    if target.is_empty() || target.contains(':') {
+        return None;
+    }
+
+    let base = match target.strip_prefix('/') {
+        Some(_) => Path::new(""),
+        None => Path::new(rel_path).parent().unwrap_or(Path::new("")),
+    };
+    let joined = base.join(target.trim_start_matches('/'));
+    let mut parts = Vec::new();
+    for component in joined.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_str()?),
+            Component::ParentDir => {
+                parts.pop()?;
+            }
+            _ => {}
+        }
+    }
+    (!parts.is_empty()).then(|| parts.join("/"))
+}
+
+/// `key: value` pairs of YAML front matter. List items (`tags: [a, b]`, or `- a`
+/// lines under a key) repeat the key; nested mappings and block scalars are skipped.
+fn parse_front_matter(block: &str) -> Vec<(String, String)> {
+    let unquote = |v: &str| v.trim().trim_matches(|c| c == '"' || c == '\'').to_string();
+
+    let mut meta = Vec::new();
+    let mut list_key: Option<String> = None;
+    for line in block.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed == "---" || trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(item) = trimmed.strip_prefix("- ") {
+            if let Some(key) = &list_key {
+                meta.push((key.clone(), unquote(item)));
+            }
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            continue;
+        }
+        list_key = None;
+        let Some((key, value)) = trimmed.split_once(':') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        if value.is_empty() {
+            list_key = Some(key.to_string());
+        } else if let Some(items) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+            meta.extend(
+                items
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| (key.to_string(), unquote(item))),
+            );
+        } else if !value.starts_with(['|', '>', '{']) {
+            meta.push((key.to_string(), unquote(value)));
+        }
+    }
+    meta
+}
+
 fn build_hierarchy(headings: Vec<FlatHeading>) -> Vec<ExtractedSymbol> {
     if headings.is_empty() {
         return Vec::new();
@@ -100,8 +430,9 @@ fn build_hierarchy(headings: Vec<FlatHeading>) -> Vec<ExtractedSymbol> {
             doc_comment: None,
             start_line: heading.start_line,
             end_line: heading.end_line,
-            children: Vec::new(),
-            references: Vec::new(),
+            children: heading.content.code_blocks,
+            references: heading.content.refs,
+            metadata: heading.metadata,
         };
 
         // Pop items from stack with level >= current (they can't be parents)
@@ -1,7 +1,24 @@
+pub mod cpp;
+pub mod csharp;
+pub mod data;
+pub mod dockerfile;
+pub mod go;
+pub mod graphql;
+pub mod java;
+pub mod kotlin;
+mod loader;
 pub mod markdown;
+pub mod notebook;
+mod openapi;
+pub mod php;
+pub mod proto;
 pub mod python;
+pub mod query;
 pub mod ruby;
 pub mod rust_lang;
+pub mod sfc;
+pub mod shell;
+pub mod sql;
 pub mod typescript;
 
 use crate::indexer::ExtractedSymbol;
@@ -12,7 +29,57 @@ pub trait LanguageExtractor: Send + Sync {
         self.language()
     }
     fn extensions(&self) -> &[&str];
+    /// Language recorded for a file picked by `ext`
+    fn language_name(&self, ext: &str) -> Option<&str> {
+        detect_language(ext)
+    }
+    /// Lowercase names of files recognized without an extension (`dockerfile`). A
+    /// name followed by a dot-suffix (`Dockerfile.dev`) matches as well.
+    fn filenames(&self) -> &[&str] {
+        &[]
+    }
+    /// Whether this extractor reads files with extension `ext`
+    fn handles_extension(&self, ext: &str) -> bool {
+        self.extensions().contains(&ext)
+    }
+    /// The entry of `filenames` a lowercase file name matches
+    fn match_filename(&self, name: &str) -> Option<String> {
+        match_filename(self.filenames(), name)
+    }
     fn extract(&self, source: &[u8], tree: &tree_sitter::Tree) -> Vec<ExtractedSymbol>;
+    /// Like `extract`, for extractors that also need to know which file they are reading
+    /// or how the repo is configured
+    fn extract_file(
+        &self,
+        _file: &FileContext,
+        source: &[u8],
+        tree: &tree_sitter::Tree,
+    ) -> Vec<ExtractedSymbol> {
+        self.extract(source, tree)
+    }
+}
+
+/// Per-file inputs to `LanguageExtractor::extract_file`.
+pub struct FileContext<'a> {
+    /// Path relative to the repository root
+    pub rel_path: &'a str,
+    /// Extension the extractor was picked by, or the matched name from `filenames`
+    pub ext: &'a str,
+    /// Nesting limit for keys in data files (`indexer.max_key_depth`)
+    pub max_key_depth: usize,
+}
+
+/// The known file name `name` matches, as is or followed by a dot-suffix.
+pub(crate) fn match_filename(known: &[impl AsRef<str>], name: &str) -> Option<String> {
+    known
+        .iter()
+        .map(|known| known.as_ref().to_ascii_lowercase())
+        .find(|known| {
+            name == *known
+                || name
+                    .strip_prefix(known.as_str())
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
 }
 
 pub fn detect_language(ext: &str) -> Option<&'static str> {
@@ -22,6 +89,156 @@ pub fn detect_language(ext: &str) -> Option<&'static str> {
         "rs" => Some("rust"),
         "rb" => Some("ruby"),
         "md" | "markdown" => Some("markdown"),
+        "ipynb" => Some("notebook"),
+        "go" => Some("go"),
+        "java" => Some("java"),
+        "kt" | "kts" => Some("kotlin"),
+        // Headers are parsed as C++, which accepts nearly all C
+        "c" => Some("c"),
+        "h" | "cc" | "cpp" | "hpp" => Some("cpp"),
+        "cs" => Some("csharp"),
+        "php" => Some("php"),
+        "vue" => Some("vue"),
+        "svelte" => Some("svelte"),
+        "json" => Some("json"),
+        "toml" => Some("toml"),
+        "yaml" | "yml" => Some("yaml"),
+        "proto" => Some("protobuf"),
+        "graphql" | "gql" => Some("graphql"),
+        "sh" | "bash" | "zsh" => Some("shell"),
+        "dockerfile" | "containerfile" => Some("dockerfile"),
+        "sql" => Some("sql"),
         _ => None,
     }
 }
+
+/// The absolute, dot-separated module path an import ref made in `rel_path` names,
+/// for languages whose imports are qualified by module and whose module symbols are
+/// named by that path (Python's `.sub.Name` in `pkg/a.py` → `pkg.sub.Name`). `None`
+/// for other languages, whose imports resolve by name like any reference.
+pub fn module_import_path(language: &str, rel_path: &str, import: &str) -> Option<String> {
+    match language {
+        "python" | "notebook" => python::absolute_import(rel_path, import),
+        _ => None,
+    }
+}
+
+/// A grammar compiled into the binary, which query packs can parse with.
+pub struct Grammar {
+    pub name: &'static str,
+    /// Extensions a `<name>.scm` pack in the queries directory applies to
+    pub extensions: &'static [&'static str],
+    pub language: fn() -> tree_sitter::Language,
+}
+
+pub const GRAMMARS: &[Grammar] = &[
+    Grammar {
+        name: "typescript",
+        extensions: &["ts"],
+        language: || tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+    },
+    Grammar {
+        name: "tsx",
+        extensions: &["tsx", "js", "jsx", "mjs", "cjs"],
+        language: || tree_sitter_typescript::LANGUAGE_TSX.into(),
+    },
+    Grammar {
+        name: "python",
+        extensions: &["py", "pyi"],
+        language: || tree_sitter_python::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "rust",
+        extensions: &["rs"],
+        language: || tree_sitter_rust::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "ruby",
+        extensions: &["rb"],
+        language: || tree_sitter_ruby::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "markdown",
+        extensions: &["md", "markdown"],
+        language: || tree_sitter_md::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "go",
+        extensions: &["go"],
+        language: || tree_sitter_go::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "java",
+        extensions: &["java"],
+        language: || tree_sitter_java::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "kotlin",
+        extensions: &["kt", "kts"],
+        language: || tree_sitter_kotlin_ng::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "c",
+        extensions: &["c"],
+        language: || tree_sitter_c::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "cpp",
+        extensions: &["h", "cc", "cpp", "hpp"],
+        language: || tree_sitter_cpp::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "csharp",
+        extensions: &["cs"],
+        language: || tree_sitter_c_sharp::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "php",
+        extensions: &["php"],
+        language: || tree_sitter_php::LANGUAGE_PHP.into(),
+    },
+    Grammar {
+        name: "json",
+        extensions: &["json"],
+        language: || tree_sitter_json::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "toml",
+        extensions: &["toml"],
+        language: || tree_sitter_toml_ng::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "yaml",
+        extensions: &["yaml", "yml"],
+        language: || tree_sitter_yaml::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "proto",
+        extensions: &["proto"],
+        language: || tree_sitter_proto::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "graphql",
+        extensions: &["graphql", "gql"],
+        language: || tree_sitter_graphql::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "bash",
+        extensions: &["sh", "bash", "zsh"],
+        language: || tree_sitter_bash::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "dockerfile",
+        extensions: &["dockerfile", "containerfile"],
+        language: || tree_sitter_containerfile::LANGUAGE.into(),
+    },
+    Grammar {
+        name: "sql",
+        extensions: &["sql"],
+        language: || tree_sitter_sequel::LANGUAGE.into(),
+    },
+];
+
+pub fn find_grammar(name: &str) -> Option<&'static Grammar> {
+    GRAMMARS.iter().find(|g| g.name == name)
+}
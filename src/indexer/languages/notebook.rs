@@ -0,0 +1,203 @@
+use tree_sitter::{Node, Parser, Tree};
+
+use super::LanguageExtractor;
+use super::python::PythonExtractor;
+use crate::indexer::{ExtractedSymbol, SymbolKind};
+
+/// Jupyter notebooks. Code cells are laid out one after another as a single Python
+/// script for `PythonExtractor`, and each markdown cell becomes a `Section`.
+///
+/// Line numbers count through that script, cells in notebook order, so symbols keep
+/// their order in the notebook. Every symbol also records where it is in Jupyter's
+/// terms: `cell=3` (the third cell) and `cell_line=2` (its line within that cell).
+pub struct NotebookExtractor;
+
+impl LanguageExtractor for NotebookExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_json::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ipynb"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let Some(notebook) = tree.root_node().named_child(0) else {
+            return Vec::new();
+        };
+        let cells = notebook_cells(notebook, source);
+        let python = is_python(notebook, source);
+
+        let mut script = String::new();
+        let mut cell_starts = Vec::with_capacity(cells.len());
+        let mut sections = Vec::new();
+        let mut line = 1;
+        for cell in &cells {
+            cell_starts.push(line);
+            let lines: Vec<&str> = cell.source.lines().collect();
+            match cell.kind {
+                CellKind::Code if python && !cell.source.trim_start().starts_with("%%") => {
+                    for l in &lines {
+                        // `%matplotlib inline`, `!pip install ...` aren't Python
+                        let shell_or_magic = l.trim_start().starts_with(['%', '!']);
+                        if !shell_or_magic {
+                            script.push_str(l);
+                        }
+                        script.push('\n');
+                    }
+                }
+                CellKind::Markdown => {
+                    if let Some(section) = markdown_section(&lines, line) {
+                        sections.push(section);
+                    }
+                    script.push_str(&"\n".repeat(lines.len()));
+                }
+                _ => script.push_str(&"\n".repeat(lines.len())),
+            }
+            line += lines.len();
+        }
+
+        let mut symbols = extract_python(&script);
+        symbols.extend(sections);
+        symbols.sort_by_key(|s| s.start_line);
+        for sym in &mut symbols {
+            record_cell(sym, &cell_starts);
+        }
+        symbols
+    }
+}
+
+enum CellKind {
+    Code,
+    Markdown,
+    Raw,
+}
+
+struct Cell {
+    kind: CellKind,
+    source: String,
+}
+
+/// nbformat 4 cells; a cell's source is a string or a list of lines.
+fn notebook_cells(notebook: Node, source: &[u8]) -> Vec<Cell> {
+    let Some(cells) = member(notebook, "cells", source) else {
+        return Vec::new();
+    };
+    elements(cells)
+        .into_iter()
+        .map(|cell| {
+            let kind = match member(cell, "cell_type", source).and_then(|t| string(t, source)) {
+                Some(t) if t == "code" => CellKind::Code,
+                Some(t) if t == "markdown" => CellKind::Markdown,
+                _ => CellKind::Raw,
+            };
+            let source = match member(cell, "source", source) {
+                Some(s) if s.kind() == "array" => elements(s)
+                    .into_iter()
+                    .filter_map(|line| string(line, source))
+                    .collect(),
+                Some(s) => string(s, source).unwrap_or_default(),
+                None => String::new(),
+            };
+            Cell { kind, source }
+        })
+        .collect()
+}
+
+/// Notebooks without language metadata are assumed to be Python, as Jupyter does.
+fn is_python(notebook: Node, source: &[u8]) -> bool {
+    let metadata = member(notebook, "metadata", source);
+    let language = metadata
+        .and_then(|m| member(m, "language_info", source))
+        .and_then(|l| member(l, "name", source))
+        .or_else(|| {
+            metadata
+                .and_then(|m| member(m, "kernelspec", source))
+                .and_then(|k| member(k, "language", source))
+        })
+        .and_then(|l| string(l, source));
+    language.is_none_or(|l| l.eq_ignore_ascii_case("python"))
+}
+
+/// The value under `key` in a JSON object node.
+fn member<'t>(object: Node<'t>, key: &str, source: &[u8]) -> Option<Node<'t>> {
+    let mut cursor = object.walk();
+    object
+        .named_children(&mut cursor)
+        .filter(|pair| pair.kind() == "pair")
+        .find(|pair| {
+            pair.child_by_field_name("key")
+                .and_then(|k| string(k, source))
+                .is_some_and(|k| k == key)
+        })
+        .and_then(|pair| pair.child_by_field_name("value"))
+}
+
+fn elements(array: Node) -> Vec<Node> {
+    let mut cursor = array.walk();
+    array
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() != "comment")
+        .collect()
+}
+
+/// A JSON string literal with its escapes decoded.
+fn string(node: Node, source: &[u8]) -> Option<String> {
+    if node.kind() != "string" {
+        return None;
+    }
+    serde_json::from_str(node.utf8_text(source).ok()?).ok()
+}
+
+/// A markdown cell, named by its first heading or else its first line.
+fn markdown_section(lines: &[&str], start_line: usize) -> Option<ExtractedSymbol> {
+    let first_line = lines.iter().map(|l| l.trim()).find(|l| !l.is_empty())?;
+    let name = lines
+        .iter()
+        .map(|l| l.trim())
+        .find(|l| l.starts_with('#'))
+        .unwrap_or(first_line)
+        .trim_start_matches('#')
+        .trim();
+    Some(ExtractedSymbol {
+        name: name.to_string(),
+        kind: SymbolKind::Section,
+        signature: None,
+        doc_comment: None,
+        start_line,
+        end_line: start_line + lines.len() - 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+/// Definitions, constants and imports of the code cells. A notebook isn't an
+/// importable module, so the module symbol is dropped.
+fn extract_python(script: &str) -> Vec<ExtractedSymbol> {
+    let extractor = PythonExtractor;
+    let mut parser = Parser::new();
+    if parser.set_language(&extractor.language()).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(script, None) else {
+        return Vec::new();
+    };
+    let mut symbols = extractor.extract(script.as_bytes(), &tree);
+    symbols.retain(|s| s.kind != SymbolKind::Mod || s.name == "_imports");
+    symbols
+}
+
+fn record_cell(sym: &mut ExtractedSymbol, cell_starts: &[usize]) {
+    let cell = cell_starts.partition_point(|&start| start <= sym.start_line);
+    if cell > 0 {
+        sym.metadata.push(("cell".to_string(), cell.to_string()));
+        sym.metadata.push((
+            "cell_line".to_string(),
+            (sym.start_line - cell_starts[cell - 1] + 1).to_string(),
+        ));
+    }
+    for child in &mut sym.children {
+        record_cell(child, cell_starts);
+    }
+}
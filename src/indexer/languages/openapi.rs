@@ -0,0 +1,335 @@
+use tree_sitter::Node;
+
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+/// A YAML or JSON document reduced to plain maps, lists and scalars, keeping the
+/// node of each mapping entry for line numbers.
+pub enum Value<'t> {
+    Map(Vec<Entry<'t>>),
+    List(Vec<Value<'t>>),
+    Scalar(String),
+}
+
+pub struct Entry<'t> {
+    pub key: String,
+    pub node: Node<'t>,
+    pub value: Value<'t>,
+}
+
+impl<'t> Value<'t> {
+    fn get(&self, key: &str) -> Option<&Value<'t>> {
+        self.entries()
+            .iter()
+            .find(|e| e.key == key)
+            .map(|e| &e.value)
+    }
+
+    fn entries(&self) -> &[Entry<'t>] {
+        match self {
+            Value::Map(entries) => entries,
+            _ => &[],
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Scalar(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+pub fn from_yaml<'t>(node: Node<'t>, source: &[u8]) -> Value<'t> {
+    match node.kind() {
+        "block_node" | "flow_node" => {
+            let mut cursor = node.walk();
+            let inner = node
+                .named_children(&mut cursor)
+                .find(|c| !matches!(c.kind(), "tag" | "anchor"));
+            inner
+                .map(|c| from_yaml(c, source))
+                .unwrap_or(Value::Scalar(String::new()))
+        }
+        "block_mapping" | "flow_mapping" => {
+            let mut entries = Vec::new();
+            let mut cursor = node.walk();
+            for pair in node.named_children(&mut cursor) {
+                let Some(key) = pair.child_by_field_name("key") else {
+                    continue;
+                };
+                let value = pair
+                    .child_by_field_name("value")
+                    .map(|v| from_yaml(v, source))
+                    .unwrap_or(Value::Scalar(String::new()));
+                entries.push(Entry {
+                    key: scalar_text(key, source),
+                    node: pair,
+                    value,
+                });
+            }
+            Value::Map(entries)
+        }
+        "block_sequence" | "flow_sequence" => {
+            let mut items = Vec::new();
+            let mut cursor = node.walk();
+            for item in node.named_children(&mut cursor) {
+                // Block items wrap their node in `block_sequence_item`
+                let item = match item.kind() {
+                    "block_sequence_item" => item.named_child(0),
+                    _ => Some(item),
+                };
+                items.extend(item.map(|i| from_yaml(i, source)));
+            }
+            Value::List(items)
+        }
+        "block_scalar" => {
+            // `|` / `>` on the first line, indented content after it
+            let raw = text(node, source);
+            let body = raw.split_once('\n').map(|(_, b)| b).unwrap_or("");
+            Value::Scalar(
+                body.lines()
+                    .map(str::trim)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+                    .trim()
+                    .to_string(),
+            )
+        }
+        _ => Value::Scalar(scalar_text(node, source)),
+    }
+}
+
+pub fn from_json<'t>(node: Node<'t>, source: &[u8]) -> Value<'t> {
+    match node.kind() {
+        "object" => {
+            let mut entries = Vec::new();
+            let mut cursor = node.walk();
+            for pair in node.named_children(&mut cursor) {
+                let (Some(key), Some(value)) = (
+                    pair.child_by_field_name("key"),
+                    pair.child_by_field_name("value"),
+                ) else {
+                    continue;
+                };
+                entries.push(Entry {
+                    key: scalar_text(key, source),
+                    node: pair,
+                    value: from_json(value, source),
+                });
+            }
+            Value::Map(entries)
+        }
+        "array" => {
+            let mut cursor = node.walk();
+            Value::List(
+                node.named_children(&mut cursor)
+                    .filter(|c| c.kind() != "comment")
+                    .map(|c| from_json(c, source))
+                    .collect(),
+            )
+        }
+        _ => Value::Scalar(scalar_text(node, source)),
+    }
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+fn scalar_text(node: Node, source: &[u8]) -> String {
+    let raw = text(node, source);
+    match raw.as_bytes().first() {
+        Some(b'"' | b'\'') if raw.len() >= 2 => raw[1..raw.len() - 1].to_string(),
+        _ => raw,
+    }
+}
+
+pub fn is_openapi(root: &Value) -> bool {
+    root.get("openapi")
+        .or_else(|| root.get("swagger"))
+        .is_some()
+}
+
+const HTTP_METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Reusable component sections (OpenAPI 3 `components.*`, Swagger 2 top-level
+/// maps) and the label each entry gets as its signature. Schemas are handled
+/// separately since their properties become fields.
+const COMPONENT_SECTIONS: &[(&str, &str)] = &[
+    ("parameters", "parameter"),
+    ("requestBodies", "request body"),
+    ("responses", "response"),
+    ("headers", "header"),
+];
+
+/// Operations become functions named by `operationId` (or `GET /path` without
+/// one) and component schemas become structs with their properties as fields.
+/// Every `$ref` turns into a `TypeRef` to the component it points at.
+pub fn extract(root: &Value) -> Vec<ExtractedSymbol> {
+    let mut symbols = Vec::new();
+
+    for path in root.get("paths").map(Value::entries).unwrap_or_default() {
+        let shared = path.value.get("parameters");
+        for op in path.value.entries() {
+            if HTTP_METHODS.contains(&op.key.as_str()) {
+                symbols.push(extract_operation(&path.key, op, shared));
+            }
+        }
+    }
+
+    // OpenAPI 3 nests components; Swagger 2 keeps them at the top level
+    let components = root.get("components").unwrap_or(root);
+    let schemas = components
+        .get("schemas")
+        .or_else(|| root.get("definitions"));
+    for schema in schemas.map(Value::entries).unwrap_or_default() {
+        symbols.push(extract_schema(schema));
+    }
+    for (section, label) in COMPONENT_SECTIONS {
+        for component in components
+            .get(section)
+            .map(Value::entries)
+            .unwrap_or_default()
+        {
+            let mut sym = symbol(component, SymbolKind::Type, Some(label.to_string()));
+            collect_refs(&component.value, &mut sym.references);
+            symbols.push(sym);
+        }
+    }
+    symbols
+}
+
+fn symbol(entry: &Entry, kind: SymbolKind, signature: Option<String>) -> ExtractedSymbol {
+    ExtractedSymbol {
+        name: entry.key.clone(),
+        kind,
+        signature,
+        doc_comment: entry
+            .value
+            .get("description")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        start_line: entry.node.start_position().row + 1,
+        end_line: entry.node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    }
+}
+
+fn extract_operation(path: &str, op: &Entry, shared: Option<&Value>) -> ExtractedSymbol {
+    let route = format!("{} {path}", op.key.to_uppercase());
+    let mut sym = symbol(op, SymbolKind::Fn, Some(route.clone()));
+    if let Some(id) = op.value.get("operationId").and_then(Value::as_str) {
+        sym.name = id.to_string();
+    } else {
+        sym.name = route;
+    }
+    if let Some(summary) = op.value.get("summary").and_then(Value::as_str) {
+        sym.doc_comment = Some(summary.to_string());
+    }
+    if let Some(shared) = shared {
+        collect_refs(shared, &mut sym.references);
+    }
+    collect_refs(&op.value, &mut sym.references);
+    sym
+}
+
+fn extract_schema(schema: &Entry) -> ExtractedSymbol {
+    let values = schema.value.get("enum");
+    let kind = match values {
+        Some(_) => SymbolKind::Enum,
+        None => SymbolKind::Struct,
+    };
+    let mut sym = symbol(schema, kind, None);
+
+    if let Some(Value::List(values)) = values {
+        for value in values.iter().filter_map(Value::as_str) {
+            sym.children.push(ExtractedSymbol {
+                name: value.to_string(),
+                kind: SymbolKind::Const,
+                signature: None,
+                doc_comment: None,
+                start_line: sym.start_line,
+                end_line: sym.start_line,
+                children: Vec::new(),
+                references: Vec::new(),
+                metadata: Vec::new(),
+            });
+        }
+    }
+    for property in schema
+        .value
+        .get("properties")
+        .map(Value::entries)
+        .unwrap_or_default()
+    {
+        sym.children.push(symbol(
+            property,
+            SymbolKind::Var,
+            schema_type(&property.value),
+        ));
+    }
+    collect_refs(&schema.value, &mut sym.references);
+    sym
+}
+
+/// `{type: string}` → `string`, `{$ref: .../User}` → `User`,
+/// `{type: array, items: {$ref: .../User}}` → `User[]`.
+fn schema_type(schema: &Value) -> Option<String> {
+    if let Some(target) = schema.get("$ref").and_then(Value::as_str) {
+        return ref_name(target);
+    }
+    let ty = schema.get("type").and_then(Value::as_str)?;
+    match (ty, schema.get("items")) {
+        ("array", Some(items)) => schema_type(items).map(|item| format!("{item}[]")),
+        _ => Some(ty.to_string()),
+    }
+}
+
+/// `#/components/schemas/User` and `users.yaml#/components/schemas/User` both
+/// name `User`; whole-file refs without a fragment don't name a component.
+fn ref_name(target: &str) -> Option<String> {
+    let (_, fragment) = target.split_once('#')?;
+    fragment
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+}
+
+fn collect_refs(value: &Value, refs: &mut Vec<ExtractedRef>) {
+    match value {
+        Value::Map(entries) => {
+            for entry in entries {
+                let target = entry.value.as_str().filter(|_| entry.key == "$ref");
+                match target.and_then(ref_name) {
+                    Some(name) => push_type_ref(name, entry.node, refs),
+                    None => collect_refs(&entry.value, refs),
+                }
+            }
+        }
+        Value::List(items) => {
+            for item in items {
+                collect_refs(item, refs);
+            }
+        }
+        Value::Scalar(_) => {}
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
@@ -0,0 +1,640 @@
+use std::collections::HashMap;
+
+use tree_sitter::{Node, Tree, TreeCursor};
+
+use super::LanguageExtractor;
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+pub struct PhpExtractor;
+
+impl LanguageExtractor for PhpExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_php::LANGUAGE_PHP.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["php"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let root = tree.root_node();
+        let mut ctx = Context {
+            source,
+            aliases: HashMap::new(),
+            imports: Vec::new(),
+        };
+        collect_uses(root, &mut ctx);
+
+        let mut symbols = extract_statements(root, &ctx);
+
+        if !ctx.imports.is_empty() {
+            let start_line = ctx.imports.iter().map(|r| r.line).min().unwrap_or(1);
+            let end_line = ctx.imports.iter().map(|r| r.line).max().unwrap_or(1);
+            symbols.insert(
+                0,
+                ExtractedSymbol {
+                    name: "_imports".to_string(),
+                    kind: SymbolKind::Mod,
+                    signature: None,
+                    doc_comment: None,
+                    start_line,
+                    end_line,
+                    children: Vec::new(),
+                    references: ctx.imports,
+                    metadata: Vec::new(),
+                },
+            );
+        }
+        symbols
+    }
+}
+
+/// Built-in classes and interfaces that never resolve in-repo — not worth a `TypeRef`.
+/// Scalar types (`string`, `array`, `mixed`, ...) are `primitive_type` nodes and skipped anyway.
+const BUILTIN_TYPES: &[&str] = &[
+    "ArrayAccess",
+    "ArrayIterator",
+    "Closure",
+    "Countable",
+    "DateTime",
+    "DateTimeImmutable",
+    "DateTimeInterface",
+    "Exception",
+    "Generator",
+    "Iterator",
+    "IteratorAggregate",
+    "JsonSerializable",
+    "Stringable",
+    "Throwable",
+    "Traversable",
+    "parent",
+    "self",
+    "static",
+    "stdClass",
+];
+
+struct Context<'a> {
+    source: &'a [u8],
+    /// Short name or `as` alias → the imported name's last segment, from the file's `use` statements
+    aliases: HashMap<String, String>,
+    imports: Vec<ExtractedRef>,
+}
+
+impl Context<'_> {
+    /// Reduces a possibly qualified or aliased class/function name to the short name it
+    /// is declared under: `\App\Models\User` → `User`, `ArrayableContract` → `Arrayable`.
+    fn resolve(&self, name: &str) -> String {
+        let short = name.rsplit('\\').next().unwrap_or(name);
+        if name.contains('\\') {
+            return short.to_string();
+        }
+        self.aliases
+            .get(short)
+            .cloned()
+            .unwrap_or_else(|| short.to_string())
+    }
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let prev = node.prev_sibling()?;
+    if prev.kind() != "comment" {
+        return None;
+    }
+    let t = text(prev, source);
+    if !t.starts_with("/**") {
+        return None;
+    }
+    let cleaned = t
+        .trim_start_matches("/**")
+        .trim_end_matches("*/")
+        .lines()
+        .map(|l| l.trim().trim_start_matches('*').trim())
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if cleaned.is_empty() {
+        None
+    } else {
+        Some(cleaned)
+    }
+}
+
+/// Walks every `use` statement in the file (top level or inside a braced namespace)
+/// to record `Import` refs and the names they bring into scope.
+fn collect_uses(node: Node, ctx: &mut Context) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "namespace_use_declaration" => collect_use_declaration(child, ctx),
+            "namespace_definition" => {
+                if let Some(body) = child.child_by_field_name("body") {
+                    collect_uses(body, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `use A\B;`, `use A\B as C;`, `use function A\f;` and grouped `use A\{B, C as D};`
+fn collect_use_declaration(node: Node, ctx: &mut Context) {
+    let source = ctx.source;
+    let mut prefix = String::new();
+    let mut clauses = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "namespace_name" => prefix = format!("{}\\", text(child, source)),
+            "namespace_use_clause" => clauses.push(child),
+            "namespace_use_group" => {
+                let mut group_cursor = child.walk();
+                clauses.extend(
+                    child
+                        .named_children(&mut group_cursor)
+                        .filter(|c| c.kind() == "namespace_use_clause"),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    for clause in clauses {
+        let alias = clause.child_by_field_name("alias").map(|n| n.id());
+        let mut clause_cursor = clause.walk();
+        let Some(target) = clause
+            .named_children(&mut clause_cursor)
+            .filter(|c| Some(c.id()) != alias)
+            .find(|c| matches!(c.kind(), "qualified_name" | "name"))
+        else {
+            continue;
+        };
+        let full = format!("{prefix}{}", text(target, source));
+        let full = full.trim_start_matches('\\').to_string();
+        let short = full.rsplit('\\').next().unwrap_or(&full).to_string();
+        let local = clause
+            .child_by_field_name("alias")
+            .map(|n| text(n, source))
+            .unwrap_or_else(|| short.clone());
+        ctx.aliases.insert(local, short);
+        ctx.imports.push(ExtractedRef {
+            name: full,
+            kind: RefKind::Import,
+            line: clause.start_position().row + 1,
+        });
+    }
+}
+
+/// Declarations in the file or in a namespace body. `namespace Foo;` owns every
+/// declaration after it, up to the next namespace statement.
+fn extract_statements(node: Node, ctx: &Context) -> Vec<ExtractedSymbol> {
+    let source = ctx.source;
+    let mut symbols = Vec::new();
+    let mut open_namespace: Option<ExtractedSymbol> = None;
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if child.kind() == "namespace_definition" {
+            let name = child
+                .child_by_field_name("name")
+                .map(|n| text(n, source))
+                .unwrap_or_default();
+            let doc_comment = get_doc_comment(child, source);
+            if let Some(body) = child.child_by_field_name("body") {
+                let children = extract_statements(body, ctx);
+                if name.is_empty() {
+                    // `namespace { ... }` is the global namespace
+                    symbols.extend(children);
+                } else {
+                    symbols.push(ExtractedSymbol {
+                        name,
+                        kind: SymbolKind::Mod,
+                        signature: None,
+                        doc_comment,
+                        start_line: child.start_position().row + 1,
+                        end_line: child.end_position().row + 1,
+                        children,
+                        references: Vec::new(),
+                        metadata: Vec::new(),
+                    });
+                }
+            } else {
+                symbols.extend(open_namespace.take());
+                open_namespace = Some(ExtractedSymbol {
+                    name,
+                    kind: SymbolKind::Mod,
+                    signature: None,
+                    doc_comment,
+                    start_line: child.start_position().row + 1,
+                    end_line: child.end_position().row + 1,
+                    children: Vec::new(),
+                    references: Vec::new(),
+                    metadata: Vec::new(),
+                });
+            }
+            continue;
+        }
+
+        let extracted = extract_declaration(child, ctx);
+        if extracted.is_empty() {
+            continue;
+        }
+        match open_namespace.as_mut() {
+            Some(ns) => {
+                ns.end_line = child.end_position().row + 1;
+                ns.children.extend(extracted);
+            }
+            None => symbols.extend(extracted),
+        }
+    }
+
+    symbols.extend(open_namespace);
+    symbols
+}
+
+fn extract_declaration(node: Node, ctx: &Context) -> Vec<ExtractedSymbol> {
+    let sym = match node.kind() {
+        "class_declaration" => extract_type(node, ctx, SymbolKind::Class),
+        "interface_declaration" => extract_type(node, ctx, SymbolKind::Interface),
+        "trait_declaration" => extract_type(node, ctx, SymbolKind::Trait),
+        "enum_declaration" => extract_type(node, ctx, SymbolKind::Enum),
+        "function_definition" => extract_function(node, ctx, SymbolKind::Fn),
+        "const_declaration" => return extract_consts(node, ctx),
+        _ => None,
+    };
+    sym.into_iter().collect()
+}
+
+fn extract_type(node: Node, ctx: &Context, kind: SymbolKind) -> Option<ExtractedSymbol> {
+    let source = ctx.source;
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+
+    let mut refs = Vec::new();
+    extract_attribute_refs(node, ctx, &mut refs);
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let ref_kind = match child.kind() {
+            "base_clause" => RefKind::Extends,
+            "class_interface_clause" => RefKind::Implements,
+            _ => continue,
+        };
+        let mut clause_cursor = child.walk();
+        for base in child.named_children(&mut clause_cursor) {
+            if matches!(base.kind(), "name" | "qualified_name") {
+                refs.push(ExtractedRef {
+                    name: ctx.resolve(&text(base, source)),
+                    kind: ref_kind,
+                    line: base.start_position().row + 1,
+                });
+            }
+        }
+    }
+
+    let mut children = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut body_cursor = body.walk();
+        for member in body.named_children(&mut body_cursor) {
+            match member.kind() {
+                "method_declaration" => {
+                    if let Some(method) = extract_function(member, ctx, SymbolKind::Method) {
+                        children.extend(promoted_properties(member, ctx));
+                        children.push(method);
+                    }
+                }
+                "property_declaration" => extract_properties(member, ctx, &mut children),
+                "const_declaration" => children.extend(extract_consts(member, ctx)),
+                "enum_case" => {
+                    if let Some(case_name) = member.child_by_field_name("name") {
+                        children.push(ExtractedSymbol {
+                            name: text(case_name, source),
+                            kind: SymbolKind::Const,
+                            signature: member.child_by_field_name("value").map(|v| text(v, source)),
+                            doc_comment: get_doc_comment(member, source),
+                            start_line: member.start_position().row + 1,
+                            end_line: member.end_position().row + 1,
+                            children: Vec::new(),
+                            references: Vec::new(),
+                            metadata: Vec::new(),
+                        });
+                    }
+                }
+                // `use SomeTrait, Other { ... }` mixes trait members into the class
+                "use_declaration" => {
+                    let mut use_cursor = member.walk();
+                    for used in member.named_children(&mut use_cursor) {
+                        if matches!(used.kind(), "name" | "qualified_name") {
+                            refs.push(ExtractedRef {
+                                name: ctx.resolve(&text(used, source)),
+                                kind: RefKind::Mixin,
+                                line: used.start_position().row + 1,
+                            });
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Backed enums: `enum Status: string`
+    let signature = if kind == SymbolKind::Enum {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .find(|c| c.kind() == "primitive_type")
+            .map(|t| format!(": {}", text(t, source)))
+    } else {
+        None
+    };
+
+    Some(ExtractedSymbol {
+        name,
+        kind,
+        signature,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children,
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// Functions and methods; signature is `(params): ReturnType` as written.
+fn extract_function(node: Node, ctx: &Context, kind: SymbolKind) -> Option<ExtractedSymbol> {
+    let source = ctx.source;
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+
+    let mut signature = node
+        .child_by_field_name("parameters")
+        .map(|p| text(p, source))
+        .unwrap_or_else(|| "()".to_string());
+    if let Some(ret) = node.child_by_field_name("return_type") {
+        signature.push_str(&format!(": {}", text(ret, source)));
+    }
+
+    let mut refs = Vec::new();
+    extract_attribute_refs(node, ctx, &mut refs);
+    for field in ["parameters", "return_type"] {
+        if let Some(n) = node.child_by_field_name(field) {
+            collect_type_refs(n, ctx, &mut refs);
+        }
+    }
+    if let Some(body) = node.child_by_field_name("body") {
+        extract_calls(body, ctx, &mut refs);
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind,
+        signature: Some(signature),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// Constructor property promotion: `__construct(private string $name)` declares `$name`.
+fn promoted_properties(method: Node, ctx: &Context) -> Vec<ExtractedSymbol> {
+    let source = ctx.source;
+    let Some(params) = method.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+    let mut props = Vec::new();
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        if param.kind() != "property_promotion_parameter" {
+            continue;
+        }
+        let Some(name) = param.child_by_field_name("name") else {
+            continue;
+        };
+        props.push(ExtractedSymbol {
+            name: variable_name(name, source),
+            kind: SymbolKind::Var,
+            signature: param.child_by_field_name("type").map(|t| text(t, source)),
+            doc_comment: None,
+            start_line: param.start_position().row + 1,
+            end_line: param.end_position().row + 1,
+            children: Vec::new(),
+            references: Vec::new(),
+            metadata: Vec::new(),
+        });
+    }
+    props
+}
+
+/// `$name` → `name`, matching how properties are accessed (`$this->name`).
+fn variable_name(node: Node, source: &[u8]) -> String {
+    text(node, source).trim_start_matches('$').to_string()
+}
+
+fn extract_properties(node: Node, ctx: &Context, children: &mut Vec<ExtractedSymbol>) {
+    let source = ctx.source;
+    let type_node = node.child_by_field_name("type");
+    let doc = get_doc_comment(node, source);
+
+    let mut refs = Vec::new();
+    if let Some(t) = type_node {
+        collect_type_refs(t, ctx, &mut refs);
+    }
+
+    let mut cursor = node.walk();
+    for element in node.named_children(&mut cursor) {
+        if element.kind() != "property_element" {
+            continue;
+        }
+        let Some(name) = element.child_by_field_name("name") else {
+            continue;
+        };
+        let mut element_refs = refs.clone();
+        if let Some(default) = element.child_by_field_name("default_value") {
+            extract_calls(default, ctx, &mut element_refs);
+        }
+        children.push(ExtractedSymbol {
+            name: variable_name(name, source),
+            kind: SymbolKind::Var,
+            signature: type_node.map(|t| text(t, source)),
+            doc_comment: doc.clone(),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            children: Vec::new(),
+            references: element_refs,
+            metadata: Vec::new(),
+        });
+    }
+}
+
+/// `const A = 1, B = 2;` at namespace or class level, one symbol per constant.
+fn extract_consts(node: Node, ctx: &Context) -> Vec<ExtractedSymbol> {
+    let source = ctx.source;
+    let doc = get_doc_comment(node, source);
+    let mut consts = Vec::new();
+    let mut cursor = node.walk();
+    for element in node.named_children(&mut cursor) {
+        if element.kind() != "const_element" {
+            continue;
+        }
+        let mut element_cursor = element.walk();
+        let mut parts = element.named_children(&mut element_cursor);
+        let Some(name) = parts.next().filter(|n| n.kind() == "name") else {
+            continue;
+        };
+        consts.push(ExtractedSymbol {
+            name: text(name, source),
+            kind: SymbolKind::Const,
+            signature: parts.next().map(|v| text(v, source)),
+            doc_comment: doc.clone(),
+            start_line: element.start_position().row + 1,
+            end_line: element.end_position().row + 1,
+            children: Vec::new(),
+            references: Vec::new(),
+            metadata: Vec::new(),
+        });
+    }
+    consts
+}
+
+/// PHP 8 attributes (`#[Audited]`) link to the attribute class.
+fn extract_attribute_refs(node: Node, ctx: &Context, refs: &mut Vec<ExtractedRef>) {
+    let Some(list) = node.child_by_field_name("attributes") else {
+        return;
+    };
+    let mut cursor = list.walk();
+    for group in list.named_children(&mut cursor) {
+        let mut group_cursor = group.walk();
+        for attr in group.named_children(&mut group_cursor) {
+            if attr.kind() != "attribute" {
+                continue;
+            }
+            let mut attr_cursor = attr.walk();
+            let Some(name) = attr
+                .named_children(&mut attr_cursor)
+                .find(|c| matches!(c.kind(), "name" | "qualified_name"))
+            else {
+                continue;
+            };
+            push_type_ref(ctx.resolve(&text(name, ctx.source)), attr, refs);
+        }
+    }
+}
+
+fn collect_type_refs(node: Node, ctx: &Context, refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "named_type" => {
+            let mut cursor = node.walk();
+            if let Some(name) = node
+                .named_children(&mut cursor)
+                .find(|c| matches!(c.kind(), "name" | "qualified_name"))
+            {
+                let resolved = ctx.resolve(&text(name, ctx.source));
+                if !BUILTIN_TYPES.contains(&resolved.as_str()) {
+                    push_type_ref(resolved, node, refs);
+                }
+            }
+        }
+        // Only the declared type of a parameter, not its default value or attributes
+        "simple_parameter" | "variadic_parameter" | "property_promotion_parameter" => {
+            if let Some(t) = node.child_by_field_name("type") {
+                collect_type_refs(t, ctx, refs);
+            }
+        }
+        "primitive_type" | "attribute_list" => {}
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, ctx, refs);
+            }
+        }
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
+
+fn extract_calls(node: Node, ctx: &Context, refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    extract_calls_recursive(&mut cursor, ctx, refs);
+}
+
+fn extract_calls_recursive(cursor: &mut TreeCursor, ctx: &Context, refs: &mut Vec<ExtractedRef>) {
+    loop {
+        let node = cursor.node();
+        if let Some(name) = call_name(node, ctx) {
+            refs.push(ExtractedRef {
+                name,
+                kind: RefKind::Call,
+                line: node.start_position().row + 1,
+            });
+        }
+
+        if cursor.goto_first_child() {
+            extract_calls_recursive(cursor, ctx, refs);
+            cursor.goto_parent();
+        }
+
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+}
+
+/// `f()` → `f`, `$this->m()`/`self::m()` → `this.m`, `$obj->m()` → `obj.m`,
+/// `User::make()` → `User.make`, `new User()` → `User`.
+fn call_name(node: Node, ctx: &Context) -> Option<String> {
+    let source = ctx.source;
+    match node.kind() {
+        "function_call_expression" => {
+            let func = node.child_by_field_name("function")?;
+            matches!(func.kind(), "name" | "qualified_name")
+                .then(|| ctx.resolve(&text(func, source)))
+        }
+        "member_call_expression" | "nullsafe_member_call_expression" => {
+            let object = node.child_by_field_name("object")?;
+            let method = text(node.child_by_field_name("name")?, source);
+            let receiver = match object.kind() {
+                "variable_name" => variable_name(object, source),
+                _ => text(object, source).replace("?->", ".").replace("->", "."),
+            };
+            let receiver = receiver.replace('$', "");
+            Some(format!("{receiver}.{method}"))
+        }
+        "scoped_call_expression" => {
+            let scope = node.child_by_field_name("scope")?;
+            let method = text(node.child_by_field_name("name")?, source);
+            let receiver = match text(scope, source).as_str() {
+                "self" | "static" => "this".to_string(),
+                "parent" => "parent".to_string(),
+                other => ctx.resolve(other),
+            };
+            Some(format!("{receiver}.{method}"))
+        }
+        "object_creation_expression" => {
+            let mut cursor = node.walk();
+            let class = node
+                .named_children(&mut cursor)
+                .find(|c| matches!(c.kind(), "name" | "qualified_name"))?;
+            let resolved = ctx.resolve(&text(class, source));
+            (!BUILTIN_TYPES.contains(&resolved.as_str())).then_some(resolved)
+        }
+        _ => None,
+    }
+}
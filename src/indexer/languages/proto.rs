@@ -0,0 +1,338 @@
+use tree_sitter::{Node, Tree};
+
+use super::LanguageExtractor;
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+pub struct ProtoExtractor;
+
+impl LanguageExtractor for ProtoExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_proto::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["proto"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let mut symbols = Vec::new();
+        extract_top_level(tree.root_node(), source, &mut symbols);
+        symbols
+    }
+}
+
+fn extract_top_level(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbol>) {
+    let mut package: Option<ExtractedSymbol> = None;
+    let mut imports = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "package" => package = extract_package(child, source),
+            "import" => {
+                if let Some(path) = child.child_by_field_name("path") {
+                    imports.push(ExtractedRef {
+                        name: text(path, source).trim_matches(['"', '\'']).to_string(),
+                        kind: RefKind::Import,
+                        line: child.start_position().row + 1,
+                    });
+                }
+            }
+            "message" => symbols.extend(extract_message(child, source)),
+            "enum" => symbols.extend(extract_enum(child, source)),
+            "service" => symbols.extend(extract_service(child, source)),
+            _ => {}
+        }
+    }
+
+    // Imports belong to the package; files without one get an `_imports` module
+    let module = match package {
+        Some(mut pkg) => {
+            if let Some(last) = imports.last() {
+                pkg.end_line = pkg.end_line.max(last.line);
+            }
+            pkg.references = imports;
+            Some(pkg)
+        }
+        None if !imports.is_empty() => Some(ExtractedSymbol {
+            name: "_imports".to_string(),
+            kind: SymbolKind::Mod,
+            signature: None,
+            doc_comment: None,
+            start_line: imports[0].line,
+            end_line: imports[imports.len() - 1].line,
+            children: Vec::new(),
+            references: imports,
+            metadata: Vec::new(),
+        }),
+        None => None,
+    };
+    if let Some(module) = module {
+        symbols.insert(0, module);
+    }
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+/// Collects the contiguous `//` comment block directly above a declaration.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut sibling = node.prev_sibling();
+    while let Some(s) = sibling {
+        if s.kind() != "comment" || s.end_position().row + 1 != expected_row {
+            break;
+        }
+        let t = text(s, source);
+        match t.strip_prefix("//") {
+            Some(stripped) => lines.push(stripped.trim().to_string()),
+            None => break,
+        }
+        expected_row = s.start_position().row;
+        sibling = s.prev_sibling();
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
+/// The identifier inside a `message_name`/`enum_name`/`service_name`/`rpc_name` node.
+fn declared_name(node: Node, name_kind: &str, source: &[u8]) -> Option<String> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .find(|c| c.kind() == name_kind)
+        .map(|n| text(n, source))
+}
+
+fn find_child<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).find(|c| c.kind() == kind)
+}
+
+fn extract_package(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = find_child(node, "full_ident").map(|n| text(n, source))?;
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Mod,
+        signature: None,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+/// Messages become structs with their fields as `Var` children. Fields inside a
+/// `oneof` are listed alongside the others, and every message-typed field adds a
+/// `TypeRef` from the message.
+fn extract_message(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = declared_name(node, "message_name", source)?;
+    let mut children = Vec::new();
+    let mut refs = Vec::new();
+    if let Some(body) = find_child(node, "message_body") {
+        extract_message_body(body, source, &mut children, &mut refs);
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Struct,
+        signature: None,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children,
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+fn extract_message_body(
+    body: Node,
+    source: &[u8],
+    children: &mut Vec<ExtractedSymbol>,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    let mut cursor = body.walk();
+    for child in body.named_children(&mut cursor) {
+        match child.kind() {
+            "field" | "oneof_field" | "map_field" => {
+                if let Some(field) = extract_field(child, source, refs) {
+                    children.push(field);
+                }
+            }
+            "oneof" => extract_message_body(child, source, children, refs),
+            "message" => children.extend(extract_message(child, source)),
+            "enum" => children.extend(extract_enum(child, source)),
+            _ => {}
+        }
+    }
+}
+
+/// `repeated Address addresses = 5;` → `addresses` with signature `repeated Address`.
+fn extract_field(
+    node: Node,
+    source: &[u8],
+    refs: &mut Vec<ExtractedRef>,
+) -> Option<ExtractedSymbol> {
+    let name = find_child(node, "identifier").map(|n| text(n, source))?;
+    let type_node = find_child(node, "type")?;
+    if let Some(named) = find_child(type_node, "message_or_enum_type") {
+        push_type_ref(type_name(named, source), named, refs);
+    }
+
+    let signature = match node.kind() {
+        "map_field" => {
+            let key = find_child(node, "key_type").map(|k| text(k, source))?;
+            format!("map<{key}, {}>", text(type_node, source))
+        }
+        _ => {
+            let label = node
+                .child(0)
+                .filter(|c| matches!(c.kind(), "repeated" | "optional" | "required"))
+                .map(|c| format!("{} ", c.kind()))
+                .unwrap_or_default();
+            format!("{label}{}", text(type_node, source))
+        }
+    };
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Var,
+        signature: Some(signature),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+fn extract_enum(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = declared_name(node, "enum_name", source)?;
+    let mut children = Vec::new();
+    if let Some(body) = find_child(node, "enum_body") {
+        let mut cursor = body.walk();
+        for value in body.named_children(&mut cursor) {
+            if value.kind() != "enum_field" {
+                continue;
+            }
+            let Some(value_name) = find_child(value, "identifier") else {
+                continue;
+            };
+            children.push(ExtractedSymbol {
+                name: text(value_name, source),
+                kind: SymbolKind::Const,
+                signature: None,
+                doc_comment: get_doc_comment(value, source),
+                start_line: value.start_position().row + 1,
+                end_line: value.end_position().row + 1,
+                children: Vec::new(),
+                references: Vec::new(),
+                metadata: Vec::new(),
+            });
+        }
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Enum,
+        signature: None,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children,
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+/// Services become interfaces with one method per RPC.
+fn extract_service(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = declared_name(node, "service_name", source)?;
+    let mut children = Vec::new();
+    let mut cursor = node.walk();
+    for rpc in node.named_children(&mut cursor) {
+        if rpc.kind() == "rpc" {
+            children.extend(extract_rpc(rpc, source));
+        }
+    }
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Interface,
+        signature: None,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children,
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+/// `rpc ListUsers(GetUserRequest) returns (stream ListUsersResponse);` → method
+/// with signature `(GetUserRequest) returns (stream ListUsersResponse)` and
+/// `TypeRef`s to both messages.
+fn extract_rpc(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name_node = find_child(node, "rpc_name")?;
+    let mut refs = Vec::new();
+    let mut signature = String::new();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.start_byte() < name_node.end_byte() {
+            continue;
+        }
+        match child.kind() {
+            "{" | ";" => break,
+            "message_or_enum_type" => {
+                push_type_ref(type_name(child, source), child, &mut refs);
+                signature.push_str(&text(child, source));
+            }
+            "returns" | "stream" => {
+                signature.push_str(child.kind());
+                signature.push(' ');
+            }
+            ")" => signature.push_str(") "),
+            _ => signature.push_str(&text(child, source)),
+        }
+    }
+
+    Some(ExtractedSymbol {
+        name: text(name_node, source),
+        kind: SymbolKind::Method,
+        signature: Some(signature.trim_end().to_string()),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
+    })
+}
+
+/// `google.protobuf.Timestamp` → `Timestamp`, the name the message is declared under.
+fn type_name(node: Node, source: &[u8]) -> String {
+    let full = text(node, source);
+    full.rsplit('.').next().unwrap_or(&full).to_string()
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
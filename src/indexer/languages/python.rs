@@ -1,6 +1,8 @@
+use std::path::Path;
+
 use tree_sitter::{Node, Tree, TreeCursor};
 
-use super::LanguageExtractor;
+use super::{FileContext, LanguageExtractor};
 use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
 
 pub struct PythonExtractor;
@@ -15,16 +17,198 @@ impl LanguageExtractor for PythonExtractor {
     }
 
     fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
-        let mut symbols = Vec::new();
-        extract_top_level(tree.root_node(), source, &mut symbols);
-        symbols
+        extract_module("_module", false, source, tree)
+    }
+
+    fn extract_file(&self, file: &FileContext, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let module = module_path(file.rel_path);
+        let name = if module.is_empty() {
+            "__init__"
+        } else {
+            &module
+        };
+        extract_module(name, is_package(file.rel_path), source, tree)
+    }
+}
+
+/// `pkg/sub.py` → `pkg.sub`; a package's `__init__.py` is the package itself (`pkg`).
+fn module_path(rel_path: &str) -> String {
+    let path = Path::new(rel_path);
+    let mut segments: Vec<&str> = path
+        .parent()
+        .into_iter()
+        .flat_map(|p| p.iter())
+        .filter_map(|s| s.to_str())
+        .collect();
+    if !is_package(rel_path)
+        && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+    {
+        segments.push(stem);
+    }
+    segments.join(".")
+}
+
+fn is_package(rel_path: &str) -> bool {
+    Path::new(rel_path)
+        .file_stem()
+        .is_some_and(|stem| stem == "__init__")
+}
+
+/// The absolute module path of an import ref made in `rel_path`: in `pkg/sub.py`,
+/// `.other.Name` → `pkg.other.Name` and `..Name` → `Name`. Absolute imports pass
+/// through; `None` when the dots climb above the repository root.
+pub fn absolute_import(rel_path: &str, import: &str) -> Option<String> {
+    let rest = import.trim_start_matches('.');
+    let dots = import.len() - rest.len();
+    if dots == 0 {
+        return Some(import.to_string());
+    }
+    let module = module_path(rel_path);
+    let mut package: Vec<&str> = module.split('.').filter(|s| !s.is_empty()).collect();
+    if !is_package(rel_path) {
+        package.pop();
+    }
+    package.truncate(package.len().checked_sub(dots - 1)?);
+    package.extend(rest.split('.').filter(|s| !s.is_empty()));
+    Some(package.join("."))
+}
+
+/// The module itself comes first, then `_imports` and the top-level definitions.
+/// Its metadata lists the names it exposes as `export=Name`: `__all__` when set,
+/// otherwise every definition without a leading underscore, plus the names
+/// re-exported by `from ... import` in a package's `__init__.py`. Exposed
+/// definitions are marked `vis=pub`.
+fn extract_module(name: &str, package: bool, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+    let root = tree.root_node();
+    let mut symbols = Vec::new();
+    let mut imports = Vec::new();
+    extract_top_level(root, source, &mut symbols, &mut imports);
+
+    let exports = match dunder_all(root, source) {
+        Some(all) => all,
+        None => {
+            let mut public: Vec<String> = symbols.iter().map(|s| s.name.clone()).collect();
+            if package {
+                public.extend(imported_names(root, source));
+            }
+            public.retain(|n| !n.starts_with('_'));
+            public
+        }
+    };
+    for sym in &mut symbols {
+        if exports.contains(&sym.name) {
+            sym.metadata
+                .insert(0, ("vis".to_string(), "pub".to_string()));
+        }
+    }
+
+    if !imports.is_empty() {
+        symbols.insert(
+            0,
+            ExtractedSymbol {
+                name: "_imports".to_string(),
+                kind: SymbolKind::Mod,
+                signature: None,
+                doc_comment: None,
+                start_line: imports[0].line,
+                end_line: imports[imports.len() - 1].line,
+                children: Vec::new(),
+                references: imports,
+                metadata: Vec::new(),
+            },
+        );
     }
+    symbols.insert(
+        0,
+        ExtractedSymbol {
+            name: name.to_string(),
+            kind: SymbolKind::Mod,
+            signature: None,
+            doc_comment: module_docstring(root, source),
+            start_line: 1,
+            end_line: root.end_position().row + 1,
+            children: Vec::new(),
+            references: Vec::new(),
+            metadata: exports
+                .into_iter()
+                .map(|e| ("export".to_string(), e))
+                .collect(),
+        },
+    );
+    symbols
 }
 
-fn extract_top_level(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbol>) {
+/// Names listed by `__all__ = [...]` and any `__all__ += [...]` that follows.
+fn dunder_all(root: Node, source: &[u8]) -> Option<Vec<String>> {
+    let mut names: Option<Vec<String>> = None;
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        let Some(assignment) = statement
+            .named_child(0)
+            .filter(|_| statement.kind() == "expression_statement")
+            .filter(|a| matches!(a.kind(), "assignment" | "augmented_assignment"))
+        else {
+            continue;
+        };
+        let (Some(left), Some(right)) = (
+            assignment.child_by_field_name("left"),
+            assignment.child_by_field_name("right"),
+        ) else {
+            continue;
+        };
+        if text(left, source) != "__all__" || !matches!(right.kind(), "list" | "tuple") {
+            continue;
+        }
+        let listed = names.get_or_insert_with(Vec::new);
+        if assignment.kind() == "assignment" {
+            listed.clear();
+        }
+        let mut items = right.walk();
+        for item in right.named_children(&mut items) {
+            if item.kind() == "string" {
+                listed.push(
+                    text(item, source)
+                        .trim_matches(|c| c == '"' || c == '\'')
+                        .to_string(),
+                );
+            }
+        }
+    }
+    names
+}
+
+/// Names bound by the module's `from ... import` statements (aliases win).
+fn imported_names(root: Node, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        if statement.kind() != "import_from_statement" {
+            continue;
+        }
+        let mut names_cursor = statement.walk();
+        for name in statement.children_by_field_name("name", &mut names_cursor) {
+            let bound = match name.kind() {
+                "aliased_import" => name.child_by_field_name("alias"),
+                _ => Some(name),
+            };
+            names.extend(bound.map(|b| text(b, source)));
+        }
+    }
+    names
+}
+
+fn extract_top_level(
+    node: Node,
+    source: &[u8],
+    symbols: &mut Vec<ExtractedSymbol>,
+    imports: &mut Vec<ExtractedRef>,
+) {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
+            "import_statement" | "import_from_statement" => {
+                collect_import_refs(child, source, imports)
+            }
             "function_definition" => {
                 if let Some(sym) = extract_function(child, source, false) {
                     symbols.push(sym);
@@ -62,6 +246,53 @@ fn extract_top_level(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbo
     }
 }
 
+/// Builtins and `typing` constructs, which never name a type in the repo.
+const BUILTIN_TYPES: &[&str] = &[
+    "int",
+    "float",
+    "complex",
+    "str",
+    "bytes",
+    "bytearray",
+    "bool",
+    "object",
+    "type",
+    "list",
+    "dict",
+    "set",
+    "frozenset",
+    "tuple",
+    "None",
+    "Any",
+    "Optional",
+    "Union",
+    "List",
+    "Dict",
+    "Set",
+    "FrozenSet",
+    "Tuple",
+    "Type",
+    "Callable",
+    "Iterable",
+    "Iterator",
+    "Sequence",
+    "Mapping",
+    "MutableMapping",
+    "MutableSequence",
+    "Generator",
+    "AsyncGenerator",
+    "AsyncIterator",
+    "Awaitable",
+    "Coroutine",
+    "Literal",
+    "ClassVar",
+    "Final",
+    "Annotated",
+    "Self",
+    "Never",
+    "NoReturn",
+];
+
 fn text(node: Node, source: &[u8]) -> String {
     node.utf8_text(source).unwrap_or("").to_string()
 }
@@ -72,6 +303,17 @@ fn extract_function(node: Node, source: &[u8], is_method: bool) -> Option<Extrac
     let doc = get_docstring(node, source);
 
     let mut refs = Vec::new();
+    if let Some(params) = node.child_by_field_name("parameters") {
+        let mut cursor = params.walk();
+        for param in params.named_children(&mut cursor) {
+            if let Some(annotation) = param.child_by_field_name("type") {
+                collect_type_refs(annotation, source, &mut refs);
+            }
+        }
+    }
+    if let Some(return_type) = node.child_by_field_name("return_type") {
+        collect_type_refs(return_type, source, &mut refs);
+    }
     if let Some(body) = node.child_by_field_name("body") {
         extract_calls(body, source, &mut refs);
     }
@@ -89,6 +331,7 @@ fn extract_function(node: Node, source: &[u8], is_method: bool) -> Option<Extrac
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: refs,
+        metadata: decorator_metadata(node, source),
     })
 }
 
@@ -135,6 +378,14 @@ fn extract_class(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
                     if let Some(sym) = extract_class_constant(child, source) {
                         children.push(sym);
                     }
+                    // Annotated attributes: dataclass fields, pydantic and TypedDict members
+                    if let Some(annotation) = child
+                        .named_child(0)
+                        .filter(|c| c.kind() == "assignment")
+                        .and_then(|a| a.child_by_field_name("type"))
+                    {
+                        collect_type_refs(annotation, source, &mut refs);
+                    }
                 }
                 _ => {}
             }
@@ -150,9 +401,131 @@ fn extract_class(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children,
         references: refs,
+        metadata: decorator_metadata(node, source),
     })
 }
 
+/// Decorators of a decorated function or class, by their callee without arguments:
+/// `@property` → `decorator=property`, `@app.route("/x")` → `decorator=app.route`.
+fn decorator_metadata(node: Node, source: &[u8]) -> Vec<(String, String)> {
+    let Some(decorated) = node.parent().filter(|p| p.kind() == "decorated_definition") else {
+        return Vec::new();
+    };
+    let mut cursor = decorated.walk();
+    decorated
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() == "decorator")
+        .filter_map(|d| d.named_child(0))
+        .map(|expr| match expr.kind() {
+            "call" => expr.child_by_field_name("function").unwrap_or(expr),
+            _ => expr,
+        })
+        .map(|callee| ("decorator".to_string(), text(callee, source)))
+        .collect()
+}
+
+/// One `Import` ref per imported name, qualified by its module as written so
+/// resolution can find the module: `import a.b as c` → `a.b`,
+/// `from .m import X as Y` → `.m.X`, `from . import m` → `.m`, and
+/// `from .m import *` → `.m.*`. Relative dots are kept.
+fn collect_import_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let module = node
+        .child_by_field_name("module_name")
+        .map(|m| text(m, source));
+    let qualify = |name: &str| match &module {
+        Some(m) if m.ends_with('.') => format!("{m}{name}"),
+        Some(m) => format!("{m}.{name}"),
+        None => name.to_string(),
+    };
+
+    let mut cursor = node.walk();
+    for name in node.children_by_field_name("name", &mut cursor) {
+        let imported = match name.kind() {
+            "aliased_import" => name.child_by_field_name("name").unwrap_or(name),
+            _ => name,
+        };
+        push_import(qualify(&text(imported, source)), name, refs);
+    }
+
+    let mut cursor = node.walk();
+    if let Some(wildcard) = node
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "wildcard_import")
+    {
+        push_import(qualify("*"), wildcard, refs);
+    }
+}
+
+fn push_import(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::Import,
+        line: node.start_position().row + 1,
+    });
+}
+
+/// `TypeRef`s for the classes an annotation names: `Optional[list[User]]` → `User`,
+/// `models.User` → `User`, and string forward references (`"User"`) alike.
+fn collect_type_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "identifier" => push_type_ref(text(node, source), node, refs),
+        "attribute" => {
+            if let Some(attr) = node.child_by_field_name("attribute") {
+                push_type_ref(text(attr, source), node, refs);
+            }
+        }
+        "string" => {
+            let quoted = text(node, source);
+            let inner = quoted.trim_matches(|c| c == '"' || c == '\'');
+            if !inner.is_empty()
+                && inner
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+            {
+                let name = inner.rsplit('.').next().unwrap_or(inner);
+                push_type_ref(name.to_string(), node, refs);
+            }
+        }
+        // `Literal["a", "b"]` lists values, not types
+        "generic_type" | "subscript" => {
+            let mut cursor = node.walk();
+            let mut parts = node.named_children(&mut cursor);
+            if let Some(base) = parts.next() {
+                let base_name = text(base, source);
+                collect_type_refs(base, source, refs);
+                if base_name.rsplit('.').next() != Some("Literal") {
+                    for part in parts {
+                        collect_type_refs(part, source, refs);
+                    }
+                }
+            }
+        }
+        // `Annotated[int, Field(gt=0)]`: call arguments are values
+        "call" | "keyword_argument" | "comment" => {}
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, source, refs);
+            }
+        }
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if BUILTIN_TYPES.contains(&name.as_str())
+        || refs
+            .iter()
+            .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
+
 fn build_signature(node: Node, source: &[u8]) -> String {
     let params = node
         .child_by_field_name("parameters")
@@ -173,7 +546,19 @@ fn get_docstring(node: Node, source: &[u8]) -> Option<String> {
     let body = node.child_by_field_name("body")?;
     let mut cursor = body.walk();
     let first_child = body.children(&mut cursor).next()?;
+    statement_docstring(first_child, source)
+}
+
+/// The module docstring, which may follow a shebang or encoding comment.
+fn module_docstring(root: Node, source: &[u8]) -> Option<String> {
+    let mut cursor = root.walk();
+    let first_statement = root
+        .named_children(&mut cursor)
+        .find(|c| c.kind() != "comment")?;
+    statement_docstring(first_statement, source)
+}
 
+fn statement_docstring(first_child: Node, source: &[u8]) -> Option<String> {
     if first_child.kind() != "expression_statement" {
         return None;
     }
@@ -201,12 +586,14 @@ fn strip_triple_quotes(s: &str) -> String {
         return String::new();
     }
     let stripped = &s[3..s.len() - 3];
+    // Blank lines stay: they end paragraphs and docstring sections
     stripped
         .lines()
         .map(|l| l.trim())
-        .filter(|l| !l.is_empty())
         .collect::<Vec<_>>()
         .join("\n")
+        .trim()
+        .to_string()
 }
 
 fn is_upper_snake_case(name: &str) -> bool {
@@ -241,6 +628,7 @@ fn extract_module_constant(node: Node, source: &[u8]) -> Option<ExtractedSymbol>
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: Vec::new(),
+        metadata: Vec::new(),
     })
 }
 
@@ -267,6 +655,7 @@ fn extract_class_constant(node: Node, source: &[u8]) -> Option<ExtractedSymbol>
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: Vec::new(),
+        metadata: Vec::new(),
     })
 }
 
@@ -278,6 +667,9 @@ fn extract_calls(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
 fn extract_calls_recursive(cursor: &mut TreeCursor, source: &[u8], refs: &mut Vec<ExtractedRef>) {
     loop {
         let node = cursor.node();
+        if matches!(node.kind(), "import_statement" | "import_from_statement") {
+            collect_import_refs(node, source, refs);
+        }
         if node.kind() == "call" {
             if let Some(func) = node.child_by_field_name("function") {
                 let name = match func.kind() {
@@ -0,0 +1,524 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{Context, Result};
+use tree_sitter::{Node, Parser, Query, QueryCursor, QueryMatch, StreamingIterator, Tree};
+
+use super::loader::GrammarLoader;
+use super::{LanguageExtractor, find_grammar, match_filename};
+use crate::config::{QueriesConfig, QueryLanguageConfig};
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+/// A language extracted by a tree-sitter query pack instead of a hand-written
+/// module. Packs follow the `tags.scm` conventions: `@definition.<kind>` on a
+/// declaration together with its `@name`, and `@reference.<kind>` on a use, named
+/// by its own text or by an inner `@name`. Optional `@doc` and `@signature`
+/// captures fill in the symbol's doc comment and signature.
+///
+/// Definitions nest by position and references belong to the innermost
+/// definition around them; imports outside any definition go on an `_imports`
+/// module. When several patterns define the same `@name` node, the one written
+/// first in the pack wins.
+pub struct QueryExtractor {
+    /// Language recorded for matched files; packs from the queries directory
+    /// keep the built-in name
+    name: Option<String>,
+    language: tree_sitter::Language,
+    extensions: Vec<String>,
+    filenames: Vec<String>,
+    query: Query,
+    /// What each capture index stands for
+    roles: Vec<Role>,
+}
+
+#[derive(Clone, Copy)]
+enum Role {
+    Definition(SymbolKind),
+    Reference(RefKind),
+    Name,
+    Doc,
+    Signature,
+    Ignored,
+}
+
+impl QueryExtractor {
+    fn new(
+        name: Option<String>,
+        language: tree_sitter::Language,
+        extensions: Vec<String>,
+        filenames: Vec<String>,
+        source: &str,
+    ) -> Result<Self> {
+        let query = Query::new(&language, source)?;
+        let roles = query
+            .capture_names()
+            .iter()
+            .map(|capture| capture_role(capture))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            name,
+            language,
+            extensions,
+            filenames,
+            query,
+            roles,
+        })
+    }
+}
+
+impl LanguageExtractor for QueryExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        self.language.clone()
+    }
+
+    // Configured at run time, so matched by `handles_extension` and `match_filename`
+    fn extensions(&self) -> &[&str] {
+        &[]
+    }
+
+    fn handles_extension(&self, ext: &str) -> bool {
+        self.extensions.iter().any(|known| known == ext)
+    }
+
+    fn match_filename(&self, name: &str) -> Option<String> {
+        match_filename(&self.filenames, name)
+    }
+
+    fn language_name(&self, ext: &str) -> Option<&str> {
+        self.name.as_deref().or_else(|| super::detect_language(ext))
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let mut found = Found::default();
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&self.query, tree.root_node(), source);
+        while let Some(m) = matches.next() {
+            self.collect_match(m, source, &mut found);
+        }
+        build_symbols(found)
+    }
+}
+
+/// A repo's query extractors, compiled once and shared by every index run over it,
+/// together with the parser those runs use.
+pub struct QueryPacks {
+    extractors: Vec<QueryExtractor>,
+    /// Holds the WASM store any pack grammar was loaded into
+    parser: Mutex<Parser>,
+}
+
+impl Default for QueryPacks {
+    fn default() -> Self {
+        Self {
+            extractors: Vec::new(),
+            parser: Mutex::new(Parser::new()),
+        }
+    }
+}
+
+impl QueryPacks {
+    /// Compiles the query extractors for a repo: the languages declared in
+    /// `indexer.queries.languages`, then every `<grammar>.scm` in the queries
+    /// directory that none of them claims. Packs that fail to load are logged and
+    /// skipped so one broken query doesn't stop indexing.
+    pub fn load(repo_root: &Path, config: &QueriesConfig) -> Self {
+        let mut parser = Parser::new();
+        let extractors = load(repo_root, config, &mut parser);
+        Self {
+            extractors,
+            parser: Mutex::new(parser),
+        }
+    }
+
+    pub fn extractors(&self) -> &[QueryExtractor] {
+        &self.extractors
+    }
+
+    /// The parser to index with, set up for any WASM grammars the packs loaded.
+    pub fn parser(&self) -> MutexGuard<'_, Parser> {
+        self.parser.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// `parser` is set up for any WASM grammars the declared languages load.
+fn load(repo_root: &Path, config: &QueriesConfig, parser: &mut Parser) -> Vec<QueryExtractor> {
+    let dir = repo_root.join(&config.dir);
+    let mut extractors = Vec::new();
+    let mut claimed = Vec::new();
+    let mut loader = GrammarLoader::new(repo_root, config);
+
+    for language in &config.languages {
+        let path = match &language.query {
+            Some(query) => repo_root.join(query),
+            None => dir.join(format!("{}.scm", language.name)),
+        };
+        match load_declared(language, &path, &mut loader) {
+            Ok(extractor) => extractors.push(extractor),
+            Err(e) => {
+                tracing::warn!(language = %language.name, error = %e, "Failed to load query pack, skipping");
+            }
+        }
+        claimed.push(path);
+    }
+    loader.install(parser);
+
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return extractors;
+    };
+    let mut packs: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "scm") && !claimed.contains(p))
+        .collect();
+    packs.sort();
+
+    for path in packs {
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let Some(grammar) = find_grammar(stem) else {
+            tracing::warn!(file = %path.display(), "No built-in grammar named after query pack, skipping");
+            continue;
+        };
+        let extractor = read_query(&path).and_then(|source| {
+            QueryExtractor::new(
+                None,
+                (grammar.language)(),
+                grammar
+                    .extensions
+                    .iter()
+                    .map(|ext| ext.to_string())
+                    .collect(),
+                Vec::new(),
+                &source,
+            )
+        });
+        match extractor {
+            Ok(extractor) => extractors.push(extractor),
+            Err(e) => {
+                tracing::warn!(file = %path.display(), error = %e, "Failed to load query pack, skipping");
+            }
+        }
+    }
+    extractors
+}
+
+fn load_declared(
+    language: &QueryLanguageConfig,
+    path: &Path,
+    loader: &mut GrammarLoader,
+) -> Result<QueryExtractor> {
+    let grammar_name = language.grammar.as_deref().unwrap_or(&language.name);
+    let grammar = match &language.grammar_path {
+        Some(grammar_path) => loader.load(grammar_path, grammar_name)?,
+        None => find_grammar(grammar_name)
+            .map(|grammar| (grammar.language)())
+            .with_context(|| format!("no built-in grammar named `{grammar_name}`"))?,
+    };
+    QueryExtractor::new(
+        Some(language.name.clone()),
+        grammar,
+        language
+            .extensions
+            .iter()
+            .map(|ext| ext.trim_start_matches('.').to_string())
+            .collect(),
+        language.filenames.clone(),
+        &read_query(path)?,
+    )
+}
+
+fn read_query(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+fn capture_role(capture: &str) -> Result<Role> {
+    if let Some(kind) = capture.strip_prefix("definition.") {
+        return symbol_kind(kind)
+            .map(Role::Definition)
+            .with_context(|| format!("unknown definition kind in `@{capture}`"));
+    }
+    if let Some(kind) = capture.strip_prefix("reference.") {
+        return ref_kind(kind)
+            .map(Role::Reference)
+            .with_context(|| format!("unknown reference kind in `@{capture}`"));
+    }
+    Ok(match capture {
+        "name" => Role::Name,
+        "doc" => Role::Doc,
+        "signature" => Role::Signature,
+        _ => Role::Ignored,
+    })
+}
+
+/// Accepts the `tags.scm` names as well as ctxhelpr's own.
+fn symbol_kind(kind: &str) -> Option<SymbolKind> {
+    Some(match kind {
+        "function" | "fn" => SymbolKind::Fn,
+        "method" => SymbolKind::Method,
+        "class" => SymbolKind::Class,
+        "interface" => SymbolKind::Interface,
+        "type" => SymbolKind::Type,
+        "struct" => SymbolKind::Struct,
+        "enum" => SymbolKind::Enum,
+        "trait" => SymbolKind::Trait,
+        "module" | "mod" | "namespace" => SymbolKind::Mod,
+        "constant" | "const" => SymbolKind::Const,
+        "variable" | "var" | "field" | "property" => SymbolKind::Var,
+        "implementation" | "impl" => SymbolKind::Impl,
+        "section" => SymbolKind::Section,
+        "macro" => SymbolKind::Macro,
+        "key" => SymbolKind::Key,
+        "code" => SymbolKind::Code,
+        _ => return None,
+    })
+}
+
+fn ref_kind(kind: &str) -> Option<RefKind> {
+    Some(match kind {
+        "call" | "send" => RefKind::Call,
+        "import" | "include" | "require" => RefKind::Import,
+        "type" | "type_ref" | "class" | "interface" => RefKind::TypeRef,
+        "extends" | "inheritance" | "superclass" => RefKind::Extends,
+        "implementation" | "implements" => RefKind::Implements,
+        "definition" => RefKind::Definition,
+        "mixin" => RefKind::Mixin,
+        "mention" => RefKind::Mention,
+        _ => return None,
+    })
+}
+
+struct Definition {
+    symbol: ExtractedSymbol,
+    start_byte: usize,
+    end_byte: usize,
+    pattern: usize,
+}
+
+struct Reference {
+    reference: ExtractedRef,
+    start_byte: usize,
+    end_byte: usize,
+}
+
+#[derive(Default)]
+struct Found {
+    definitions: Vec<Definition>,
+    /// `@name` node range → index into `definitions`
+    by_name: HashMap<(usize, usize), usize>,
+    references: Vec<Reference>,
+    /// Reference node ranges already recorded, per kind
+    seen_references: HashSet<(usize, usize, &'static str)>,
+}
+
+impl QueryExtractor {
+    fn collect_match(&self, m: &QueryMatch, source: &[u8], found: &mut Found) {
+        let mut definition = None;
+        let mut references = Vec::new();
+        let mut name = None;
+        let mut docs = Vec::new();
+        let mut signature: Option<(usize, usize)> = None;
+
+        for capture in m.captures {
+            let node = capture.node;
+            match self.roles[capture.index as usize] {
+                Role::Definition(kind) => definition = Some((kind, node)),
+                Role::Reference(kind) => references.push((kind, node)),
+                Role::Name => name = Some(node),
+                Role::Doc => docs.push(node),
+                Role::Signature => {
+                    let (start, end) = signature.unwrap_or((node.start_byte(), node.end_byte()));
+                    signature = Some((start.min(node.start_byte()), end.max(node.end_byte())));
+                }
+                Role::Ignored => {}
+            }
+        }
+
+        // `@name` names the definition when the pattern has one, else the reference
+        for (kind, node) in references {
+            let target = match (definition, name) {
+                (None, Some(name)) => name,
+                _ => node,
+            };
+            let key = (target.start_byte(), target.end_byte(), kind.as_str());
+            if !found.seen_references.insert(key) {
+                continue;
+            }
+            found.references.push(Reference {
+                reference: ExtractedRef {
+                    name: one_line(&text(target, source)),
+                    kind,
+                    line: target.start_position().row + 1,
+                },
+                start_byte: target.start_byte(),
+                end_byte: target.end_byte(),
+            });
+        }
+
+        let (Some((kind, node)), Some(name)) = (definition, name) else {
+            return;
+        };
+        let key = (name.start_byte(), name.end_byte());
+        let existing = found.by_name.get(&key).copied();
+        if existing.is_some_and(|i| found.definitions[i].pattern <= m.pattern_index) {
+            return;
+        }
+
+        docs.sort_by_key(|d| d.start_byte());
+        let doc = docs
+            .iter()
+            .map(|d| clean_doc(&text(*d, source)))
+            .filter(|d| !d.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let definition = Definition {
+            symbol: ExtractedSymbol {
+                name: text(name, source),
+                kind,
+                signature: signature
+                    .and_then(|(start, end)| source.get(start..end))
+                    .map(|s| one_line(&String::from_utf8_lossy(s))),
+                doc_comment: (!doc.is_empty()).then_some(doc),
+                start_line: node.start_position().row + 1,
+                end_line: node.end_position().row + 1,
+                children: Vec::new(),
+                references: Vec::new(),
+                metadata: Vec::new(),
+            },
+            start_byte: node.start_byte(),
+            end_byte: node.end_byte(),
+            pattern: m.pattern_index,
+        };
+        match existing {
+            Some(i) => found.definitions[i] = definition,
+            None => {
+                found.by_name.insert(key, found.definitions.len());
+                found.definitions.push(definition);
+            }
+        }
+    }
+}
+
+/// Nests definitions by their byte ranges and hands each reference to the
+/// innermost definition containing it.
+fn build_symbols(found: Found) -> Vec<ExtractedSymbol> {
+    let mut definitions = found.definitions;
+    definitions.sort_by(|a, b| {
+        a.start_byte
+            .cmp(&b.start_byte)
+            .then(b.end_byte.cmp(&a.end_byte))
+    });
+
+    let mut parents: Vec<Option<usize>> = Vec::with_capacity(definitions.len());
+    let mut stack: Vec<usize> = Vec::new();
+    for (i, def) in definitions.iter().enumerate() {
+        while let Some(&top) = stack.last() {
+            if definitions[top].end_byte >= def.end_byte
+                && def.start_byte < definitions[top].end_byte
+            {
+                break;
+            }
+            stack.pop();
+        }
+        parents.push(stack.last().copied());
+        stack.push(i);
+    }
+
+    let mut imports = Vec::new();
+    let mut references = found.references;
+    references.sort_by_key(|r| r.start_byte);
+    for r in references {
+        // Later definitions in sorted order are the more deeply nested ones
+        let owner = definitions
+            .iter()
+            .rposition(|d| d.start_byte <= r.start_byte && r.end_byte <= d.end_byte);
+        match owner {
+            Some(i) => definitions[i].symbol.references.push(r.reference),
+            None if r.reference.kind == RefKind::Import => imports.push(r.reference),
+            None => {}
+        }
+    }
+
+    // Children sort after their parents, so building back to front finishes
+    // every child before its parent takes it
+    let mut built: Vec<Option<ExtractedSymbol>> =
+        definitions.into_iter().map(|d| Some(d.symbol)).collect();
+    let mut children: Vec<Vec<ExtractedSymbol>> = vec![Vec::new(); built.len()];
+    let mut symbols = Vec::new();
+    for i in (0..built.len()).rev() {
+        let Some(mut symbol) = built[i].take() else {
+            continue;
+        };
+        let mut own = std::mem::take(&mut children[i]);
+        own.reverse();
+        symbol.children = own;
+        match parents[i] {
+            Some(parent) => children[parent].push(symbol),
+            None => symbols.push(symbol),
+        }
+    }
+    symbols.reverse();
+
+    if !imports.is_empty() {
+        symbols.insert(
+            0,
+            ExtractedSymbol {
+                name: "_imports".to_string(),
+                kind: SymbolKind::Mod,
+                signature: None,
+                doc_comment: None,
+                start_line: imports[0].line,
+                end_line: imports[imports.len() - 1].line,
+                children: Vec::new(),
+                references: imports,
+                metadata: Vec::new(),
+            },
+        );
+    }
+    symbols
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+fn one_line(raw: &str) -> String {
+    raw.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+const LINE_COMMENT_PREFIXES: &[&str] = &["///", "//!", "//", "--", "#", ";;", ";", "*"];
+
+/// Strips docstring quotes and comment markers from a `@doc` capture. Blank
+/// lines stay, since they separate paragraphs and docstring sections.
+fn clean_doc(raw: &str) -> String {
+    let raw = raw.trim();
+    let docstring = ["\"\"\"", "'''"]
+        .iter()
+        .find_map(|q| raw.strip_prefix(q).and_then(|r| r.strip_suffix(q)));
+    let inner = docstring
+        .or_else(|| {
+            raw.strip_prefix("/*")
+                .and_then(|r| r.strip_suffix("*/"))
+                .map(|r| r.trim_start_matches('*'))
+        })
+        .unwrap_or(raw);
+    inner
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            if docstring.is_some() {
+                // A NumPy `-------` underline isn't a comment
+                return line;
+            }
+            LINE_COMMENT_PREFIXES
+                .iter()
+                .find_map(|p| line.strip_prefix(p))
+                .unwrap_or(line)
+                .trim()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
@@ -104,6 +104,7 @@ fn extract_method(node: Node, source: &[u8], is_in_class: bool) -> Option<Extrac
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: refs,
+        metadata: Vec::new(),
     })
 }
 
@@ -137,6 +138,7 @@ fn extract_singleton_method(node: Node, source: &[u8]) -> Option<ExtractedSymbol
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: refs,
+        metadata: Vec::new(),
     })
 }
 
@@ -175,6 +177,7 @@ fn extract_class(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children,
         references: refs,
+        metadata: Vec::new(),
     })
 }
 
@@ -202,35 +205,242 @@ fn extract_class_body(
                     children.push(sym);
                 }
             }
-            "call" => {
-                // Handle include/extend/require at class level
-                if let Some(method_name) = child.child_by_field_name("method") {
-                    let mname = text(method_name, source);
-                    match mname.as_str() {
-                        "include" | "extend" => {
-                            if let Some(args) = child.child_by_field_name("arguments") {
-                                let mut ac = args.walk();
-                                for arg in args.children(&mut ac) {
-                                    if arg.kind() == "constant" || arg.kind() == "scope_resolution"
-                                    {
-                                        refs.push(ExtractedRef {
-                                            name: text(arg, source),
-                                            kind: RefKind::Extends,
-                                            line: arg.start_position().row + 1,
-                                        });
-                                    }
-                                }
-                            }
-                        }
-                        _ => {}
-                    }
+            "call" => extract_class_macro(child, source, children, refs),
+            _ => {}
+        }
+    }
+}
+
+/// Rails association macros, whose name points at a model class.
+const ASSOCIATIONS: &[&str] = &[
+    "belongs_to",
+    "has_one",
+    "has_many",
+    "has_and_belongs_to_many",
+];
+
+/// Validation and callback macros. Arguments of `validate` and the callbacks name
+/// methods of the class; those of `validates*` name attributes.
+const VALIDATIONS: &[&str] = &[
+    "validates",
+    "validate",
+    "validates_presence_of",
+    "validates_uniqueness_of",
+    "validates_format_of",
+    "validates_length_of",
+    "validates_inclusion_of",
+    "validates_associated",
+];
+
+const CALLBACKS: &[&str] = &[
+    "before_action",
+    "after_action",
+    "around_action",
+    "skip_before_action",
+    "prepend_before_action",
+    "before_validation",
+    "after_validation",
+    "before_save",
+    "after_save",
+    "around_save",
+    "before_create",
+    "after_create",
+    "before_update",
+    "after_update",
+    "before_destroy",
+    "after_destroy",
+    "after_commit",
+    "after_rollback",
+    "after_initialize",
+];
+
+/// Class-level macro calls: `include`/`extend`/`prepend` become `Mixin` refs,
+/// `attr_accessor :a, :b` declares `a` and `b`, and the Rails DSL becomes
+/// symbols tagged `dsl=<macro>`: `has_many :comments` is a `comments` var with a
+/// `TypeRef` to `Comment`, `scope :recent, -> { ... }` a `recent` method, and
+/// validations and callbacks a symbol named by the macro, calling the methods
+/// they name.
+fn extract_class_macro(
+    node: Node,
+    source: &[u8],
+    children: &mut Vec<ExtractedSymbol>,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    if node.child_by_field_name("receiver").is_some() {
+        return;
+    }
+    let Some(macro_name) = node.child_by_field_name("method").map(|m| text(m, source)) else {
+        return;
+    };
+    let Some(args) = node.child_by_field_name("arguments") else {
+        return;
+    };
+    let mut cursor = args.walk();
+    let args: Vec<Node> = args.named_children(&mut cursor).collect();
+    let symbols = || {
+        args.iter()
+            .filter(|a| a.kind() == "simple_symbol")
+            .map(|a| (symbol_name(*a, source), *a))
+    };
+    let line = node.start_position().row + 1;
+    let signature = text(node, source)
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+    let dsl_symbol =
+        |name: String, kind: SymbolKind, references: Vec<ExtractedRef>| ExtractedSymbol {
+            name,
+            kind,
+            signature: Some(signature.clone()),
+            doc_comment: get_doc_comment(node, source),
+            start_line: line,
+            end_line: node.end_position().row + 1,
+            children: Vec::new(),
+            references,
+            metadata: vec![("dsl".to_string(), macro_name.clone())],
+        };
+
+    match macro_name.as_str() {
+        "include" | "extend" | "prepend" => {
+            for arg in &args {
+                if arg.kind() == "constant" || arg.kind() == "scope_resolution" {
+                    refs.push(ExtractedRef {
+                        name: text(*arg, source),
+                        kind: RefKind::Mixin,
+                        line: arg.start_position().row + 1,
+                    });
                 }
             }
-            _ => {}
         }
+        "attr_accessor" | "attr_reader" | "attr_writer" => {
+            for (name, arg) in symbols() {
+                children.push(ExtractedSymbol {
+                    name,
+                    kind: SymbolKind::Var,
+                    signature: Some(macro_name.clone()),
+                    doc_comment: get_doc_comment(node, source),
+                    start_line: arg.start_position().row + 1,
+                    end_line: arg.end_position().row + 1,
+                    children: Vec::new(),
+                    references: Vec::new(),
+                    metadata: Vec::new(),
+                });
+            }
+        }
+        m if ASSOCIATIONS.contains(&m) => {
+            let Some((name, _)) = symbols().next() else {
+                return;
+            };
+            let mut references = Vec::new();
+            if option_value(&args, "polymorphic", source).is_none() {
+                let model = option_value(&args, "class_name", source).unwrap_or_else(|| {
+                    let singular = if m.starts_with("has_many") || m.starts_with("has_and") {
+                        singularize(&name)
+                    } else {
+                        name.clone()
+                    };
+                    camelize(&singular)
+                });
+                references.push(ExtractedRef {
+                    name: model,
+                    kind: RefKind::TypeRef,
+                    line,
+                });
+            }
+            children.push(dsl_symbol(name, SymbolKind::Var, references));
+        }
+        "scope" => {
+            let Some((name, _)) = symbols().next() else {
+                return;
+            };
+            let mut references = Vec::new();
+            for arg in args.iter().filter(|a| a.kind() == "lambda") {
+                extract_calls(*arg, source, &mut references);
+            }
+            children.push(dsl_symbol(name, SymbolKind::Method, references));
+        }
+        m if VALIDATIONS.contains(&m) || CALLBACKS.contains(&m) => {
+            let references = if m.starts_with("validates") {
+                Vec::new()
+            } else {
+                symbols()
+                    .map(|(name, arg)| ExtractedRef {
+                        name,
+                        kind: RefKind::Call,
+                        line: arg.start_position().row + 1,
+                    })
+                    .collect()
+            };
+            children.push(dsl_symbol(
+                macro_name.clone(),
+                SymbolKind::Method,
+                references,
+            ));
+        }
+        _ => {}
     }
 }
 
+fn symbol_name(node: Node, source: &[u8]) -> String {
+    text(node, source).trim_start_matches(':').to_string()
+}
+
+/// The value of a `key: value` (or `:key => value`) option, without quotes or colon.
+fn option_value(args: &[Node], key: &str, source: &[u8]) -> Option<String> {
+    args.iter()
+        .flat_map(|arg| {
+            let mut cursor = arg.walk();
+            if arg.kind() == "hash" {
+                arg.named_children(&mut cursor).collect()
+            } else {
+                vec![*arg]
+            }
+        })
+        .filter(|arg| arg.kind() == "pair")
+        .find(|pair| {
+            pair.child_by_field_name("key")
+                .is_some_and(|k| text(k, source).trim_matches(':') == key)
+        })
+        .and_then(|pair| pair.child_by_field_name("value"))
+        .map(|value| {
+            text(value, source)
+                .trim_start_matches(':')
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_string()
+        })
+}
+
+/// `categories` → `category`, `addresses` → `address`, `posts` → `post`.
+fn singularize(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if ["sses", "xes", "ches", "shes"]
+        .iter()
+        .any(|suffix| word.ends_with(suffix))
+    {
+        word[..word.len() - 2].to_string()
+    } else if word.ends_with('s') && !word.ends_with("ss") {
+        word[..word.len() - 1].to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+/// `cover_image` → `CoverImage`
+fn camelize(word: &str) -> String {
+    word.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
 fn extract_module(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
     let doc = get_doc_comment(node, source);
@@ -250,6 +460,7 @@ fn extract_module(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children,
         references: refs,
+        metadata: Vec::new(),
     })
 }
 
@@ -276,6 +487,7 @@ fn extract_constant(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: Vec::new(),
+        metadata: Vec::new(),
     })
 }
 
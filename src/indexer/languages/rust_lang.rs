@@ -1,6 +1,8 @@
+use std::path::Path;
+
 use tree_sitter::{Node, Tree, TreeCursor};
 
-use super::LanguageExtractor;
+use super::{FileContext, LanguageExtractor};
 use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
 
 pub struct RustExtractor;
@@ -16,17 +18,83 @@ impl LanguageExtractor for RustExtractor {
 
     fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
         let mut symbols = Vec::new();
-        extract_top_level(tree.root_node(), source, &mut symbols);
+        let mut imports = Vec::new();
+        extract_top_level(tree.root_node(), source, &mut symbols, &mut imports);
+        if !imports.is_empty() {
+            symbols.insert(
+                0,
+                ExtractedSymbol {
+                    name: "_imports".to_string(),
+                    kind: SymbolKind::Mod,
+                    signature: None,
+                    doc_comment: None,
+                    start_line: imports[0].line,
+                    end_line: imports[imports.len() - 1].line,
+                    children: Vec::new(),
+                    references: imports,
+                    metadata: Vec::new(),
+                },
+            );
+        }
+        symbols
+    }
+
+    fn extract_file(&self, file: &FileContext, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let root = tree.root_node();
+        let mut symbols = self.extract(source, tree);
+        symbols.insert(
+            0,
+            ExtractedSymbol {
+                name: module_name(file.rel_path).to_string(),
+                kind: SymbolKind::Mod,
+                signature: None,
+                doc_comment: inner_doc(root, source),
+                start_line: 1,
+                end_line: root.end_position().row + 1,
+                children: Vec::new(),
+                references: Vec::new(),
+                metadata: Vec::new(),
+            },
+        );
         symbols
     }
 }
 
-fn extract_top_level(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbol>) {
+/// The module a file is: `src/net/tcp.rs` → `tcp`, `src/net/mod.rs` → `net`, and
+/// `crate` for a crate root (`lib.rs`, `main.rs`).
+fn module_name(rel_path: &str) -> &str {
+    let path = Path::new(rel_path);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    match stem {
+        "lib" | "main" => "crate",
+        "mod" => path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|s| s.to_str())
+            .unwrap_or("crate"),
+        _ => stem,
+    }
+}
+
+/// Items of a file or inline module. `use`, `extern crate` and `mod foo;`
+/// declarations go to `imports`, owned by the module (or the file's `_imports`).
+fn extract_top_level(
+    node: Node,
+    source: &[u8],
+    symbols: &mut Vec<ExtractedSymbol>,
+    imports: &mut Vec<ExtractedRef>,
+) {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
+            "use_declaration" => collect_use_refs(child, source, imports),
+            "extern_crate_declaration" => {
+                if let Some(name) = child.child_by_field_name("name") {
+                    push_import(text(name, source), child, imports);
+                }
+            }
             "function_item" => {
-                if let Some(sym) = extract_function(child, source, false) {
+                if let Some(sym) = extract_function(child, source, false, &[]) {
                     symbols.push(sym);
                 }
             }
@@ -51,12 +119,18 @@ fn extract_top_level(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbo
                 }
             }
             "mod_item" => {
-                if let Some(sym) = extract_mod(child, source) {
-                    symbols.push(sym);
+                if child.child_by_field_name("body").is_some() {
+                    if let Some(sym) = extract_mod(child, source) {
+                        symbols.push(sym);
+                    }
+                } else if let Some(name) = child.child_by_field_name("name") {
+                    // `mod foo;` pulls in another file, whose own module symbol
+                    // the import resolves to
+                    push_import(text(name, source), child, imports);
                 }
             }
             "type_item" => {
-                if let Some(sym) = extract_type_alias(child, source) {
+                if let Some(sym) = extract_type_alias(child, source, &[]) {
                     symbols.push(sym);
                 }
             }
@@ -65,34 +139,220 @@ fn extract_top_level(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbo
                     symbols.push(sym);
                 }
             }
+            "macro_definition" => {
+                if let Some(sym) = extract_macro(child, source) {
+                    symbols.push(sym);
+                }
+            }
             _ => {}
         }
     }
 }
 
+/// Primitives, `Self` and the prelude types and traits that show up in nearly
+/// every signature — never worth a `TypeRef`. `Result` is left out since crates
+/// commonly alias it.
+const BUILTIN_TYPES: &[&str] = &[
+    "Self", "bool", "char", "str", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32",
+    "u64", "u128", "usize", "f32", "f64", "Box", "Option", "String", "Vec", "Fn", "FnMut",
+    "FnOnce", "Send", "Sync", "Sized",
+];
+
+/// Standard library macros, invoked everywhere and never defined in the repo.
+const STD_MACROS: &[&str] = &[
+    "println",
+    "print",
+    "eprintln",
+    "eprint",
+    "format",
+    "format_args",
+    "write",
+    "writeln",
+    "vec",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "panic",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "matches",
+    "dbg",
+    "concat",
+    "stringify",
+    "include_str",
+    "include_bytes",
+    "env",
+    "option_env",
+    "line",
+    "file",
+    "column",
+    "cfg",
+    "compile_error",
+    "thread_local",
+];
+
 fn text(node: Node, source: &[u8]) -> String {
     node.utf8_text(source).unwrap_or("").to_string()
 }
 
+/// Outer docs in any form — `///`, `/** */`, `#[doc = "..."]` — then, for inline
+/// modules, the inner `//!`, `/*! */` and `#![doc = "..."]` docs at the top of the body.
 fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
-    let mut lines = Vec::new();
+    let mut parts = Vec::new();
     let mut sibling = node.prev_sibling();
     while let Some(s) = sibling {
-        if s.kind() == "line_comment" {
-            let t = text(s, source);
-            if let Some(stripped) = t.strip_prefix("///") {
-                lines.push(stripped.trim().to_string());
-                sibling = s.prev_sibling();
-                continue;
-            }
+        match s.kind() {
+            // Other attributes usually sit between the doc comment and the item
+            "attribute_item" => parts.extend(doc_attribute(s, source)),
+            "line_comment" | "block_comment" => match doc_comment_text(s, source, "///", "/**") {
+                Some(doc) => parts.push(doc),
+                None => break,
+            },
+            _ => break,
         }
-        break;
+        sibling = s.prev_sibling();
+    }
+    parts.reverse();
+
+    if node.kind() == "mod_item"
+        && let Some(body) = node.child_by_field_name("body")
+    {
+        parts.extend(inner_doc(body, source));
     }
-    if lines.is_empty() {
+
+    if parts.is_empty() {
+        return None;
+    }
+    Some(parts.join("\n"))
+}
+
+/// The inner `//!`, `/*! */` and `#![doc = "..."]` docs at the top of a file or
+/// an inline module body.
+fn inner_doc(container: Node, source: &[u8]) -> Option<String> {
+    let mut parts = Vec::new();
+    let mut cursor = container.walk();
+    for child in container.named_children(&mut cursor) {
+        let doc = match child.kind() {
+            "inner_attribute_item" => doc_attribute(child, source),
+            "line_comment" | "block_comment" => doc_comment_text(child, source, "//!", "/*!"),
+            _ => break,
+        };
+        parts.extend(doc);
+    }
+    (!parts.is_empty()).then(|| parts.join("\n"))
+}
+
+/// The text of a `///`-style line or `/**`-style block doc comment, without the
+/// comment markers and the leading `*` of block comment lines.
+fn doc_comment_text(node: Node, source: &[u8], line: &str, block: &str) -> Option<String> {
+    let t = text(node, source);
+    if let Some(stripped) = t.strip_prefix(line) {
+        // `////` is an ordinary comment
+        return (!stripped.starts_with('/')).then(|| stripped.trim().to_string());
+    }
+    let body = t.strip_prefix(block)?.strip_suffix("*/")?;
+    if body.starts_with('*') {
         return None;
     }
-    lines.reverse();
-    Some(lines.join("\n"))
+    let lines: Vec<&str> = body
+        .lines()
+        .map(|l| {
+            let l = l.trim();
+            l.strip_prefix('*').map_or(l, str::trim_start)
+        })
+        .collect();
+    let start = lines.iter().position(|l| !l.is_empty())?;
+    let end = lines.iter().rposition(|l| !l.is_empty())?;
+    Some(lines[start..=end].join("\n"))
+}
+
+/// The string of a `#[doc = "..."]` or `#![doc = "..."]` attribute.
+fn doc_attribute(node: Node, source: &[u8]) -> Option<String> {
+    let attribute = node.named_child(0)?;
+    if attribute.named_child(0).map(|p| text(p, source)).as_deref() != Some("doc") {
+        return None;
+    }
+    let value = attribute.child_by_field_name("value")?;
+    let mut cursor = value.walk();
+    let content = value
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "string_content")
+        .map(|c| text(c, source))
+        .unwrap_or_default();
+    Some(
+        content
+            .replace("\\\"", "\"")
+            .replace("\\n", "\n")
+            .trim()
+            .to_string(),
+    )
+}
+
+/// Lint levels and docs say nothing about what an item is.
+const IGNORED_ATTRIBUTES: &[&str] = &["doc", "allow", "warn", "deny", "forbid", "expect"];
+
+/// Visibility and outer attributes as metadata: `pub(crate)` → `vis=pub(crate)`,
+/// `#[derive(Debug, serde::Serialize)]` → `derive=Debug`, `derive=Serialize`,
+/// `#[cfg(test)]` → `cfg=test`, and any other attribute by its path without
+/// arguments (`attr=test`, `attr=tokio::main`). Private items have no `vis`.
+fn symbol_metadata(node: Node, source: &[u8]) -> Vec<(String, String)> {
+    let mut metadata = Vec::new();
+    let mut cursor = node.walk();
+    if let Some(vis) = node
+        .children(&mut cursor)
+        .find(|c| c.kind() == "visibility_modifier")
+    {
+        metadata.push(("vis".to_string(), text(vis, source)));
+    }
+
+    let mut attributes = Vec::new();
+    let mut sibling = node.prev_sibling();
+    while let Some(s) = sibling {
+        match s.kind() {
+            "attribute_item" => attributes.extend(s.named_child(0)),
+            "line_comment" | "block_comment" => {}
+            _ => break,
+        }
+        sibling = s.prev_sibling();
+    }
+    attributes.reverse();
+
+    for attribute in attributes {
+        let Some(path) = attribute.named_child(0).map(|p| text(p, source)) else {
+            continue;
+        };
+        let arguments = attribute
+            .child_by_field_name("arguments")
+            .map(|a| text(a, source))
+            .unwrap_or_default();
+        let arguments = arguments
+            .strip_prefix('(')
+            .and_then(|a| a.strip_suffix(')'))
+            .unwrap_or(&arguments);
+        match path.as_str() {
+            "derive" => {
+                for derived in arguments.split(',') {
+                    let name = derived.rsplit("::").next().unwrap_or(derived).trim();
+                    if !name.is_empty() {
+                        metadata.push(("derive".to_string(), name.to_string()));
+                    }
+                }
+            }
+            "cfg" => metadata.push(("cfg".to_string(), arguments.trim().to_string())),
+            // Exported macros are public API wherever they're defined
+            "macro_export" => {
+                metadata.push(("vis".to_string(), "pub".to_string()));
+                metadata.push(("attr".to_string(), path));
+            }
+            _ if IGNORED_ATTRIBUTES.contains(&path.as_str()) => {}
+            _ => metadata.push(("attr".to_string(), path)),
+        }
+    }
+    metadata
 }
 
 fn build_fn_signature(node: Node, source: &[u8]) -> String {
@@ -119,12 +379,26 @@ fn build_fn_signature(node: Node, source: &[u8]) -> String {
     sig
 }
 
-fn extract_function(node: Node, source: &[u8], is_method: bool) -> Option<ExtractedSymbol> {
+/// `outer_generics` are the type parameters of the enclosing impl or trait.
+fn extract_function(
+    node: Node,
+    source: &[u8],
+    is_method: bool,
+    outer_generics: &[String],
+) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
     let sig = build_fn_signature(node, source);
     let doc = get_doc_comment(node, source);
 
+    let mut generics = outer_generics.to_vec();
+    generics.extend(type_parameter_names(node, source));
     let mut refs = Vec::new();
+    for field in ["type_parameters", "parameters", "return_type"] {
+        if let Some(n) = node.child_by_field_name(field) {
+            collect_type_refs(n, source, &generics, &mut refs);
+        }
+    }
+    collect_where_clause_refs(node, source, &generics, &mut refs);
     if let Some(body) = node.child_by_field_name("body") {
         extract_calls(body, source, &mut refs);
     }
@@ -142,33 +416,23 @@ fn extract_function(node: Node, source: &[u8], is_method: bool) -> Option<Extrac
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: refs,
+        metadata: symbol_metadata(node, source),
     })
 }
 
 fn extract_struct(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
     let doc = get_doc_comment(node, source);
+    let generics = type_parameter_names(node, source);
 
     let mut children = Vec::new();
+    let mut refs = Vec::new();
+    if let Some(n) = node.child_by_field_name("type_parameters") {
+        collect_type_refs(n, source, &generics, &mut refs);
+    }
+    collect_where_clause_refs(node, source, &generics, &mut refs);
     if let Some(body) = node.child_by_field_name("body") {
-        let mut cursor = body.walk();
-        for child in body.children(&mut cursor) {
-            if child.kind() == "field_declaration" {
-                if let Some(field_name) = child.child_by_field_name("name") {
-                    let type_sig = child.child_by_field_name("type").map(|n| text(n, source));
-                    children.push(ExtractedSymbol {
-                        name: text(field_name, source),
-                        kind: SymbolKind::Var,
-                        signature: type_sig,
-                        doc_comment: None,
-                        start_line: child.start_position().row + 1,
-                        end_line: child.end_position().row + 1,
-                        children: Vec::new(),
-                        references: Vec::new(),
-                    });
-                }
-            }
-        }
+        extract_fields(body, source, &generics, &mut children, &mut refs);
     }
 
     Some(ExtractedSymbol {
@@ -179,20 +443,64 @@ fn extract_struct(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         start_line: node.start_position().row + 1,
         end_line: node.end_position().row + 1,
         children,
-        references: Vec::new(),
+        references: refs,
+        metadata: symbol_metadata(node, source),
     })
 }
 
+/// Named fields become `Var` children with their own `TypeRef`s; the types of
+/// tuple fields, which have no symbol, go to `refs`.
+fn extract_fields(
+    body: Node,
+    source: &[u8],
+    generics: &[String],
+    children: &mut Vec<ExtractedSymbol>,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    if body.kind() == "ordered_field_declaration_list" {
+        collect_type_refs(body, source, generics, refs);
+        return;
+    }
+    let mut cursor = body.walk();
+    for child in body.children(&mut cursor) {
+        if child.kind() == "field_declaration" {
+            if let Some(field_name) = child.child_by_field_name("name") {
+                let type_node = child.child_by_field_name("type");
+                let mut field_refs = Vec::new();
+                if let Some(t) = type_node {
+                    collect_type_refs(t, source, generics, &mut field_refs);
+                }
+                children.push(ExtractedSymbol {
+                    name: text(field_name, source),
+                    kind: SymbolKind::Var,
+                    signature: type_node.map(|n| text(n, source)),
+                    doc_comment: None,
+                    start_line: child.start_position().row + 1,
+                    end_line: child.end_position().row + 1,
+                    children: Vec::new(),
+                    references: field_refs,
+                    metadata: symbol_metadata(child, source),
+                });
+            }
+        }
+    }
+}
+
 fn extract_enum(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
     let doc = get_doc_comment(node, source);
 
+    let generics = type_parameter_names(node, source);
     let mut children = Vec::new();
     if let Some(body) = node.child_by_field_name("body") {
         let mut cursor = body.walk();
         for child in body.children(&mut cursor) {
             if child.kind() == "enum_variant" {
                 if let Some(variant_name) = child.child_by_field_name("name") {
+                    let mut refs = Vec::new();
+                    if let Some(fields) = child.child_by_field_name("body") {
+                        collect_type_refs(fields, source, &generics, &mut refs);
+                    }
                     children.push(ExtractedSymbol {
                         name: text(variant_name, source),
                         kind: SymbolKind::Const,
@@ -201,7 +509,8 @@ fn extract_enum(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
                         start_line: child.start_position().row + 1,
                         end_line: child.end_position().row + 1,
                         children: Vec::new(),
-                        references: Vec::new(),
+                        references: refs,
+                        metadata: symbol_metadata(child, source),
                     });
                 }
             }
@@ -217,19 +526,40 @@ fn extract_enum(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children,
         references: Vec::new(),
+        metadata: symbol_metadata(node, source),
     })
 }
 
+/// Supertraits (`trait Shape: HasArea + Debug`) are `Extends` refs.
 fn extract_trait(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
     let doc = get_doc_comment(node, source);
+    let generics = type_parameter_names(node, source);
+
+    let mut refs = Vec::new();
+    if let Some(bounds) = node.child_by_field_name("bounds") {
+        let mut cursor = bounds.walk();
+        for bound in bounds.named_children(&mut cursor) {
+            if let Some(supertrait) = base_type_name(bound, source) {
+                refs.push(ExtractedRef {
+                    name: supertrait,
+                    kind: RefKind::Extends,
+                    line: bound.start_position().row + 1,
+                });
+            }
+        }
+    }
+    if let Some(n) = node.child_by_field_name("type_parameters") {
+        collect_type_refs(n, source, &generics, &mut refs);
+    }
+    collect_where_clause_refs(node, source, &generics, &mut refs);
 
     let mut children = Vec::new();
     if let Some(body) = node.child_by_field_name("body") {
         let mut cursor = body.walk();
         for child in body.children(&mut cursor) {
             if child.kind() == "function_item" || child.kind() == "function_signature_item" {
-                if let Some(sym) = extract_function(child, source, true) {
+                if let Some(sym) = extract_function(child, source, true, &generics) {
                     children.push(sym);
                 }
             }
@@ -244,13 +574,18 @@ fn extract_trait(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         start_line: node.start_position().row + 1,
         end_line: node.end_position().row + 1,
         children,
-        references: Vec::new(),
+        references: refs,
+        metadata: symbol_metadata(node, source),
     })
 }
 
+/// `impl Trait for Type` → an `Implements` ref to the trait and a `TypeRef` to
+/// the type, so both "who implements this trait" and "what is implemented for
+/// this type" are answerable.
 fn extract_impl(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let type_node = node.child_by_field_name("type")?;
     let type_name = text(type_node, source);
+    let generics = type_parameter_names(node, source);
 
     let trait_node = node.child_by_field_name("trait");
     let name = match trait_node {
@@ -258,13 +593,29 @@ fn extract_impl(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         None => type_name,
     };
 
+    let mut refs = Vec::new();
+    if let Some(t) = trait_node
+        && let Some(trait_name) = base_type_name(t, source)
+    {
+        refs.push(ExtractedRef {
+            name: trait_name,
+            kind: RefKind::Implements,
+            line: t.start_position().row + 1,
+        });
+    }
+    collect_type_refs(type_node, source, &generics, &mut refs);
+    if let Some(n) = node.child_by_field_name("type_parameters") {
+        collect_type_refs(n, source, &generics, &mut refs);
+    }
+    collect_where_clause_refs(node, source, &generics, &mut refs);
+
     let mut children = Vec::new();
     if let Some(body) = node.child_by_field_name("body") {
         let mut cursor = body.walk();
         for child in body.children(&mut cursor) {
             match child.kind() {
                 "function_item" => {
-                    if let Some(sym) = extract_function(child, source, true) {
+                    if let Some(sym) = extract_function(child, source, true, &generics) {
                         children.push(sym);
                     }
                 }
@@ -274,7 +625,7 @@ fn extract_impl(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
                     }
                 }
                 "type_item" => {
-                    if let Some(sym) = extract_type_alias(child, source) {
+                    if let Some(sym) = extract_type_alias(child, source, &generics) {
                         children.push(sym);
                     }
                 }
@@ -291,7 +642,8 @@ fn extract_impl(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         start_line: node.start_position().row + 1,
         end_line: node.end_position().row + 1,
         children,
-        references: Vec::new(),
+        references: refs,
+        metadata: symbol_metadata(node, source),
     })
 }
 
@@ -300,8 +652,9 @@ fn extract_mod(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let doc = get_doc_comment(node, source);
 
     let mut children = Vec::new();
+    let mut imports = Vec::new();
     if let Some(body) = node.child_by_field_name("body") {
-        extract_top_level(body, source, &mut children);
+        extract_top_level(body, source, &mut children, &mut imports);
     }
 
     Some(ExtractedSymbol {
@@ -312,24 +665,38 @@ fn extract_mod(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         start_line: node.start_position().row + 1,
         end_line: node.end_position().row + 1,
         children,
-        references: Vec::new(),
+        references: imports,
+        metadata: symbol_metadata(node, source),
     })
 }
 
-fn extract_type_alias(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+/// `outer_generics` are the type parameters of the enclosing impl, for
+/// associated types.
+fn extract_type_alias(
+    node: Node,
+    source: &[u8],
+    outer_generics: &[String],
+) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
     let doc = get_doc_comment(node, source);
-    let value = node.child_by_field_name("type").map(|n| text(n, source));
+    let value = node.child_by_field_name("type");
+    let mut generics = outer_generics.to_vec();
+    generics.extend(type_parameter_names(node, source));
+    let mut refs = Vec::new();
+    if let Some(v) = value {
+        collect_type_refs(v, source, &generics, &mut refs);
+    }
 
     Some(ExtractedSymbol {
         name,
         kind: SymbolKind::Type,
-        signature: value,
+        signature: value.map(|n| text(n, source)),
         doc_comment: doc,
         start_line: node.start_position().row + 1,
         end_line: node.end_position().row + 1,
         children: Vec::new(),
-        references: Vec::new(),
+        references: refs,
+        metadata: symbol_metadata(node, source),
     })
 }
 
@@ -347,9 +714,181 @@ fn extract_const(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: Vec::new(),
+        metadata: symbol_metadata(node, source),
+    })
+}
+
+/// `macro_rules! square { ($x:expr) => ...; ($x:expr, $y:expr) => ... }` →
+/// `square` with signature `($x:expr) | ($x:expr, $y:expr)`.
+fn extract_macro(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let mut cursor = node.walk();
+    let patterns: Vec<String> = node
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() == "macro_rule")
+        .filter_map(|rule| rule.child_by_field_name("left"))
+        .map(|left| text(left, source))
+        .collect();
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Macro,
+        signature: (!patterns.is_empty()).then(|| patterns.join(" | ")),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: symbol_metadata(node, source),
     })
 }
 
+/// One `Import` ref per item a `use` tree brings in, named by the item itself:
+/// `use a::{b::C as D, e::{self}}` imports `C` and `e`. Glob imports keep their
+/// path (`crate::storage::*`) since they don't name an item.
+fn collect_use_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    if let Some(argument) = node.child_by_field_name("argument") {
+        collect_use_tree(argument, "", source, refs);
+    }
+}
+
+/// Imports every path a `use` tree names, in full (`use a::{b::C, self}` →
+/// `a::b::C`, `a`), so resolution can check where the target lives.
+fn collect_use_tree(node: Node, prefix: &str, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "identifier" | "scoped_identifier" => {
+            push_import(join_path(prefix, &text(node, source)), node, refs)
+        }
+        "self" if !prefix.is_empty() => push_import(prefix.to_string(), node, refs),
+        "use_as_clause" => {
+            if let Some(path) = node.child_by_field_name("path") {
+                collect_use_tree(path, prefix, source, refs);
+            }
+        }
+        "use_wildcard" => push_import(join_path(prefix, &text(node, source)), node, refs),
+        "use_list" => {
+            let mut cursor = node.walk();
+            for item in node.named_children(&mut cursor) {
+                collect_use_tree(item, prefix, source, refs);
+            }
+        }
+        "scoped_use_list" => {
+            let path = node
+                .child_by_field_name("path")
+                .map(|p| join_path(prefix, &text(p, source)))
+                .unwrap_or_else(|| prefix.to_string());
+            if let Some(list) = node.child_by_field_name("list") {
+                collect_use_tree(list, &path, source, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn join_path(prefix: &str, path: &str) -> String {
+    if prefix.is_empty() {
+        path.to_string()
+    } else {
+        format!("{prefix}::{path}")
+    }
+}
+
+fn push_import(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::Import,
+        line: node.start_position().row + 1,
+    });
+}
+
+/// Names of an item's type parameters (`<T: Clone, const N: usize>` → `T`, `N`),
+/// which refer to no type of their own.
+fn type_parameter_names(node: Node, source: &[u8]) -> Vec<String> {
+    let Some(params) = node.child_by_field_name("type_parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter(|p| matches!(p.kind(), "type_parameter" | "const_parameter"))
+        .filter_map(|p| p.child_by_field_name("name"))
+        .map(|n| text(n, source))
+        .collect()
+}
+
+/// The named type behind paths and generic arguments: `crate::a::Point` →
+/// `Point`, `Into<Point>` → `Into`.
+fn base_type_name(node: Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "type_identifier" => Some(text(node, source)),
+        "scoped_type_identifier" => node.child_by_field_name("name").map(|n| text(n, source)),
+        "generic_type" => base_type_name(node.child_by_field_name("type")?, source),
+        _ => None,
+    }
+}
+
+fn collect_where_clause_refs(
+    node: Node,
+    source: &[u8],
+    generics: &[String],
+    refs: &mut Vec<ExtractedRef>,
+) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if child.kind() == "where_clause" {
+            collect_type_refs(child, source, generics, refs);
+        }
+    }
+}
+
+/// `TypeRef`s for every named type under `node`: parameter and return types,
+/// generic arguments and trait bounds.
+fn collect_type_refs(node: Node, source: &[u8], generics: &[String], refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "type_identifier" | "scoped_type_identifier" => {
+            // `T::Item` names an associated type of a type parameter
+            let on_generic = node
+                .child_by_field_name("path")
+                .is_some_and(|p| generics.contains(&text(p, source)));
+            if let Some(name) = base_type_name(node, source)
+                && !on_generic
+                && !BUILTIN_TYPES.contains(&name.as_str())
+                && !generics.contains(&name)
+            {
+                push_type_ref(name, node, refs);
+            }
+        }
+        // `Iterator<Item = Point>`: `Item` is the trait's associated type
+        "type_binding" => {
+            if let Some(t) = node.child_by_field_name("type") {
+                collect_type_refs(t, source, generics, refs);
+            }
+        }
+        // Patterns, lifetimes and default values aren't types
+        "identifier" | "lifetime" | "block" => {}
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, source, generics, refs);
+            }
+        }
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
+
 fn extract_calls(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
     let mut cursor = node.walk();
     extract_calls_recursive(&mut cursor, source, refs);
@@ -358,6 +897,21 @@ fn extract_calls(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
 fn extract_calls_recursive(cursor: &mut TreeCursor, source: &[u8], refs: &mut Vec<ExtractedRef>) {
     loop {
         let node = cursor.node();
+        if node.kind() == "use_declaration" {
+            collect_use_refs(node, source, refs);
+        }
+        if node.kind() == "macro_invocation"
+            && let Some(mac) = node.child_by_field_name("macro")
+        {
+            let name = text(mac, source);
+            if !STD_MACROS.contains(&name.as_str()) {
+                refs.push(ExtractedRef {
+                    name,
+                    kind: RefKind::Call,
+                    line: node.start_position().row + 1,
+                });
+            }
+        }
         if node.kind() == "call_expression" {
             if let Some(func) = node.child_by_field_name("function") {
                 let name = match func.kind() {
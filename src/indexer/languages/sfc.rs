@@ -0,0 +1,208 @@
+use std::path::Path;
+
+use tree_sitter::{Node, Parser, Range, Tree};
+
+use super::typescript::TypeScriptExtractor;
+use super::{FileContext, LanguageExtractor};
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+/// Vue and Svelte single-file components. The `<script>` blocks are handed to
+/// `TypeScriptExtractor`, and the file itself becomes a component symbol whose
+/// references are the child components used in its template.
+pub struct SfcExtractor;
+
+impl LanguageExtractor for SfcExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_vue_next::LANGUAGE.into()
+    }
+
+    fn language_for_ext(&self, ext: &str) -> tree_sitter::Language {
+        match ext {
+            "svelte" => tree_sitter_svelte_ng::LANGUAGE.into(),
+            _ => tree_sitter_vue_next::LANGUAGE.into(),
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["vue", "svelte"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        extract_component("Component", source, tree)
+    }
+
+    fn extract_file(&self, file: &FileContext, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        extract_component(&component_name(file.rel_path), source, tree)
+    }
+}
+
+fn extract_component(name: &str, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+    let root = tree.root_node();
+    let mut scripts = Vec::new();
+    let mut refs = Vec::new();
+
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        match child.kind() {
+            "script_element" => scripts.push(child),
+            "style_element" => {}
+            _ => collect_component_refs(child, source, &mut refs),
+        }
+    }
+
+    let mut symbols = Vec::new();
+    let mut children = Vec::new();
+    for sym in extract_scripts(&scripts, source) {
+        // Imports stay at file level, like any other TS module
+        if sym.name == "_imports" {
+            symbols.push(sym);
+        } else {
+            children.push(sym);
+        }
+    }
+
+    symbols.insert(
+        0,
+        ExtractedSymbol {
+            name: name.to_string(),
+            kind: SymbolKind::Class,
+            signature: Some("component".to_string()),
+            doc_comment: None,
+            start_line: 1,
+            end_line: root.end_position().row + 1,
+            children,
+            references: refs,
+            metadata: Vec::new(),
+        },
+    );
+    symbols
+}
+
+/// Framework-provided components that have no in-repo definition.
+const BUILTIN_COMPONENTS: &[&str] = &[
+    "Component",
+    "KeepAlive",
+    "Slot",
+    "Suspense",
+    "Teleport",
+    "Template",
+    "Transition",
+    "TransitionGroup",
+];
+
+/// `src/components/user-card.vue` → `UserCard`
+fn component_name(rel_path: &str) -> String {
+    let stem = Path::new(rel_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(rel_path);
+    pascal_case(stem)
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(['-', '_'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Parses all `<script>` blocks as one TS program. Using included ranges over the
+/// original source keeps every position — and so every line number — file-relative.
+fn extract_scripts(scripts: &[Node], source: &[u8]) -> Vec<ExtractedSymbol> {
+    let mut ranges: Vec<Range> = Vec::new();
+    let mut ext = "ts";
+    for script in scripts {
+        let mut cursor = script.walk();
+        let Some(raw) = script
+            .named_children(&mut cursor)
+            .find(|c| c.kind() == "raw_text")
+        else {
+            continue;
+        };
+        ranges.push(raw.range());
+        if matches!(script_lang(*script, source).as_deref(), Some("tsx" | "jsx")) {
+            ext = "tsx";
+        }
+    }
+    if ranges.is_empty() {
+        return Vec::new();
+    }
+
+    let extractor = TypeScriptExtractor;
+    let mut parser = Parser::new();
+    if parser
+        .set_language(&extractor.language_for_ext(ext))
+        .is_err()
+        || parser.set_included_ranges(&ranges).is_err()
+    {
+        return Vec::new();
+    }
+    match parser.parse(source, None) {
+        Some(tree) => extractor.extract(source, &tree),
+        None => Vec::new(),
+    }
+}
+
+/// The `lang` attribute of a `<script>` tag, if any.
+fn script_lang(script: Node, source: &[u8]) -> Option<String> {
+    let mut cursor = script.walk();
+    let start_tag = script
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "start_tag")?;
+    let mut tag_cursor = start_tag.walk();
+    let lang = start_tag.named_children(&mut tag_cursor).find(|attr| {
+        attr.kind() == "attribute"
+            && attr
+                .named_child(0)
+                .is_some_and(|n| n.utf8_text(source).unwrap_or("") == "lang")
+    })?;
+    let value = lang.named_child(1)?;
+    Some(
+        value
+            .utf8_text(source)
+            .unwrap_or("")
+            .trim_matches(['"', '\''])
+            .to_string(),
+    )
+}
+
+/// Template elements are components when they are PascalCase (`<UserAvatar>`) or
+/// kebab-case (`<base-button>`); plain HTML elements are neither.
+fn collect_component_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    if node.kind() == "tag_name"
+        && matches!(
+            node.parent().map(|p| p.kind()),
+            Some("start_tag" | "self_closing_tag")
+        )
+    {
+        let tag = node.utf8_text(source).unwrap_or("");
+        let is_component = tag.contains('-') || tag.starts_with(|c: char| c.is_ascii_uppercase());
+        // `<svelte:head>` and friends are compiler directives
+        if is_component && !tag.contains(':') {
+            let name = if tag.contains('-') {
+                pascal_case(tag)
+            } else {
+                tag.to_string()
+            };
+            if !BUILTIN_COMPONENTS.contains(&name.as_str()) {
+                refs.push(ExtractedRef {
+                    name,
+                    kind: RefKind::Call,
+                    line: node.start_position().row + 1,
+                });
+            }
+        }
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_component_refs(child, source, refs);
+    }
+}
@@ -0,0 +1,301 @@
+use std::path::Path;
+
+use tree_sitter::{Node, Tree};
+
+use super::{FileContext, LanguageExtractor};
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+/// Bash, sh and zsh scripts. Besides its functions and exported variables, each
+/// script gets a symbol named after its file (`deploy.sh`) holding the refs of its
+/// top-level commands, so `source` imports and invocations of other in-repo
+/// scripts resolve to the script they name.
+pub struct ShellExtractor;
+
+impl LanguageExtractor for ShellExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_bash::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["sh", "bash", "zsh"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        extract_script("_script", source, tree)
+    }
+
+    fn extract_file(&self, file: &FileContext, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        extract_script(&script_name(file.rel_path), source, tree)
+    }
+}
+
+/// Builtins and ubiquitous utilities — never worth a `Call` ref.
+const BUILTIN_COMMANDS: &[&str] = &[
+    "[", "[[", "alias", "awk", "basename", "break", "cat", "cd", "chmod", "command", "continue",
+    "cp", "curl", "cut", "date", "declare", "dirname", "echo", "env", "eval", "exec", "exit",
+    "export", "false", "find", "getopts", "grep", "head", "local", "ls", "mkdir", "mktemp", "mv",
+    "print", "printf", "pwd", "read", "readonly", "return", "rm", "sed", "set", "shift", "sleep",
+    "sort", "tail", "tee", "test", "touch", "tr", "trap", "true", "type", "uniq", "unset", "wait",
+    "wc", "xargs",
+];
+
+/// Commands that run the script given as their first argument.
+const INTERPRETERS: &[&str] = &["bash", "sh", "zsh"];
+
+const SCRIPT_EXTENSIONS: &[&str] = &[".sh", ".bash", ".zsh"];
+
+fn script_name(rel_path: &str) -> String {
+    Path::new(rel_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(rel_path)
+        .to_string()
+}
+
+fn extract_script(name: &str, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+    let root = tree.root_node();
+    let mut symbols = Vec::new();
+    let mut imports = Vec::new();
+    let mut calls = Vec::new();
+
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        match child.kind() {
+            "function_definition" => symbols.extend(extract_function(child, source)),
+            "declaration_command" => extract_exports(child, source, &mut symbols),
+            "comment" => {}
+            _ => collect_commands(child, source, &mut imports, &mut calls),
+        }
+    }
+
+    if !imports.is_empty() {
+        symbols.insert(
+            0,
+            ExtractedSymbol {
+                name: "_imports".to_string(),
+                kind: SymbolKind::Mod,
+                signature: None,
+                doc_comment: None,
+                start_line: imports[0].line,
+                end_line: imports[imports.len() - 1].line,
+                children: Vec::new(),
+                references: imports,
+                metadata: Vec::new(),
+            },
+        );
+    }
+    symbols.insert(
+        0,
+        ExtractedSymbol {
+            name: name.to_string(),
+            kind: SymbolKind::Fn,
+            signature: Some("script".to_string()),
+            doc_comment: header_comment(root, source),
+            start_line: 1,
+            end_line: root.end_position().row + 1,
+            children: Vec::new(),
+            references: calls,
+            metadata: Vec::new(),
+        },
+    );
+    symbols
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+/// Collects the contiguous `#` comment block directly above a declaration.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut sibling = node.prev_named_sibling();
+    while let Some(s) = sibling {
+        let t = text(s, source);
+        if s.kind() != "comment" || t.starts_with("#!") || s.end_position().row + 1 != expected_row
+        {
+            break;
+        }
+        lines.push(t.trim_start_matches('#').trim().to_string());
+        expected_row = s.start_position().row;
+        sibling = s.prev_named_sibling();
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
+/// The comment block opening the file, after the shebang.
+fn header_comment(root: Node, source: &[u8]) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        let t = text(child, source);
+        if child.kind() != "comment" {
+            break;
+        }
+        if !t.starts_with("#!") {
+            lines.push(t.trim_start_matches('#').trim().to_string());
+        }
+    }
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// `build() { ... }` and `function build { ... }`.
+fn extract_function(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = node.child_by_field_name("name").map(|n| text(n, source))?;
+    let mut imports = Vec::new();
+    let mut calls = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        collect_commands(body, source, &mut imports, &mut calls);
+    }
+    // A `source` inside a function still pulls the file in for the caller
+    calls.extend(imports);
+
+    Some(ExtractedSymbol {
+        name,
+        kind: SymbolKind::Fn,
+        signature: None,
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: calls,
+        metadata: Vec::new(),
+    })
+}
+
+/// `export NAME=value` and `export NAME` at the top level. `export -n` (un-export)
+/// and `export -f` (functions) are skipped; `readonly`/`local` aren't exports.
+fn extract_exports(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbol>) {
+    let keyword = node.child(0).map(|k| k.kind()).unwrap_or("");
+    let mut cursor = node.walk();
+    let args: Vec<Node> = node.named_children(&mut cursor).collect();
+    let flags: Vec<String> = args
+        .iter()
+        .filter(|a| a.kind() == "word")
+        .map(|a| text(*a, source))
+        .collect();
+    let exported = match keyword {
+        "export" => !flags.iter().any(|f| f == "-n" || f == "-f"),
+        "declare" | "typeset" => flags.iter().any(|f| f.starts_with('-') && f.contains('x')),
+        _ => false,
+    };
+    if !exported {
+        return;
+    }
+
+    for arg in args {
+        let (name, value) = match arg.kind() {
+            "variable_assignment" => (
+                arg.child_by_field_name("name").map(|n| text(n, source)),
+                arg.child_by_field_name("value")
+                    .map(|v| text(v, source).trim_matches(['"', '\'']).to_string()),
+            ),
+            "variable_name" => (Some(text(arg, source)), None),
+            _ => continue,
+        };
+        let Some(name) = name else {
+            continue;
+        };
+        if symbols
+            .iter()
+            .any(|s| s.kind == SymbolKind::Var && s.name == name)
+        {
+            continue;
+        }
+        symbols.push(ExtractedSymbol {
+            name,
+            kind: SymbolKind::Var,
+            signature: value.filter(|v| !v.contains('\n')),
+            doc_comment: get_doc_comment(node, source),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            children: Vec::new(),
+            references: Vec::new(),
+            metadata: Vec::new(),
+        });
+    }
+}
+
+/// Walks every command under `node`: `source`/`.` become imports, calls to
+/// other scripts (`./scripts/migrate.sh`, `bash notify.sh`) become calls to the
+/// script's file name, and anything else that isn't a builtin is a function call.
+fn collect_commands(
+    node: Node,
+    source: &[u8],
+    imports: &mut Vec<ExtractedRef>,
+    calls: &mut Vec<ExtractedRef>,
+) {
+    if node.kind() == "command" {
+        collect_command(node, source, imports, calls);
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_commands(child, source, imports, calls);
+    }
+}
+
+fn collect_command(
+    node: Node,
+    source: &[u8],
+    imports: &mut Vec<ExtractedRef>,
+    calls: &mut Vec<ExtractedRef>,
+) {
+    let Some(name) = node.child_by_field_name("name").map(|n| text(n, source)) else {
+        return;
+    };
+    let line = node.start_position().row + 1;
+    let mut cursor = node.walk();
+    let first_arg = node
+        .children_by_field_name("argument", &mut cursor)
+        .map(|a| text(a, source))
+        .find(|a| !a.starts_with('-'));
+
+    let (target, kind) = match name.as_str() {
+        "source" | "." => (
+            first_arg.and_then(|a| script_path_name(&a, false)),
+            RefKind::Import,
+        ),
+        n if INTERPRETERS.contains(&n) => (
+            first_arg.and_then(|a| script_path_name(&a, false)),
+            RefKind::Call,
+        ),
+        n if BUILTIN_COMMANDS.contains(&n) => return,
+        _ => match script_path_name(&name, true) {
+            Some(script) => (Some(script), RefKind::Call),
+            None if is_function_name(&name) => (Some(name), RefKind::Call),
+            None => return,
+        },
+    };
+    let Some(target) = target else {
+        return;
+    };
+    let refs = match kind {
+        RefKind::Import => imports,
+        _ => calls,
+    };
+    refs.push(ExtractedRef {
+        name: target,
+        kind,
+        line,
+    });
+}
+
+/// `"$(dirname "$0")/common.sh"` → `common.sh`. A bare command only counts as a
+/// script when it is a path or has a script extension; arguments to `source` and
+/// interpreters always do.
+fn script_path_name(raw: &str, command: bool) -> Option<String> {
+    let path = raw.trim_matches(['"', '\'']);
+    let file = path.rsplit('/').next().unwrap_or(path);
+    let is_script =
+        !command || path.contains('/') || SCRIPT_EXTENSIONS.iter().any(|ext| file.ends_with(ext));
+    (is_script && !file.is_empty() && !file.starts_with('$')).then(|| file.to_string())
+}
+
+fn is_function_name(name: &str) -> bool {
+    name.chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ':'))
+}
@@ -0,0 +1,345 @@
+use tree_sitter::{Node, Tree};
+
+use super::LanguageExtractor;
+use crate::indexer::{ExtractedRef, ExtractedSymbol, RefKind, SymbolKind};
+
+/// SQL schema files and migrations. `CREATE TABLE` becomes a struct with its
+/// columns as fields, views, indexes, functions and triggers become symbols of
+/// their own, and foreign keys and the tables a view selects from are `TypeRef`s.
+/// `ALTER TABLE ... ADD COLUMN` extends a table created earlier in the same file.
+pub struct SqlExtractor;
+
+impl LanguageExtractor for SqlExtractor {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_sequel::LANGUAGE.into()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["sql"]
+    }
+
+    fn extract(&self, source: &[u8], tree: &Tree) -> Vec<ExtractedSymbol> {
+        let root = tree.root_node();
+        let mut symbols = Vec::new();
+
+        let mut cursor = root.walk();
+        for statement in root.named_children(&mut cursor) {
+            let Some(node) = statement.named_child(0) else {
+                continue;
+            };
+            let symbol = match node.kind() {
+                "create_table" => extract_table(node, statement, source),
+                "create_view" | "create_materialized_view" => extract_view(node, statement, source),
+                "create_index" => extract_index(node, statement, source),
+                "create_function" => extract_function(node, statement, source),
+                "create_trigger" => extract_trigger(node, statement, source),
+                "alter_table" => {
+                    alter_table(node, source, &mut symbols);
+                    None
+                }
+                _ => None,
+            };
+            symbols.extend(symbol);
+        }
+        symbols
+    }
+}
+
+fn text(node: Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
+
+fn find_child<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).find(|c| c.kind() == kind)
+}
+
+/// The object reference following a keyword (`ON posts`, `FUNCTION touch()`).
+fn reference_after<'a>(node: Node<'a>, keyword: &str) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    let mut found = false;
+    for child in node.named_children(&mut cursor) {
+        if child.kind() == keyword {
+            found = true;
+        } else if found && child.kind() == "object_reference" {
+            return Some(child);
+        }
+    }
+    None
+}
+
+/// `public.posts` → `posts`.
+fn object_name(node: Node, source: &[u8]) -> Option<String> {
+    node.child_by_field_name("name").map(|n| text(n, source))
+}
+
+/// Collapses a multi-line clause onto one line for use as a signature.
+fn one_line(raw: &str) -> String {
+    raw.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Collects the contiguous `--` or `/* */` comment block directly above a
+/// statement or column.
+fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
+    let mut blocks = Vec::new();
+    let mut expected_row = node.start_position().row;
+    let mut sibling = node.prev_named_sibling();
+    while let Some(s) = sibling {
+        if !matches!(s.kind(), "comment" | "marginalia") || s.end_position().row + 1 != expected_row
+        {
+            break;
+        }
+        blocks.push(comment_text(&text(s, source)));
+        expected_row = s.start_position().row;
+        sibling = s.prev_named_sibling();
+    }
+    if blocks.is_empty() {
+        return None;
+    }
+    blocks.reverse();
+    Some(blocks.join("\n"))
+}
+
+fn comment_text(raw: &str) -> String {
+    if let Some(line) = raw.strip_prefix("--") {
+        return line.trim().to_string();
+    }
+    raw.trim_start_matches("/*")
+        .trim_end_matches("*/")
+        .lines()
+        .map(|l| l.trim().trim_start_matches('*').trim())
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn symbol(
+    name: String,
+    kind: SymbolKind,
+    signature: Option<String>,
+    statement: Node,
+    source: &[u8],
+) -> ExtractedSymbol {
+    ExtractedSymbol {
+        name,
+        kind,
+        signature,
+        doc_comment: get_doc_comment(statement, source),
+        start_line: statement.start_position().row + 1,
+        end_line: statement.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    }
+}
+
+/// Tables become structs with one `Var` per column. Schema-qualified tables keep
+/// the full name as their signature.
+fn extract_table(node: Node, statement: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let table = find_child(node, "object_reference")?;
+    let name = object_name(table, source)?;
+    let qualified = text(table, source);
+    let signature = (qualified != name).then_some(qualified);
+    let mut sym = symbol(name, SymbolKind::Struct, signature, statement, source);
+
+    if let Some(columns) = find_child(node, "column_definitions") {
+        let mut cursor = columns.walk();
+        for column in columns.named_children(&mut cursor) {
+            if column.kind() == "column_definition" {
+                sym.children.extend(extract_column(column, source));
+            }
+        }
+    }
+    collect_foreign_keys(node, source, &mut sym.references);
+    Some(sym)
+}
+
+/// `email VARCHAR(255) NOT NULL UNIQUE` → `email` with the rest of the definition
+/// as its signature.
+fn extract_column(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name_node = node.child_by_field_name("name")?;
+    let definition = text(node, source);
+    let rest = &definition[name_node.end_byte() - node.start_byte()..];
+    Some(ExtractedSymbol {
+        name: text(name_node, source),
+        kind: SymbolKind::Var,
+        signature: Some(one_line(rest)).filter(|s| !s.is_empty()),
+        doc_comment: get_doc_comment(node, source),
+        start_line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        children: Vec::new(),
+        references: Vec::new(),
+        metadata: Vec::new(),
+    })
+}
+
+/// Every `REFERENCES <table>`, whether inline on a column or a table constraint.
+fn collect_foreign_keys(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "keyword_references" {
+            let target = child
+                .next_named_sibling()
+                .filter(|t| t.kind() == "object_reference");
+            if let Some(target) = target
+                && let Some(name) = object_name(target, source)
+            {
+                push_type_ref(name, target, refs);
+            }
+        } else {
+            collect_foreign_keys(child, source, refs);
+        }
+    }
+}
+
+/// `ALTER TABLE posts ADD COLUMN ...` on a table created earlier in the file
+/// adds the column and any foreign key it declares.
+fn alter_table(node: Node, source: &[u8], symbols: &mut [ExtractedSymbol]) {
+    let Some(name) = find_child(node, "object_reference").and_then(|t| object_name(t, source))
+    else {
+        return;
+    };
+    let Some(table) = symbols
+        .iter_mut()
+        .find(|s| s.kind == SymbolKind::Struct && s.name == name)
+    else {
+        return;
+    };
+
+    let mut cursor = node.walk();
+    for action in node.named_children(&mut cursor) {
+        if let Some(column) = find_child(action, "column_definition") {
+            table.children.extend(extract_column(column, source));
+        }
+    }
+    collect_foreign_keys(node, source, &mut table.references);
+}
+
+/// Views reference every table they select from, subqueries and joins included.
+fn extract_view(node: Node, statement: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = find_child(node, "object_reference").and_then(|v| object_name(v, source))?;
+    let signature = match node.kind() {
+        "create_materialized_view" => "materialized view",
+        _ => "view",
+    };
+    let mut sym = symbol(
+        name,
+        SymbolKind::Type,
+        Some(signature.to_string()),
+        statement,
+        source,
+    );
+    if let Some(query) = find_child(node, "create_query") {
+        collect_relations(query, source, &mut sym.references);
+    }
+    Some(sym)
+}
+
+fn collect_relations(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    if node.kind() == "relation"
+        && let Some(table) = find_child(node, "object_reference")
+        && let Some(name) = object_name(table, source)
+    {
+        push_type_ref(name, table, refs);
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_relations(child, source, refs);
+    }
+}
+
+/// `CREATE UNIQUE INDEX idx_posts_title ON posts (title)` → `idx_posts_title` with
+/// signature `UNIQUE ON posts (title)`. Unnamed indexes are skipped.
+fn extract_index(node: Node, statement: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = node
+        .child_by_field_name("column")
+        .map(|n| text(n, source))?;
+    let table = reference_after(node, "keyword_on")?;
+    let unique = find_child(node, "keyword_unique").map_or("", |_| "UNIQUE ");
+    let fields = find_child(node, "index_fields")
+        .map(|f| format!(" {}", text(f, source)))
+        .unwrap_or_default();
+    let signature = format!("{unique}ON {}{fields}", text(table, source));
+
+    let mut sym = symbol(name, SymbolKind::Const, Some(signature), statement, source);
+    if let Some(table_name) = object_name(table, source) {
+        push_type_ref(table_name, table, &mut sym.references);
+    }
+    Some(sym)
+}
+
+/// `CREATE FUNCTION post_count(author BIGINT) RETURNS INTEGER` → `post_count`
+/// with signature `(author BIGINT) RETURNS INTEGER`.
+fn extract_function(node: Node, statement: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name = find_child(node, "object_reference").and_then(|f| object_name(f, source))?;
+    let params = find_child(node, "function_arguments")
+        .map(|a| one_line(&text(a, source)))
+        .unwrap_or_else(|| "()".to_string());
+    let returns = node
+        .children(&mut node.walk())
+        .skip_while(|c| c.kind() != "keyword_returns")
+        .nth(1)
+        .map(|r| format!(" RETURNS {}", text(r, source)))
+        .unwrap_or_default();
+    Some(symbol(
+        name,
+        SymbolKind::Fn,
+        Some(format!("{params}{returns}")),
+        statement,
+        source,
+    ))
+}
+
+/// `CREATE TRIGGER posts_touch BEFORE UPDATE ON posts ... EXECUTE FUNCTION touch()`
+/// → signature `BEFORE UPDATE ON posts`, a `TypeRef` to the table and a `Call`
+/// to the function it runs.
+fn extract_trigger(node: Node, statement: Node, source: &[u8]) -> Option<ExtractedSymbol> {
+    let name_node = find_child(node, "object_reference")?;
+    let table = reference_after(node, "keyword_on");
+    let mut cursor = node.walk();
+    let timing_end = node
+        .children(&mut cursor)
+        .find(|c| matches!(c.kind(), "keyword_for" | "keyword_when" | "keyword_execute"))
+        .map_or(node.end_byte(), |c| c.start_byte());
+    let timing = source
+        .get(name_node.end_byte()..timing_end)
+        .map(|t| one_line(&String::from_utf8_lossy(t)))
+        .filter(|t| !t.is_empty());
+
+    let mut sym = symbol(
+        object_name(name_node, source)?,
+        SymbolKind::Fn,
+        timing,
+        statement,
+        source,
+    );
+    if let Some(table) = table
+        && let Some(table_name) = object_name(table, source)
+    {
+        push_type_ref(table_name, table, &mut sym.references);
+    }
+    if let Some(function) = reference_after(node, "keyword_execute")
+        && let Some(function_name) = object_name(function, source)
+    {
+        sym.references.push(ExtractedRef {
+            name: function_name,
+            kind: RefKind::Call,
+            line: function.start_position().row + 1,
+        });
+    }
+    Some(sym)
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if refs
+        .iter()
+        .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
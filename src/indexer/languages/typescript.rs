@@ -106,6 +106,7 @@ fn extract_from_node(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbo
             end_line: file_end,
             children: Vec::new(),
             references: import_refs,
+            metadata: Vec::new(),
         });
     }
 
@@ -124,6 +125,7 @@ fn extract_from_node(node: Node, source: &[u8], symbols: &mut Vec<ExtractedSymbo
             end_line: node.end_position().row + 1,
             children: Vec::new(),
             references: test_refs,
+            metadata: Vec::new(),
         });
     }
 }
@@ -176,6 +178,7 @@ fn extract_export_statement(node: Node, source: &[u8], symbols: &mut Vec<Extract
                             end_line: node.end_position().row + 1,
                             children: Vec::new(),
                             references: Vec::new(),
+                            metadata: Vec::new(),
                         });
                         break;
                     }
@@ -235,6 +238,7 @@ fn extract_export_clause(
                     end_line: child.end_position().row + 1,
                     children: Vec::new(),
                     references: refs,
+                    metadata: Vec::new(),
                 });
             }
         }
@@ -276,6 +280,7 @@ fn extract_default_export(node: Node, source: &[u8]) -> Option<ExtractedSymbol>
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: refs,
+        metadata: Vec::new(),
     })
 }
 
@@ -328,6 +333,10 @@ fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
     if node.parent().map(|p| p.kind()) == Some("export_statement") {
         prev = node.parent().and_then(|p| p.prev_sibling());
     }
+    // Method decorators sit between the doc comment and the method
+    while let Some(decorator) = prev.filter(|p| p.kind() == "decorator") {
+        prev = decorator.prev_sibling();
+    }
     if let Some(comment_node) = prev {
         if comment_node.kind() == "comment" {
             let t = text(comment_node, source);
@@ -349,12 +358,47 @@ fn get_doc_comment(node: Node, source: &[u8]) -> Option<String> {
     None
 }
 
+/// Decorators of a class, method or field by their callee without arguments:
+/// `@Injectable()` → `decorator=Injectable`, `@Controller('/x')` → `decorator=Controller`.
+/// A class's may sit on its `export` statement; a method's precede it in the class body.
+fn decorator_metadata(node: Node, source: &[u8]) -> Vec<(String, String)> {
+    let mut decorators = Vec::new();
+    if let Some(export) = node.parent().filter(|p| p.kind() == "export_statement") {
+        let mut cursor = export.walk();
+        decorators.extend(export.children_by_field_name("decorator", &mut cursor));
+    }
+    let mut cursor = node.walk();
+    decorators.extend(node.children_by_field_name("decorator", &mut cursor));
+    if node.kind() == "method_definition" {
+        let mut preceding = Vec::new();
+        let mut sibling = node.prev_sibling();
+        while let Some(s) = sibling.filter(|s| matches!(s.kind(), "decorator" | "comment")) {
+            if s.kind() == "decorator" {
+                preceding.push(s);
+            }
+            sibling = s.prev_sibling();
+        }
+        decorators.extend(preceding.into_iter().rev());
+    }
+
+    decorators
+        .into_iter()
+        .filter_map(|d| d.named_child(0))
+        .map(|expr| match expr.kind() {
+            "call_expression" => expr.child_by_field_name("function").unwrap_or(expr),
+            _ => expr,
+        })
+        .map(|callee| ("decorator".to_string(), text(callee, source)))
+        .collect()
+}
+
 fn extract_function(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
     let sig = build_signature(node, source);
     let doc = get_doc_comment(node, source);
 
     let mut refs = Vec::new();
+    collect_signature_type_refs(node, source, &mut refs);
     if let Some(body) = node.child_by_field_name("body") {
         extract_calls(body, source, &mut refs);
     }
@@ -368,6 +412,7 @@ fn extract_function(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: refs,
+        metadata: Vec::new(),
     })
 }
 
@@ -378,27 +423,39 @@ fn extract_class(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let mut children = Vec::new();
     let mut refs = Vec::new();
 
-    // Check for extends/implements
-    // type_parameters are separate from heritage; heritage is handled below
+    // `extends Base<User>` / `implements Repository<User>`: the supertypes, and
+    // their type arguments as type refs
+    let generics = type_parameter_names(node, source);
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        if child.kind() == "class_heritage" {
-            let heritage_text = text(child, source);
-            if heritage_text.contains("extends") || heritage_text.contains("implements") {
-                // Extract referenced type names
-                let mut hc = child.walk();
-                for hchild in child.children(&mut hc) {
-                    if hchild.kind() == "identifier" || hchild.kind() == "type_identifier" {
+        if child.kind() != "class_heritage" {
+            continue;
+        }
+        let mut hc = child.walk();
+        for clause in child.named_children(&mut hc) {
+            match clause.kind() {
+                "extends_clause" => {
+                    if let Some(value) = clause.child_by_field_name("value") {
                         refs.push(ExtractedRef {
-                            name: text(hchild, source),
+                            name: extract_callable_name(value, source),
                             kind: RefKind::Extends,
-                            line: hchild.start_position().row + 1,
+                            line: value.start_position().row + 1,
                         });
                     }
+                    if let Some(args) = clause.child_by_field_name("type_arguments") {
+                        collect_type_refs(args, source, &generics, &mut refs);
+                    }
+                }
+                "implements_clause" => {
+                    push_supertypes(clause, source, &generics, RefKind::Implements, &mut refs);
                 }
+                _ => {}
             }
         }
     }
+    if let Some(params) = node.child_by_field_name("type_parameters") {
+        collect_type_refs(params, source, &generics, &mut refs);
+    }
 
     if let Some(body) = node.child_by_field_name("body") {
         let mut cursor = body.walk();
@@ -428,6 +485,7 @@ fn extract_class(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children,
         references: refs,
+        metadata: decorator_metadata(node, source),
     })
 }
 
@@ -437,6 +495,7 @@ fn extract_method(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let doc = get_doc_comment(node, source);
 
     let mut refs = Vec::new();
+    collect_signature_type_refs(node, source, &mut refs);
     if let Some(body) = node.child_by_field_name("body") {
         extract_calls(body, source, &mut refs);
     }
@@ -450,27 +509,46 @@ fn extract_method(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children: Vec::new(),
         references: refs,
+        metadata: decorator_metadata(node, source),
     })
 }
 
 fn extract_field(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
-    let type_ann = node.child_by_field_name("type").map(|n| text(n, source));
+    let type_node = node.child_by_field_name("type");
+    let mut refs = Vec::new();
+    if let Some(t) = type_node {
+        collect_type_refs(t, source, &type_parameter_names(node, source), &mut refs);
+    }
     Some(ExtractedSymbol {
         name,
         kind: SymbolKind::Var,
-        signature: type_ann,
+        signature: type_node.map(|n| text(n, source)),
         doc_comment: None,
         start_line: node.start_position().row + 1,
         end_line: node.end_position().row + 1,
         children: Vec::new(),
-        references: Vec::new(),
+        references: refs,
+        metadata: decorator_metadata(node, source),
     })
 }
 
 fn extract_interface(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
     let doc = get_doc_comment(node, source);
+    let generics = type_parameter_names(node, source);
+
+    let mut refs = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "extends_type_clause" => {
+                push_supertypes(child, source, &generics, RefKind::Extends, &mut refs);
+            }
+            "type_parameters" => collect_type_refs(child, source, &generics, &mut refs),
+            _ => {}
+        }
+    }
 
     let mut children = Vec::new();
     if let Some(body) = node.child_by_field_name("body") {
@@ -479,21 +557,28 @@ fn extract_interface(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
             match member.kind() {
                 "property_signature" => {
                     if let Some(prop_name) = member.child_by_field_name("name") {
-                        let type_ann = member.child_by_field_name("type").map(|n| text(n, source));
+                        let type_node = member.child_by_field_name("type");
+                        let mut member_refs = Vec::new();
+                        if let Some(t) = type_node {
+                            collect_type_refs(t, source, &generics, &mut member_refs);
+                        }
                         children.push(ExtractedSymbol {
                             name: text(prop_name, source),
                             kind: SymbolKind::Var,
-                            signature: type_ann,
+                            signature: type_node.map(|n| text(n, source)),
                             doc_comment: None,
                             start_line: member.start_position().row + 1,
                             end_line: member.end_position().row + 1,
                             children: Vec::new(),
-                            references: Vec::new(),
+                            references: member_refs,
+                            metadata: Vec::new(),
                         });
                     }
                 }
                 "method_signature" => {
                     if let Some(method_name) = member.child_by_field_name("name") {
+                        let mut member_refs = Vec::new();
+                        collect_signature_type_refs(member, source, &mut member_refs);
                         children.push(ExtractedSymbol {
                             name: text(method_name, source),
                             kind: SymbolKind::Method,
@@ -502,7 +587,8 @@ fn extract_interface(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
                             start_line: member.start_position().row + 1,
                             end_line: member.end_position().row + 1,
                             children: Vec::new(),
-                            references: Vec::new(),
+                            references: member_refs,
+                            metadata: Vec::new(),
                         });
                     }
                 }
@@ -519,24 +605,35 @@ fn extract_interface(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         start_line: node.start_position().row + 1,
         end_line: node.end_position().row + 1,
         children,
-        references: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
     })
 }
 
 fn extract_type_alias(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
     let name = node.child_by_field_name("name").map(|n| text(n, source))?;
-    let value = node.child_by_field_name("value").map(|n| text(n, source));
+    let value_node = node.child_by_field_name("value");
     let doc = get_doc_comment(node, source);
 
+    let generics = type_parameter_names(node, source);
+    let mut refs = Vec::new();
+    if let Some(params) = node.child_by_field_name("type_parameters") {
+        collect_type_refs(params, source, &generics, &mut refs);
+    }
+    if let Some(value) = value_node {
+        collect_type_refs(value, source, &generics, &mut refs);
+    }
+
     Some(ExtractedSymbol {
         name,
         kind: SymbolKind::Type,
-        signature: value,
+        signature: value_node.map(|n| text(n, source)),
         doc_comment: doc,
         start_line: node.start_position().row + 1,
         end_line: node.end_position().row + 1,
         children: Vec::new(),
-        references: Vec::new(),
+        references: refs,
+        metadata: Vec::new(),
     })
 }
 
@@ -567,6 +664,7 @@ fn extract_enum(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
                         end_line: member.end_position().row + 1,
                         children: Vec::new(),
                         references: Vec::new(),
+                        metadata: Vec::new(),
                     });
                 }
             }
@@ -582,6 +680,7 @@ fn extract_enum(node: Node, source: &[u8]) -> Option<ExtractedSymbol> {
         end_line: node.end_position().row + 1,
         children,
         references: Vec::new(),
+        metadata: Vec::new(),
     })
 }
 
@@ -600,6 +699,10 @@ fn extract_variable_declarations(node: Node, source: &[u8], symbols: &mut Vec<Ex
                         let doc = get_doc_comment(node, source);
 
                         let mut refs = Vec::new();
+                        if let Some(t) = child.child_by_field_name("type") {
+                            collect_type_refs(t, source, &[], &mut refs);
+                        }
+                        collect_signature_type_refs(value_n, source, &mut refs);
                         if let Some(body) = value_n.child_by_field_name("body") {
                             extract_calls(body, source, &mut refs);
                         }
@@ -613,20 +716,26 @@ fn extract_variable_declarations(node: Node, source: &[u8], symbols: &mut Vec<Ex
                             end_line: node.end_position().row + 1,
                             children: Vec::new(),
                             references: refs,
+                            metadata: Vec::new(),
                         });
                     }
                     _ => {
                         // Regular variable/const
-                        let type_ann = child.child_by_field_name("type").map(|n| text(n, source));
+                        let type_node = child.child_by_field_name("type");
+                        let mut refs = Vec::new();
+                        if let Some(t) = type_node {
+                            collect_type_refs(t, source, &[], &mut refs);
+                        }
                         symbols.push(ExtractedSymbol {
                             name,
                             kind: SymbolKind::Const,
-                            signature: type_ann,
+                            signature: type_node.map(|n| text(n, source)),
                             doc_comment: get_doc_comment(node, source),
                             start_line: node.start_position().row + 1,
                             end_line: node.end_position().row + 1,
                             children: Vec::new(),
-                            references: Vec::new(),
+                            references: refs,
+                            metadata: Vec::new(),
                         });
                     }
                 }
@@ -668,6 +777,19 @@ fn extract_calls_recursive(cursor: &mut TreeCursor, source: &[u8], refs: &mut Ve
                     }
                 }
             }
+            // `<UserCard />` renders a component; lowercase tags are HTML elements
+            "jsx_opening_element" | "jsx_self_closing_element" => {
+                if let Some(tag) = node.child_by_field_name("name") {
+                    let name = text(tag, source);
+                    if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                        refs.push(ExtractedRef {
+                            name,
+                            kind: RefKind::Call,
+                            line: node.start_position().row + 1,
+                        });
+                    }
+                }
+            }
             "binary_expression" => {
                 // Capture `x instanceof Y`
                 if let Some(op) = node.child_by_field_name("operator") {
@@ -717,6 +839,193 @@ fn extract_callable_name(func: Node, source: &[u8]) -> String {
     }
 }
 
+/// Global and utility types that never resolve to a symbol in the repo.
+const BUILTIN_TYPES: &[&str] = &[
+    "Array",
+    "ReadonlyArray",
+    "Promise",
+    "PromiseLike",
+    "Record",
+    "Partial",
+    "Required",
+    "Readonly",
+    "Pick",
+    "Omit",
+    "Exclude",
+    "Extract",
+    "NonNullable",
+    "ReturnType",
+    "Parameters",
+    "InstanceType",
+    "Awaited",
+    "Map",
+    "Set",
+    "WeakMap",
+    "WeakSet",
+    "Date",
+    "RegExp",
+    "Error",
+    "Function",
+    "Object",
+    "String",
+    "Number",
+    "Boolean",
+    "Symbol",
+    "Iterable",
+    "Iterator",
+    "AsyncIterable",
+    "AsyncIterator",
+    "Generator",
+    "AsyncGenerator",
+];
+
+/// Type parameters in scope at `node`: its own and those of every enclosing
+/// declaration, so a method sees its class's `T`.
+fn type_parameter_names(node: Node, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut current = Some(node);
+    while let Some(n) = current {
+        if let Some(params) = n.child_by_field_name("type_parameters") {
+            let mut cursor = params.walk();
+            for param in params.named_children(&mut cursor) {
+                if let Some(name) = param.child_by_field_name("name") {
+                    names.push(text(name, source));
+                }
+            }
+        }
+        current = n.parent();
+    }
+    names
+}
+
+/// `TypeRef`s from a function's type parameter constraints, parameter types and
+/// return type. Default values and parameter names are skipped.
+fn collect_signature_type_refs(node: Node, source: &[u8], refs: &mut Vec<ExtractedRef>) {
+    let generics = type_parameter_names(node, source);
+    if let Some(params) = node.child_by_field_name("type_parameters") {
+        collect_type_refs(params, source, &generics, refs);
+    }
+    if let Some(params) = node.child_by_field_name("parameters") {
+        let mut cursor = params.walk();
+        for param in params.named_children(&mut cursor) {
+            if let Some(t) = param.child_by_field_name("type") {
+                collect_type_refs(t, source, &generics, refs);
+            }
+        }
+    }
+    if let Some(ret) = node.child_by_field_name("return_type") {
+        collect_type_refs(ret, source, &generics, refs);
+    }
+}
+
+/// `implements A, B<User>` / interface `extends A, B`: each listed type as a
+/// `kind` ref, and its type arguments as type refs.
+fn push_supertypes(
+    clause: Node,
+    source: &[u8],
+    generics: &[String],
+    kind: RefKind,
+    refs: &mut Vec<ExtractedRef>,
+) {
+    let mut cursor = clause.walk();
+    for ty in clause.named_children(&mut cursor) {
+        let (base, args) = match ty.kind() {
+            "generic_type" => (
+                ty.child_by_field_name("name"),
+                ty.child_by_field_name("type_arguments"),
+            ),
+            _ => (Some(ty), None),
+        };
+        if let Some(name) = base.and_then(|b| base_type_name(b, source)) {
+            refs.push(ExtractedRef {
+                name,
+                kind,
+                line: ty.start_position().row + 1,
+            });
+        }
+        if let Some(args) = args {
+            collect_type_refs(args, source, generics, refs);
+        }
+    }
+}
+
+/// `ns.User` → `User`
+fn base_type_name(node: Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "type_identifier" | "identifier" => Some(text(node, source)),
+        "nested_type_identifier" => node.child_by_field_name("name").map(|n| text(n, source)),
+        _ => None,
+    }
+}
+
+/// `TypeRef`s for the types an annotation names: `Promise<User | null>` → `User`,
+/// `ns.UserDto[]` → `UserDto`. Builtins and type parameters in `generics` are skipped.
+fn collect_type_refs(node: Node, source: &[u8], generics: &[String], refs: &mut Vec<ExtractedRef>) {
+    match node.kind() {
+        "type_identifier" => {
+            let name = text(node, source);
+            if !generics.contains(&name) {
+                push_type_ref(name, node, refs);
+            }
+        }
+        "nested_type_identifier" => {
+            if let Some(name) = node.child_by_field_name("name") {
+                push_type_ref(text(name, source), node, refs);
+            }
+        }
+        // `typeof x` names a value; literal and primitive types never resolve
+        "type_query" | "literal_type" | "predefined_type" | "this_type" | "comment" => {}
+        // `{ [K in keyof T]: V }` binds `K` for the rest of the signature
+        "index_signature" => {
+            let mut scoped = generics.to_vec();
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                if child.kind() == "mapped_type_clause" {
+                    if let Some(name) = child.child_by_field_name("name") {
+                        scoped.push(text(name, source));
+                    }
+                }
+            }
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, source, &scoped, refs);
+            }
+        }
+        // `<U>(x: U) => U` declares its own type parameters
+        "function_type" | "constructor_type"
+            if node.child_by_field_name("type_parameters").is_some() =>
+        {
+            let mut scoped = generics.to_vec();
+            scoped.extend(type_parameter_names(node, source));
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, source, &scoped, refs);
+            }
+        }
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_type_refs(child, source, generics, refs);
+            }
+        }
+    }
+}
+
+fn push_type_ref(name: String, node: Node, refs: &mut Vec<ExtractedRef>) {
+    if BUILTIN_TYPES.contains(&name.as_str())
+        || refs
+            .iter()
+            .any(|r| r.kind == RefKind::TypeRef && r.name == name)
+    {
+        return;
+    }
+    refs.push(ExtractedRef {
+        name,
+        kind: RefKind::TypeRef,
+        line: node.start_position().row + 1,
+    });
+}
+
 /// Extract import references from an import statement.
 /// Returns refs without wrapping in a symbol — caller accumulates into a single `_imports` symbol.
 fn extract_import_refs(node: Node, source: &[u8]) -> Vec<ExtractedRef> {
@@ -821,6 +1130,7 @@ fn extract_ambient_declaration(node: Node, source: &[u8], symbols: &mut Vec<Extr
                 end_line: node.end_position().row + 1,
                 children,
                 references: Vec::new(),
+                metadata: Vec::new(),
             });
             return;
         }
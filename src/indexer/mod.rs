@@ -1,14 +1,18 @@
+pub mod doc;
 pub mod hasher;
 pub mod languages;
 
 use anyhow::{Context, Result};
 use ignore::WalkBuilder;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::time::Instant;
 
+use crate::config::IndexerConfig;
 use crate::storage::{IndexStats, SqliteStorage};
-use languages::LanguageExtractor;
+use languages::query::QueryExtractor;
+pub use languages::query::QueryPacks;
+use languages::{FileContext, LanguageExtractor};
 
 pub const DEFAULT_IGNORE: &[&str] = &[
     "node_modules",
@@ -25,7 +29,14 @@ pub const DEFAULT_IGNORE: &[&str] = &[
     ".cache",
 ];
 
-pub const DEFAULT_IGNORE_SUFFIXES: &[&str] = &[".min.js", ".min.mjs", ".min.cjs", ".min.css"];
+pub const DEFAULT_IGNORE_SUFFIXES: &[&str] = &[
+    ".min.js",
+    ".min.mjs",
+    ".min.cjs",
+    ".min.css",
+    "package-lock.json",
+    "pnpm-lock.yaml",
+];
 
 /// Check if a single path component name matches a default ignore directory.
 pub fn is_ignored_component(name: &str) -> bool {
@@ -49,6 +60,9 @@ pub struct ExtractedSymbol {
     pub end_line: usize,
     pub children: Vec<ExtractedSymbol>,
     pub references: Vec<ExtractedRef>,
+    /// Facts that don't belong in the signature, as `key`/`value` pairs
+    /// (`vis`/`pub`, `derive`/`Serialize`, `attr`/`test`). A key may repeat.
+    pub metadata: Vec<(String, String)>,
 }
 
 #[derive(Debug, Clone)]
@@ -74,6 +88,11 @@ pub enum SymbolKind {
     Var,
     Impl,
     Section,
+    Macro,
+    /// A key in a data file (YAML, TOML, JSON)
+    Key,
+    /// A fenced code block in a document, named by its language
+    Code,
 }
 
 impl SymbolKind {
@@ -92,6 +111,9 @@ impl SymbolKind {
             Self::Var => "var",
             Self::Impl => "impl",
             Self::Section => "section",
+            Self::Macro => "macro",
+            Self::Key => "key",
+            Self::Code => "code",
         }
     }
 }
@@ -110,6 +132,13 @@ pub enum RefKind {
     TypeRef,
     Extends,
     Implements,
+    /// From a declaration (e.g. a C/C++ prototype) to the symbol that defines it
+    Definition,
+    /// From a class to a trait or module whose members it pulls in (PHP `use SomeTrait;`,
+    /// Ruby `include`/`extend`/`prepend`)
+    Mixin,
+    /// From a document section to a symbol it names in backticks or a file it links to
+    Mention,
 }
 
 impl RefKind {
@@ -120,6 +149,9 @@ impl RefKind {
             Self::TypeRef => "type_ref",
             Self::Extends => "extends",
             Self::Implements => "implements",
+            Self::Definition => "definition",
+            Self::Mixin => "mixin",
+            Self::Mention => "mention",
         }
     }
 }
@@ -136,8 +168,17 @@ struct ExistingFile {
 }
 
 enum FileResult {
-    New { symbols: usize, refs: usize },
-    Changed { symbols: usize, refs: usize },
+    New {
+        symbols: usize,
+        refs: usize,
+        /// Symbol names the file added or removed
+        names: HashSet<String>,
+    },
+    Changed {
+        symbols: usize,
+        refs: usize,
+        names: HashSet<String>,
+    },
     Unchanged,
     Skipped,
 }
@@ -161,27 +202,64 @@ impl Indexer {
                 Box::new(languages::rust_lang::RustExtractor),
                 Box::new(languages::ruby::RubyExtractor),
                 Box::new(languages::markdown::MarkdownExtractor),
+                Box::new(languages::notebook::NotebookExtractor),
+                Box::new(languages::go::GoExtractor),
+                Box::new(languages::java::JavaExtractor),
+                Box::new(languages::kotlin::KotlinExtractor),
+                Box::new(languages::cpp::CppExtractor),
+                Box::new(languages::csharp::CSharpExtractor),
+                Box::new(languages::php::PhpExtractor),
+                Box::new(languages::sfc::SfcExtractor),
+                Box::new(languages::data::DataExtractor),
+                Box::new(languages::proto::ProtoExtractor),
+                Box::new(languages::graphql::GraphqlExtractor),
+                Box::new(languages::shell::ShellExtractor),
+                Box::new(languages::dockerfile::DockerfileExtractor),
+                Box::new(languages::sql::SqlExtractor),
             ],
         }
     }
 
-    fn get_extractor(&self, ext: &str) -> Option<&dyn LanguageExtractor> {
-        self.extractors
-            .iter()
-            .find(|e| e.extensions().contains(&ext))
-            .map(|e| e.as_ref())
+    /// Picks the extractor by extension, falling back to well-known file names
+    /// (`Dockerfile`, `Containerfile.dev`). The repo's query extractors come first so
+    /// a query pack can replace a built-in extractor. Returns the extractor with the
+    /// key used for grammar and language lookups: the extension, or the matched file
+    /// name.
+    fn get_extractor<'a>(
+        &'a self,
+        path: &Path,
+        queries: &'a [QueryExtractor],
+    ) -> Option<(&'a dyn LanguageExtractor, String)> {
+        let extractors = || {
+            queries
+                .iter()
+                .map(|e| e as &dyn LanguageExtractor)
+                .chain(self.extractors.iter().map(|e| e.as_ref()))
+        };
+
+        if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+            if let Some(e) = extractors().find(|e| e.handles_extension(ext)) {
+                return Some((e, ext.to_string()));
+            }
+        }
+
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        extractors().find_map(|e| e.match_filename(&name).map(|known| (e, known)))
     }
 
+    /// Indexes a repository. `queries` are the repo's query packs, compiled from
+    /// `config.queries` by the caller so repeated runs can share them.
     pub fn index(
         &self,
         repo_path: &str,
         storage: &SqliteStorage,
-        ignore_patterns: &[String],
-        max_file_size: u64,
+        config: &IndexerConfig,
+        queries: &QueryPacks,
     ) -> Result<IndexStats> {
         let start = Instant::now();
         let abs_path = std::fs::canonicalize(repo_path)
             .with_context(|| format!("Invalid repository path: {}", repo_path))?;
+        let mut parser = queries.parser();
         let abs_path_str = abs_path
             .to_str()
             .ok_or_else(|| anyhow::anyhow!("Path is not valid UTF-8"))?;
@@ -196,8 +274,7 @@ impl Indexer {
         let mut files_unchanged = 0;
         let mut total_symbols = 0;
         let mut total_refs = 0;
-
-        let mut parser = tree_sitter::Parser::new();
+        let mut changed_names = HashSet::new();
 
         for entry in WalkBuilder::new(&abs_path)
             .hidden(false)
@@ -214,18 +291,13 @@ impl Indexer {
             }
 
             let path = entry.path();
-            let ext = match path.extension().and_then(|e| e.to_str()) {
-                Some(e) => e,
-                None => continue,
-            };
-
-            let extractor = match self.get_extractor(ext) {
-                Some(e) => e,
+            let (extractor, ext) = match self.get_extractor(path, queries.extractors()) {
+                Some(found) => found,
                 None => continue,
             };
 
             if let Ok(meta) = entry.metadata() {
-                if meta.len() > max_file_size {
+                if meta.len() > config.max_file_size {
                     continue;
                 }
             }
@@ -241,14 +313,19 @@ impl Indexer {
                 continue;
             }
 
-            if matches_ignore_pattern(&rel_path, ignore_patterns) {
+            if matches_ignore_pattern(&rel_path, &config.ignore) {
                 continue;
             }
 
             let previous_entry = existing_map.remove(&rel_path);
 
+            let file = FileContext {
+                rel_path: &rel_path,
+                ext: &ext,
+                max_key_depth: config.max_key_depth,
+            };
             match process_file(
-                &rel_path,
+                &file,
                 path,
                 extractor,
                 &previous_entry,
@@ -256,15 +333,25 @@ impl Indexer {
                 storage,
                 repo_id,
             )? {
-                FileResult::New { symbols, refs } => {
+                FileResult::New {
+                    symbols,
+                    refs,
+                    names,
+                } => {
                     files_new += 1;
                     total_symbols += symbols;
                     total_refs += refs;
+                    changed_names.extend(names);
                 }
-                FileResult::Changed { symbols, refs } => {
+                FileResult::Changed {
+                    symbols,
+                    refs,
+                    names,
+                } => {
                     files_changed += 1;
                     total_symbols += symbols;
                     total_refs += refs;
+                    changed_names.extend(names);
                 }
                 FileResult::Unchanged => {
                     files_unchanged += 1;
@@ -273,8 +360,9 @@ impl Indexer {
             }
         }
 
-        let files_deleted = remove_deleted_files(storage, &existing_map)?;
+        let files_deleted = remove_deleted_files(storage, &existing_map, &mut changed_names)?;
 
+        storage.unresolve_refs_named(repo_id, &changed_names)?;
         storage.resolve_references(repo_id)?;
         storage.update_repo_timestamp(repo_id)?;
         storage.commit()?;
@@ -296,11 +384,12 @@ impl Indexer {
         repo_path: &str,
         files: &[String],
         storage: &SqliteStorage,
-        ignore_patterns: &[String],
-        max_file_size: u64,
+        config: &IndexerConfig,
+        queries: &QueryPacks,
     ) -> Result<IndexStats> {
         let start = Instant::now();
         let abs_path = std::fs::canonicalize(repo_path)?;
+        let mut parser = queries.parser();
         let abs_path_str = abs_path
             .to_str()
             .ok_or_else(|| anyhow::anyhow!("Path not UTF-8"))?;
@@ -309,13 +398,13 @@ impl Indexer {
 
         let mut existing_map = build_existing_file_map(storage, repo_id)?;
 
-        let mut parser = tree_sitter::Parser::new();
         let mut total_symbols = 0;
         let mut total_refs = 0;
         let mut files_updated = 0;
+        let mut changed_names = HashSet::new();
 
         for rel_path in files {
-            if matches_ignore_pattern(rel_path, ignore_patterns) {
+            if matches_ignore_pattern(rel_path, &config.ignore) {
                 continue;
             }
 
@@ -325,25 +414,25 @@ impl Indexer {
             }
 
             if let Ok(meta) = std::fs::metadata(&full_path) {
-                if meta.len() > max_file_size {
+                if meta.len() > config.max_file_size {
                     continue;
                 }
             }
 
-            let ext = match full_path.extension().and_then(|e| e.to_str()) {
-                Some(e) => e,
-                None => continue,
-            };
-
-            let extractor = match self.get_extractor(ext) {
-                Some(e) => e,
+            let (extractor, ext) = match self.get_extractor(&full_path, queries.extractors()) {
+                Some(found) => found,
                 None => continue,
             };
 
             let previous_entry = existing_map.remove(rel_path.as_str());
 
-            match process_file(
+            let file = FileContext {
                 rel_path,
+                ext: &ext,
+                max_key_depth: config.max_key_depth,
+            };
+            match process_file(
+                &file,
                 &full_path,
                 extractor,
                 &previous_entry,
@@ -351,15 +440,26 @@ impl Indexer {
                 storage,
                 repo_id,
             )? {
-                FileResult::New { symbols, refs } | FileResult::Changed { symbols, refs } => {
+                FileResult::New {
+                    symbols,
+                    refs,
+                    names,
+                }
+                | FileResult::Changed {
+                    symbols,
+                    refs,
+                    names,
+                } => {
                     files_updated += 1;
                     total_symbols += symbols;
                     total_refs += refs;
+                    changed_names.extend(names);
                 }
                 FileResult::Unchanged | FileResult::Skipped => {}
             }
         }
 
+        storage.unresolve_refs_named(repo_id, &changed_names)?;
         storage.resolve_references(repo_id)?;
         storage.commit()?;
 
@@ -396,7 +496,7 @@ fn build_existing_file_map(
 }
 
 fn process_file(
-    rel_path: &str,
+    file: &FileContext,
     full_path: &Path,
     extractor: &dyn LanguageExtractor,
     previous_entry: &Option<ExistingFile>,
@@ -407,7 +507,7 @@ fn process_file(
     let source = match std::fs::read(full_path) {
         Ok(s) => s,
         Err(e) => {
-            tracing::warn!(file = %rel_path, error = %e, "Failed to read file");
+            tracing::warn!(file = %file.rel_path, error = %e, "Failed to read file");
             return Ok(FileResult::Skipped);
         }
     };
@@ -420,44 +520,52 @@ fn process_file(
         }
     }
 
-    let ext = full_path.extension().and_then(|e| e.to_str()).unwrap_or("");
-    if let Err(err) = parser.set_language(&extractor.language_for_ext(ext)) {
-        tracing::warn!(file = %rel_path, error = %err, "Failed to set parser language");
+    if let Err(err) = parser.set_language(&extractor.language_for_ext(file.ext)) {
+        tracing::warn!(file = %file.rel_path, error = %err, "Failed to set parser language");
         return Ok(FileResult::Skipped);
     }
 
     let tree = match parser.parse(&source, None) {
         Some(t) => t,
         None => {
-            tracing::warn!(file = %rel_path, "Failed to parse file");
+            tracing::warn!(file = %file.rel_path, "Failed to parse file");
             return Ok(FileResult::Skipped);
         }
     };
 
-    let symbols = extractor.extract(&source, &tree);
+    let symbols = extractor.extract_file(file, &source, &tree);
 
-    let language = languages::detect_language(ext).unwrap_or("unknown");
-    let file_id = storage.upsert_file(repo_id, rel_path, &hash, language)?;
+    let language = extractor.language_name(file.ext).unwrap_or("unknown");
+    let file_id = storage.upsert_file(repo_id, file.rel_path, &hash, language)?;
+    let previous_names = storage.file_symbol_names(file_id)?;
     storage.clear_file_symbols(file_id)?;
 
     let mut sym_count = 0;
     let mut ref_count = 0;
+    let mut names = HashSet::new();
     for sym in &symbols {
-        storage.insert_symbol_tree(file_id, repo_id, rel_path, sym, None)?;
+        storage.insert_symbol_tree(file_id, repo_id, file.rel_path, sym, None)?;
         sym_count += count_symbols(sym);
         ref_count += count_refs(sym);
+        collect_names(sym, &mut names);
     }
+    let names = names
+        .symmetric_difference(&previous_names)
+        .cloned()
+        .collect();
 
     let is_new = previous_entry.is_none();
     if is_new {
         Ok(FileResult::New {
             symbols: sym_count,
             refs: ref_count,
+            names,
         })
     } else {
         Ok(FileResult::Changed {
             symbols: sym_count,
             refs: ref_count,
+            names,
         })
     }
 }
@@ -465,9 +573,11 @@ fn process_file(
 fn remove_deleted_files(
     storage: &SqliteStorage,
     remaining: &HashMap<String, ExistingFile>,
+    removed_names: &mut HashSet<String>,
 ) -> Result<usize> {
     let count = remaining.len();
     for existing in remaining.values() {
+        removed_names.extend(storage.file_symbol_names(existing.id)?);
         storage.delete_file(existing.id)?;
     }
     Ok(count)
@@ -510,3 +620,10 @@ fn count_symbols(sym: &ExtractedSymbol) -> usize {
 fn count_refs(sym: &ExtractedSymbol) -> usize {
     sym.references.len() + sym.children.iter().map(count_refs).sum::<usize>()
 }
+
+fn collect_names(sym: &ExtractedSymbol, names: &mut HashSet<String>) {
+    names.insert(sym.name.clone());
+    for child in &sym.children {
+        collect_names(child, names);
+    }
+}
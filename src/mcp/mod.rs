@@ -11,7 +11,7 @@ use serde::Deserialize;
 use std::sync::Arc;
 
 use crate::config::{ConfigCache, OutputConfig};
-use crate::indexer::Indexer;
+use crate::indexer::{Indexer, QueryPacks};
 use crate::output::{CompactFormatter, OutputFormatter, TokenBudget};
 use crate::storage::{self, SqliteStorage};
 use crate::watcher::WatcherHandle;
@@ -72,6 +72,35 @@ pub struct SearchParams {
     pub max_tokens: Option<usize>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FindSymbolsParams {
+    /// Absolute path to the repository root
+    pub path: String,
+    /// Metadata filters, all of which must match: `key` (any value) or `key=value`,
+    /// e.g. `vis=pub`, `attr=test`, `derive=Serialize`, `cfg=test`, `decorator=property`, `dsl=belongs_to`
+    pub meta: Vec<String>,
+    /// Optional symbol kind (fn, method, struct, enum, trait, ...)
+    pub kind: Option<String>,
+    /// Optional path prefix to restrict results to, e.g. `src/storage/`
+    pub file: Option<String>,
+    /// Optional token budget — limits response size (approximate, 1 token ≈ 4 bytes)
+    pub max_tokens: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UncertainRefsParams {
+    /// Absolute path to the repository root
+    pub path: String,
+    /// Optional symbol ID; covers the symbol and the symbols nested inside it
+    pub symbol_id: Option<i64>,
+    /// Optional relative file path; covers every symbol in the file
+    pub file: Option<String>,
+    /// Optional `ambiguous` or `unresolved` to list only those; both when omitted
+    pub only: Option<String>,
+    /// Optional token budget — limits response size (approximate, 1 token ≈ 4 bytes)
+    pub max_tokens: Option<usize>,
+}
+
 fn resolve_budget(param_budget: Option<usize>, config_budget: Option<usize>) -> Option<usize> {
     param_budget.or(config_budget)
 }
@@ -162,13 +191,12 @@ impl CtxhelprServer {
         tokio::spawn(async move {
             let idx = indexer.clone();
             let p = path_owned.clone();
-            let config = config_cache.get(&p);
-            let ignore = config.indexer.ignore.clone();
-            let max_file_size = config.indexer.max_file_size;
+            let config = config_cache.get(&p).indexer;
 
             let result = tokio::task::spawn_blocking(move || {
                 let storage = SqliteStorage::open(&p)?;
-                idx.index(&p, &storage, &ignore, max_file_size)
+                let queries = QueryPacks::load(std::path::Path::new(&p), &config.queries);
+                idx.index(&p, &storage, &config, &queries)
             })
             .await;
 
@@ -220,12 +248,12 @@ impl CtxhelprServer {
         let config = self.config_cache.get(&params.path);
         let indexer = self.indexer.clone();
         let path = params.path.clone();
-        let ignore = config.indexer.ignore.clone();
-        let max_file_size = config.indexer.max_file_size;
+        let indexer_config = config.indexer.clone();
         let stats = tokio::task::spawn_blocking(move || {
             let storage = open_storage(&path)?;
+            let queries = QueryPacks::load(std::path::Path::new(&path), &indexer_config.queries);
             indexer
-                .index(&path, &storage, &ignore, max_file_size)
+                .index(&path, &storage, &indexer_config, &queries)
                 .map_err(|e| McpError::internal_error(format!("Indexing failed: {e}"), None))
         })
         .await
@@ -287,7 +315,7 @@ impl CtxhelprServer {
     }
 
     #[tool(
-        description = "PREFER over Read for inspecting a specific function, class, or type. Returns signature, doc comment, call graph (what it calls and who calls it), and type references in a single call."
+        description = "PREFER over Read for inspecting a specific function, class, or type. Returns signature, doc comment (summary, description, parameters, return value and errors separately), call graph (what it calls and who calls it), and type references in a single call. For declarations such as C/C++ prototypes, `def` is the ID of the implementation."
     )]
     async fn get_symbol_detail(
         &self,
@@ -309,8 +337,11 @@ impl CtxhelprServer {
                 tracing::warn!(symbol_id = params.symbol_id, error = %err, "Failed to get dependencies");
                 Vec::new()
             });
+        let (definitions, deps): (Vec<_>, Vec<_>) = all_deps
+            .into_iter()
+            .partition(|r| r.ref_kind == "definition");
         let (type_refs, calls): (Vec<_>, Vec<_>) =
-            all_deps.into_iter().partition(|r| r.ref_kind == "type_ref");
+            deps.into_iter().partition(|r| r.ref_kind == "type_ref");
         let called_by = storage
             .get_references(&params.path, params.symbol_id)
             .unwrap_or_else(|err| {
@@ -319,7 +350,7 @@ impl CtxhelprServer {
             });
         let budget = resolve_budget(params.max_tokens, config.output.max_tokens);
         let output = apply_budget(
-            fmt.format_symbol_detail(&sym, &calls, &called_by, &type_refs),
+            fmt.format_symbol_detail(&sym, &calls, &called_by, &type_refs, &definitions),
             budget,
             "called_by",
         );
@@ -353,7 +384,44 @@ impl CtxhelprServer {
     }
 
     #[tool(
-        description = "PREFER over Grep for finding callers and usages of a symbol. Returns semantically accurate references (callers, importers, type references) -- unlike text search, never returns false positives from comments or strings."
+        description = "Find symbols by metadata rather than name: visibility (`vis=pub`, `vis=pub(crate)`), attributes (`attr=test`, `attr=tokio::main`), derives (`derive=Serialize`), cfg predicates (`cfg=test`) Python and TypeScript decorators (`decorator=pytest.fixture`, `decorator=Controller`) and Rails macros (`dsl=has_many`). Use it to list a module's public API, locate tests, or find every type implementing a derive. Every filter must match; narrow further by kind or file prefix."
+    )]
+    async fn find_symbols(
+        &self,
+        Parameters(params): Parameters<FindSymbolsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tracing::info!(path = %params.path, meta = ?params.meta, "find_symbols");
+        if let Some(result) = self.ensure_indexed(&params.path) {
+            return Ok(result);
+        }
+        let config = self.config_cache.get(&params.path);
+        let storage = open_storage(&params.path)?;
+        let fmt = formatter(&config.output);
+        let filters: Vec<storage::MetaFilter> = params
+            .meta
+            .iter()
+            .map(|f| storage::MetaFilter::parse(f))
+            .collect();
+        let symbols = storage
+            .find_symbols_by_meta(
+                &params.path,
+                &filters,
+                params.kind.as_deref(),
+                params.file.as_deref(),
+                config.search.max_results,
+            )
+            .map_err(|e| McpError::internal_error(format!("Query failed: {e}"), None))?;
+        let budget = resolve_budget(params.max_tokens, config.output.max_tokens);
+        let output = apply_budget(
+            fmt.format_meta_matches(&params.meta, &symbols),
+            budget,
+            "syms",
+        );
+        Ok(CallToolResult::success(vec![Content::text(output)]))
+    }
+
+    #[tool(
+        description = "PREFER over Grep for finding callers and usages of a symbol. Returns semantically accurate references (callers, importers, type references) -- unlike text search, never returns false positives from comments or strings. Each reference carries its resolution scope (via) and confidence (conf)."
     )]
     async fn get_references(
         &self,
@@ -404,6 +472,44 @@ impl CtxhelprServer {
         Ok(CallToolResult::success(vec![Content::text(output)]))
     }
 
+    #[tool(
+        description = "List the references of a symbol or file that need double-checking: ambiguous ones (the name matched several symbols and the pick has low confidence) and unresolved ones (no indexed target, usually a library or builtin). Pass only=ambiguous or only=unresolved to list one kind. Read the code at these call sites before trusting the call graph."
+    )]
+    async fn get_uncertain_refs(
+        &self,
+        Parameters(params): Parameters<UncertainRefsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tracing::info!(path = %params.path, symbol_id = ?params.symbol_id, file = ?params.file, only = ?params.only, "get_uncertain_refs");
+        if params.symbol_id.is_none() && params.file.is_none() {
+            return Err(McpError::invalid_params(
+                "Provide symbol_id, file, or both",
+                None,
+            ));
+        }
+        let only = match params.only.as_deref() {
+            Some(only) => Some(storage::Uncertainty::parse(only).ok_or_else(|| {
+                McpError::invalid_params("only must be `ambiguous` or `unresolved`", None)
+            })?),
+            None => None,
+        };
+        if let Some(result) = self.ensure_indexed(&params.path) {
+            return Ok(result);
+        }
+        let config = self.config_cache.get(&params.path);
+        let storage = open_storage(&params.path)?;
+        let fmt = formatter(&config.output);
+        let refs = storage
+            .get_uncertain_refs(&params.path, params.symbol_id, params.file.as_deref(), only)
+            .map_err(|e| McpError::internal_error(format!("Query failed: {e}"), None))?;
+        let budget = resolve_budget(params.max_tokens, config.output.max_tokens);
+        let output = apply_budget(
+            fmt.format_uncertain_refs(params.symbol_id, params.file.as_deref(), &refs),
+            budget,
+            "refs",
+        );
+        Ok(CallToolResult::success(vec![Content::text(output)]))
+    }
+
     #[tool(
         description = "Check index freshness and statistics: when last indexed, file/symbol/reference counts, stale and deleted files."
     )]
@@ -507,7 +613,8 @@ impl ServerHandler for CtxhelprServer {
                  ctxhelpr returns structured symbol data with signatures, call graphs, and \
                  cross-references in a single call -- faster and more accurate than text search. \
                  Workflow: get_overview -> drill with search_symbols/get_file_symbols/\
-                 get_symbol_detail/get_references/get_dependencies. \
+                 get_symbol_detail/get_references/get_dependencies; check get_uncertain_refs \
+                 before relying on a call edge. \
                  The index is kept fresh automatically via background file watching -- no manual \
                  update calls needed. The index only includes git-tracked files (.gitignore is respected). \
                  For gitignored files, use Grep/Glob/Read. \
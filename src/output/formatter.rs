@@ -10,9 +10,17 @@ pub trait OutputFormatter: Send + Sync {
         calls: &[RefRecord],
         called_by: &[RefRecord],
         type_refs: &[RefRecord],
+        definitions: &[RefRecord],
     ) -> String;
     fn format_search_results(&self, query: &str, hits: &[SearchHit]) -> String;
+    fn format_meta_matches(&self, filters: &[String], symbols: &[SymbolRecord]) -> String;
     fn format_references(&self, symbol_id: i64, refs: &[RefRecord]) -> String;
     fn format_dependencies(&self, symbol_id: i64, deps: &[RefRecord]) -> String;
+    fn format_uncertain_refs(
+        &self,
+        symbol_id: Option<i64>,
+        file: Option<&str>,
+        refs: &[RefRecord],
+    ) -> String;
     fn format_index_status(&self, status: &IndexStatus) -> String;
 }
@@ -9,6 +9,7 @@ pub use formatter::OutputFormatter;
 pub use token_budget::TokenBudget;
 
 use crate::config::OutputConfig;
+use crate::indexer::doc::{DocComment, DocEntry};
 use crate::storage::*;
 
 pub struct CompactFormatter {
@@ -113,6 +114,7 @@ impl OutputFormatter for CompactFormatter {
         calls: &[RefRecord],
         called_by: &[RefRecord],
         type_refs: &[RefRecord],
+        definitions: &[RefRecord],
     ) -> String {
         let mut obj = json!({
             "id": sym.id,
@@ -125,8 +127,11 @@ impl OutputFormatter for CompactFormatter {
         if let Some(sig) = &sym.signature {
             obj["sig"] = json!(sig);
         }
-        if let Some(doc) = &sym.doc_comment {
-            obj["doc"] = json!(doc);
+        if let Some(doc) = &sym.doc {
+            doc_detail(&mut obj, doc);
+        }
+        if !sym.metadata.is_empty() {
+            obj["meta"] = json!(meta_list(&sym.metadata));
         }
 
         if !calls.is_empty() {
@@ -197,6 +202,11 @@ impl OutputFormatter for CompactFormatter {
             );
         }
 
+        // A declaration points at its implementation elsewhere (e.g. a C prototype)
+        if let Some(id) = definitions.iter().find_map(|r| r.to_symbol_id) {
+            obj["def"] = json!(id);
+        }
+
         obj.to_string()
     }
 
@@ -237,6 +247,35 @@ impl OutputFormatter for CompactFormatter {
         obj.to_string()
     }
 
+    fn format_meta_matches(&self, filters: &[String], symbols: &[SymbolRecord]) -> String {
+        let mut path_index = PathIndex::new();
+
+        let results: Vec<Value> = symbols
+            .iter()
+            .map(|s| {
+                let mut v = symbol_brief(s, false, self.max_sig_len, self.max_doc_brief_len);
+                v["fi"] = json!(path_index.index(&s.file_rel_path));
+                v
+            })
+            .collect();
+
+        let mut obj = json!({"meta": filters, "syms": results});
+        if path_index.len() > 1 {
+            obj["_f"] = json!(path_index.into_list());
+        } else if let Some(only) = path_index.into_list().into_iter().next() {
+            if let Some(arr) = obj["syms"].as_array_mut() {
+                for item in arr {
+                    if let Some(item_obj) = item.as_object_mut() {
+                        item_obj.remove("fi");
+                        item_obj.insert("f".to_string(), json!(only));
+                    }
+                }
+            }
+        }
+
+        obj.to_string()
+    }
+
     fn format_references(&self, symbol_id: i64, refs: &[RefRecord]) -> String {
         let mut path_index = PathIndex::new();
 
@@ -256,6 +295,7 @@ impl OutputFormatter for CompactFormatter {
                 if let Some(l) = r.line {
                     v["line"] = json!(l);
                 }
+                resolution_info(&mut v, r);
                 v
             })
             .collect();
@@ -284,6 +324,7 @@ impl OutputFormatter for CompactFormatter {
                 let mut v = json!({"to_n": r.to_name, "kind": r.ref_kind});
                 if let Some(id) = r.to_symbol_id {
                     v["to_id"] = json!(id);
+                    resolution_info(&mut v, r);
                 } else {
                     v["external"] = json!(true);
                 }
@@ -294,6 +335,64 @@ impl OutputFormatter for CompactFormatter {
         json!({"id": symbol_id, "deps": results}).to_string()
     }
 
+    fn format_uncertain_refs(
+        &self,
+        symbol_id: Option<i64>,
+        file: Option<&str>,
+        refs: &[RefRecord],
+    ) -> String {
+        let mut path_index = PathIndex::new();
+        let results: Vec<Value> = refs
+            .iter()
+            .map(|r| {
+                let mut v = json!({
+                    "from_id": r.from_symbol_id,
+                    "to_n": r.to_name,
+                    "kind": r.ref_kind,
+                });
+                if let Some(n) = &r.from_name {
+                    v["from_n"] = json!(n);
+                }
+                if let Some(f) = &r.from_file {
+                    v["fi"] = json!(path_index.index(f));
+                }
+                if let Some(l) = r.line {
+                    v["line"] = json!(l);
+                }
+                if let Some(id) = r.to_symbol_id {
+                    v["to_id"] = json!(id);
+                    resolution_info(&mut v, r);
+                } else {
+                    v["external"] = json!(true);
+                }
+                v
+            })
+            .collect();
+
+        let mut obj = json!({"refs": results});
+        if let Some(id) = symbol_id {
+            obj["id"] = json!(id);
+        }
+        if let Some(f) = file {
+            obj["f"] = json!(f);
+        }
+        if path_index.len() > 1 {
+            obj["_f"] = json!(path_index.into_list());
+        } else {
+            // One file: the one asked about, or the symbol's
+            if let Some(only) = path_index.into_list().into_iter().next() {
+                obj["f"] = json!(only);
+            }
+            if let Some(arr) = obj["refs"].as_array_mut() {
+                for item in arr.iter_mut().filter_map(Value::as_object_mut) {
+                    item.remove("fi");
+                }
+            }
+        }
+
+        obj.to_string()
+    }
+
     fn format_index_status(&self, status: &IndexStatus) -> String {
         let mut obj = json!({
             "repo": status.repo_path,
@@ -348,12 +447,69 @@ fn symbol_brief(
     if let Some(sig) = &s.signature {
         v["sig"] = json!(normalize_signature(sig, max_sig_len));
     }
-    if let Some(doc) = &s.doc_comment {
-        v["doc"] = json!(truncate_doc(doc, max_doc_brief_len));
+    if let Some(doc) = s.doc.as_ref().filter(|d| !d.summary.is_empty()) {
+        v["doc"] = json!(truncate_doc(&doc.summary, max_doc_brief_len));
+    }
+    if !s.metadata.is_empty() {
+        v["meta"] = json!(meta_list(&s.metadata));
     }
     v
 }
 
+/// How a resolved reference found its target: `via` (file, import, module or repo),
+/// `conf` (high, medium or low) and, when the name matched several symbols, `cands`.
+fn resolution_info(v: &mut Value, r: &RefRecord) {
+    if let Some(via) = &r.resolution {
+        v["via"] = json!(via);
+    }
+    if let Some(conf) = &r.confidence {
+        v["conf"] = json!(conf);
+    }
+    if let Some(candidates) = r.candidates.filter(|&n| n > 1) {
+        v["cands"] = json!(candidates);
+    }
+}
+
+/// A doc comment's parts under their own keys: `doc` (summary), `desc`, `params`,
+/// `ret` and `throws`, each entry `{"n": name, "d": description}`.
+fn doc_detail(obj: &mut Value, doc: &DocComment) {
+    let entries = |entries: &[DocEntry]| {
+        entries
+            .iter()
+            .map(|e| {
+                let mut v = json!({});
+                if !e.name.is_empty() {
+                    v["n"] = json!(e.name);
+                }
+                if !e.description.is_empty() {
+                    v["d"] = json!(e.description);
+                }
+                v
+            })
+            .collect::<Vec<_>>()
+    };
+    if !doc.summary.is_empty() {
+        obj["doc"] = json!(doc.summary);
+    }
+    if let Some(desc) = &doc.description {
+        obj["desc"] = json!(desc);
+    }
+    if !doc.params.is_empty() {
+        obj["params"] = json!(entries(&doc.params));
+    }
+    if let Some(returns) = doc.returns.as_ref().filter(|r| !r.is_empty()) {
+        obj["ret"] = json!(returns);
+    }
+    if !doc.throws.is_empty() {
+        obj["throws"] = json!(entries(&doc.throws));
+    }
+}
+
+/// Metadata as `key=value` strings, the same form `find_symbols` filters take.
+fn meta_list(metadata: &[(String, String)]) -> Vec<String> {
+    metadata.iter().map(|(k, v)| format!("{k}={v}")).collect()
+}
+
 // ── Path deduplication ──
 
 struct PathIndex {
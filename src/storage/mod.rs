@@ -1,11 +1,15 @@
+mod resolve;
 pub mod tokenizer;
 
 use anyhow::{Context, Result};
 use rusqlite::{Connection, params};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
+use crate::indexer::doc::DocComment;
 use crate::indexer::{ExtractedRef, ExtractedSymbol};
 
+pub use self::resolve::{Confidence, Resolution};
 use self::tokenizer::split_code_identifier;
 
 const SCHEMA: &str = include_str!("schema.sql");
@@ -27,11 +31,67 @@ pub struct SymbolRecord {
     pub name: String,
     pub kind: String,
     pub signature: Option<String>,
+    #[allow(dead_code)] // Used by integration tests; output reads `doc`
     pub doc_comment: Option<String>,
+    /// The doc comment split into summary, parameters and so on, as parsed at
+    /// index time
+    pub doc: Option<DocComment>,
     pub start_line: i64,
     pub end_line: i64,
     pub file_rel_path: String,
     pub parent_symbol_id: Option<i64>,
+    /// `key`/`value` pairs such as `vis`/`pub`; only loaded by the queries
+    /// that return it (file symbols, symbol detail, metadata search)
+    pub metadata: Vec<(String, String)>,
+}
+
+/// A `key` or `key=value` condition on symbol metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetaFilter {
+    pub key: String,
+    pub value: Option<String>,
+}
+
+impl MetaFilter {
+    pub fn parse(filter: &str) -> Self {
+        match filter.split_once('=') {
+            Some((key, value)) => Self {
+                key: key.trim().to_string(),
+                value: Some(value.trim().to_string()),
+            },
+            None => Self {
+                key: filter.trim().to_string(),
+                value: None,
+            },
+        }
+    }
+}
+
+/// Which references `get_uncertain_refs` lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Uncertainty {
+    /// Resolved with low confidence: the name fit several symbols equally well, or
+    /// was a guess across the repository
+    Ambiguous,
+    /// No indexed symbol matched, usually a library or builtin
+    Unresolved,
+}
+
+impl Uncertainty {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "ambiguous" => Some(Uncertainty::Ambiguous),
+            "unresolved" => Some(Uncertainty::Unresolved),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Uncertainty::Ambiguous => "ambiguous",
+            Uncertainty::Unresolved => "unresolved",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -43,6 +103,12 @@ pub struct RefRecord {
     pub to_name: String,
     pub ref_kind: String,
     pub line: Option<i64>,
+    /// How the reference was resolved (`Resolution::as_str`); `None` while unresolved
+    pub resolution: Option<String>,
+    /// How sure the resolution is (`Confidence::as_str`)
+    pub confidence: Option<String>,
+    /// How many symbols the name could have meant
+    pub candidates: Option<i64>,
 }
 
 impl RefRecord {
@@ -55,6 +121,9 @@ impl RefRecord {
             to_name: row.get(4)?,
             ref_kind: row.get(5)?,
             line: row.get(6)?,
+            resolution: row.get(7)?,
+            confidence: row.get(8)?,
+            candidates: row.get(9)?,
         })
     }
 }
@@ -121,10 +190,14 @@ impl SymbolRecord {
             kind: row.get(2)?,
             signature: row.get(3)?,
             doc_comment: row.get(4)?,
+            doc: row
+                .get::<_, Option<String>>(9)?
+                .and_then(|json| serde_json::from_str(&json).ok()),
             start_line: row.get(5)?,
             end_line: row.get(6)?,
             file_rel_path: row.get(7)?,
             parent_symbol_id: row.get(8)?,
+            metadata: Vec::new(),
         })
     }
 }
@@ -366,7 +439,68 @@ impl SqliteStorage {
 
             // Set schema version
             self.conn.execute(
-                "INSERT OR REPLACE INTO metadata (key, value) VALUES ('schema_version', '2')",
+                "INSERT OR REPLACE INTO metadata (key, value) VALUES ('schema_version', '6')",
+                [],
+            )?;
+        } else if !self.has_table("symbol_meta") {
+            // Schema v2 DB: add the metadata table and forget file hashes so the
+            // next index run re-extracts every file and fills it in
+            self.conn
+                .execute_batch(SCHEMA)
+                .context("Failed to add symbol_meta table")?;
+            self.conn
+                .execute("UPDATE files SET content_hash = ''", [])?;
+            self.conn.execute(
+                "INSERT OR REPLACE INTO metadata (key, value) VALUES ('schema_version', '6')",
+                [],
+            )?;
+        }
+
+        if !self.has_column("refs", "resolution") {
+            // Schema v3 DB: refs were resolved by name alone, so re-index everything
+            // to resolve them by scope
+            self.conn
+                .execute_batch("ALTER TABLE refs ADD COLUMN resolution TEXT")
+                .context("Failed to add refs.resolution column")?;
+            self.conn
+                .execute("UPDATE files SET content_hash = ''", [])?;
+        }
+        if !self.has_column("symbols", "doc_parts") {
+            // Schema v5 DB: re-index everything to store each doc comment's parts
+            self.conn
+                .execute_batch("ALTER TABLE symbols ADD COLUMN doc_parts TEXT")
+                .context("Failed to add symbols.doc_parts column")?;
+            self.conn
+                .execute("UPDATE files SET content_hash = ''", [])?;
+            self.conn.execute(
+                "INSERT OR REPLACE INTO metadata (key, value) VALUES ('schema_version', '6')",
+                [],
+            )?;
+        }
+        if !self.has_column("refs", "confidence") {
+            // Schema v4 DB: re-index everything to record how sure each resolution is
+            self.conn
+                .execute_batch(
+                    "ALTER TABLE refs ADD COLUMN confidence TEXT;
+                     ALTER TABLE refs ADD COLUMN candidates INTEGER;",
+                )
+                .context("Failed to add refs.confidence and refs.candidates columns")?;
+            self.conn
+                .execute("UPDATE files SET content_hash = ''", [])?;
+            self.conn.execute(
+                "INSERT OR REPLACE INTO metadata (key, value) VALUES ('schema_version', '6')",
+                [],
+            )?;
+        }
+
+        if self.schema_version() < 7 {
+            // Schema v6 DB: headers were recorded as C though always parsed as C++
+            self.conn.execute(
+                "UPDATE files SET language = 'cpp' WHERE language = 'c' AND rel_path LIKE '%.h'",
+                [],
+            )?;
+            self.conn.execute(
+                "INSERT OR REPLACE INTO metadata (key, value) VALUES ('schema_version', '7')",
                 [],
             )?;
         }
@@ -385,6 +519,18 @@ impl SqliteStorage {
             .unwrap_or(false)
     }
 
+    fn schema_version(&self) -> i64 {
+        self.conn
+            .query_row(
+                "SELECT value FROM metadata WHERE key = 'schema_version'",
+                [],
+                |row| row.get::<_, String>(0),
+            )
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0)
+    }
+
     fn has_table(&self, table: &str) -> bool {
         self.conn
             .query_row(
@@ -490,7 +636,8 @@ impl SqliteStorage {
         Ok(())
     }
 
-    /// Delete files by their relative paths within a repo. Returns how many were deleted.
+    /// Delete files by their relative paths within a repo, then re-rank the refs that
+    /// named their symbols. Returns how many were deleted.
     pub fn delete_files_by_rel_paths(
         &self,
         repo_path: &str,
@@ -506,12 +653,24 @@ impl SqliteStorage {
             .context("Repository not indexed")?;
 
         let mut deleted = 0;
+        let mut removed_names = HashSet::new();
         for rel_path in rel_paths {
-            let count = self.conn.execute(
-                "DELETE FROM files WHERE repo_id = ?1 AND rel_path = ?2",
-                params![repo_id, rel_path],
-            )?;
-            deleted += count;
+            let file_id = self
+                .conn
+                .query_row(
+                    "SELECT id FROM files WHERE repo_id = ?1 AND rel_path = ?2",
+                    params![repo_id, rel_path],
+                    |row| row.get(0),
+                )
+                .ok();
+            if let Some(file_id) = file_id {
+                removed_names.extend(self.file_symbol_names(file_id)?);
+                self.delete_file(file_id)?;
+                deleted += 1;
+            }
+        }
+        if self.unresolve_refs_named(repo_id, &removed_names)? > 0 {
+            self.resolve_references(repo_id)?;
         }
         Ok(deleted)
     }
@@ -533,15 +692,21 @@ impl SqliteStorage {
         parent_id: Option<i64>,
     ) -> Result<i64> {
         let name_tokens = split_code_identifier(&sym.name);
+        let doc_parts = sym
+            .doc_comment
+            .as_deref()
+            .map(|doc| serde_json::to_string(&DocComment::parse(doc)))
+            .transpose()?;
         self.conn.execute(
-            "INSERT INTO symbols (file_id, name, kind, signature, doc_comment, start_line, end_line, parent_symbol_id, file_rel_path, repo_id, name_tokens)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            "INSERT INTO symbols (file_id, name, kind, signature, doc_comment, doc_parts, start_line, end_line, parent_symbol_id, file_rel_path, repo_id, name_tokens)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
             params![
                 file_id,
                 sym.name,
                 sym.kind.as_str(),
                 sym.signature,
                 sym.doc_comment,
+                doc_parts,
                 sym.start_line as i64,
                 sym.end_line as i64,
                 parent_id,
@@ -562,6 +727,14 @@ impl SqliteStorage {
         Ok(())
     }
 
+    pub fn insert_symbol_meta(&self, symbol_id: i64, key: &str, value: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO symbol_meta (symbol_id, key, value) VALUES (?1, ?2, ?3)",
+            params![symbol_id, key, value],
+        )?;
+        Ok(())
+    }
+
     /// Insert a symbol and all its children/references recursively
     pub fn insert_symbol_tree(
         &self,
@@ -575,6 +748,9 @@ impl SqliteStorage {
         for r in &sym.references {
             self.insert_ref(sym_id, r)?;
         }
+        for (key, value) in &sym.metadata {
+            self.insert_symbol_meta(sym_id, key, value)?;
+        }
         for child in &sym.children {
             self.insert_symbol_tree(file_id, repo_id, file_rel_path, child, Some(sym_id))?;
         }
@@ -583,60 +759,131 @@ impl SqliteStorage {
 
     // ── Reference resolution ──
 
+    /// Links pending refs to the symbols they name. Module-qualified imports and
+    /// document links go first, since they name one module or file; then every other reference by
+    /// scope; then declarations to their definitions. Document mentions that name
+    /// nothing are dropped.
     pub fn resolve_references(&self, repo_id: i64) -> Result<usize> {
-        // Pass 1: Exact name match — prefer definitions over re-exports/aliases
-        let updated = self.conn.execute(
-            "UPDATE refs SET to_symbol_id = (
-                SELECT s.id FROM symbols s
-                WHERE s.name = refs.to_name AND s.repo_id = ?1
-                ORDER BY CASE WHEN s.kind IN ('class','fn','interface','type','enum','struct','trait','method') THEN 0 ELSE 1 END
-                LIMIT 1
-             )
-             WHERE to_symbol_id IS NULL
-             AND from_symbol_id IN (SELECT id FROM symbols WHERE repo_id = ?1)",
-            params![repo_id],
+        let imports = self.resolve_module_imports(repo_id)? + self.resolve_doc_links(repo_id)?;
+        let scoped = self.resolve_by_scope(repo_id)?;
+        let definitions = self.resolve_definitions(repo_id)?;
+        self.drop_unresolved_mentions(repo_id)?;
+        Ok(imports + scoped + definitions)
+    }
+
+    /// A link from a document names a file (`src/foo.rs`), so it resolves to
+    /// that file's first top-level symbol, preferring declarations over synthetic
+    /// ones like `_imports`. Every top-level symbol of the file is a candidate.
+    fn resolve_doc_links(&self, repo_id: i64) -> Result<usize> {
+        let mut stmt = self.conn.prepare(
+            "SELECT r.id, s.id, COUNT(*) OVER (PARTITION BY r.id) FROM refs r
+             JOIN symbols d ON d.id = r.from_symbol_id
+             JOIN files f ON f.repo_id = d.repo_id AND f.rel_path = r.to_name
+             JOIN symbols s ON s.file_id = f.id AND s.parent_symbol_id IS NULL
+             WHERE r.to_symbol_id IS NULL
+             AND r.ref_kind = 'mention'
+             AND d.repo_id = ?1
+             ORDER BY r.id, s.name LIKE '\\_%' ESCAPE '\\', s.start_line, s.id",
         )?;
+        let rows: Vec<(i64, i64, usize)> = stmt
+            .query_map(params![repo_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        drop(stmt);
+
+        let mut update = self.conn.prepare(
+            "UPDATE refs SET to_symbol_id = ?1, resolution = ?2, confidence = ?3, candidates = ?4
+             WHERE id = ?5",
+        )?;
+        let mut updated = 0usize;
+        for link in rows.chunk_by(|a, b| a.0 == b.0) {
+            let (ref_id, sym_id, candidates) = link[0];
+            // The first symbol stands for the file, so it is never a tie
+            let confidence = Confidence::assess(Resolution::Import, candidates, 1);
+            update.execute(params![
+                sym_id,
+                Resolution::Import.as_str(),
+                confidence.as_str(),
+                candidates,
+                ref_id
+            ])?;
+            updated += 1;
+        }
+        Ok(updated)
+    }
 
-        // Pass 2: For `this.X` patterns only (single dot, `this.` prefix).
-        // These are almost always intra-class method calls with unambiguous resolution.
-        // We avoid resolving arbitrary dotted names (e.g., `response.json`) to prevent
-        // false positives with common last-segment names like get, set, map, filter.
+    /// Links declarations (C/C++ prototypes, `extern` variables) to their definitions.
+    /// The declaration's name is fully qualified (`geo::Circle::area`), while the definition
+    /// may be written with any trailing part of it (`Circle::area` inside `namespace geo`),
+    /// so the longest matching suffix wins, the closest to the declaration first. Other
+    /// declarations are never candidates.
+    fn resolve_definitions(&self, repo_id: i64) -> Result<usize> {
+        // Every trailing part of each pending name (`geo::Circle::area`, `Circle::area`,
+        // `area`) joined to the symbols of that name, longest part first
         let mut stmt = self.conn.prepare(
-            "SELECT id, to_name FROM refs
-             WHERE to_symbol_id IS NULL
-             AND to_name LIKE 'this.%'
-             AND INSTR(SUBSTR(to_name, 6), '.') = 0
-             AND from_symbol_id IN (SELECT id FROM symbols WHERE repo_id = ?1)",
+            "WITH RECURSIVE pending(ref_id, from_id, from_kind, from_path, suffix) AS (
+                SELECT r.id, r.from_symbol_id, s.kind, s.file_rel_path, r.to_name FROM refs r
+                JOIN symbols s ON s.id = r.from_symbol_id
+                WHERE r.to_symbol_id IS NULL
+                AND r.ref_kind = 'definition'
+                AND s.repo_id = ?1
+                UNION ALL
+                SELECT ref_id, from_id, from_kind, from_path, substr(suffix, instr(suffix, '::') + 2)
+                FROM pending WHERE instr(suffix, '::') > 0
+             )
+             SELECT p.ref_id, p.from_path, length(p.suffix), c.id, c.file_rel_path
+             FROM pending p
+             JOIN symbols c ON c.name = p.suffix
+             WHERE c.repo_id = ?1 AND c.id != p.from_id
+             AND CASE WHEN p.from_kind IN ('fn', 'method') THEN c.kind IN ('fn', 'method')
+                      ELSE c.kind IN ('var', 'const') END
+             AND NOT EXISTS (
+                 SELECT 1 FROM refs d
+                 WHERE d.from_symbol_id = c.id AND d.ref_kind = 'definition'
+             )
+             ORDER BY p.ref_id, length(p.suffix) DESC, c.id",
         )?;
-        let dotted_refs: Vec<(i64, String)> = stmt
-            .query_map(params![repo_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+        let rows: Vec<(i64, String, i64, i64, String)> = stmt
+            .query_map(params![repo_id], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
         drop(stmt);
 
-        let mut updated2 = 0usize;
-        for (ref_id, to_name) in &dotted_refs {
-            let method_name = match to_name.strip_prefix("this.") {
-                Some(m) => m,
-                None => continue,
-            };
-            let resolved: Option<i64> = self
-                .conn
-                .query_row(
-                    "SELECT id FROM symbols WHERE name = ?1 AND repo_id = ?2 LIMIT 1",
-                    params![method_name, repo_id],
-                    |row| row.get(0),
-                )
-                .ok();
-            if let Some(sym_id) = resolved {
-                self.conn.execute(
-                    "UPDATE refs SET to_symbol_id = ?1 WHERE id = ?2",
-                    params![sym_id, ref_id],
-                )?;
-                updated2 += 1;
-            }
+        let mut update = self.conn.prepare(
+            "UPDATE refs SET to_symbol_id = ?1, resolution = ?2, confidence = ?3, candidates = ?4
+             WHERE id = ?5",
+        )?;
+        let mut updated = 0usize;
+        for group in rows.chunk_by(|a, b| a.0 == b.0) {
+            let (ref_id, from_path, longest, _, _) = &group[0];
+            let mut ranked: Vec<(Resolution, i64)> = group
+                .iter()
+                .take_while(|(_, _, len, _, _)| len == longest)
+                .map(|(_, _, _, id, path)| (Resolution::by_location(from_path, path), *id))
+                .collect();
+            ranked.sort();
+            let (resolution, sym_id) = ranked[0];
+            let tied = ranked.iter().filter(|(r, _)| *r == resolution).count();
+            let confidence = Confidence::assess(resolution, ranked.len(), tied);
+            update.execute(params![
+                sym_id,
+                resolution.as_str(),
+                confidence.as_str(),
+                ranked.len(),
+                ref_id
+            ])?;
+            updated += 1;
         }
 
-        Ok(updated + updated2)
+        Ok(updated)
     }
 
     // ── Query operations ──
@@ -741,7 +988,7 @@ impl SqliteStorage {
 
         let placeholders: Vec<String> = (0..values.len()).map(|i| format!("?{}", i + 2)).collect();
         let sql = format!(
-            "SELECT id, name, kind, signature, doc_comment, start_line, end_line, file_rel_path, parent_symbol_id
+            "SELECT id, name, kind, signature, doc_comment, start_line, end_line, file_rel_path, parent_symbol_id, doc_parts
              FROM symbols WHERE repo_id = ?1 AND {column} IN ({})
              {order_by} LIMIT {limit}",
             placeholders.join(","),
@@ -759,25 +1006,102 @@ impl SqliteStorage {
 
     pub fn get_file_symbols(&self, repo_path: &str, file: &str) -> Result<Vec<SymbolRecord>> {
         let mut stmt = self.conn.prepare(
-            "SELECT s.id, s.name, s.kind, s.signature, s.doc_comment, s.start_line, s.end_line, s.file_rel_path, s.parent_symbol_id
+            "SELECT s.id, s.name, s.kind, s.signature, s.doc_comment, s.start_line, s.end_line, s.file_rel_path, s.parent_symbol_id, s.doc_parts
              FROM symbols s
              JOIN repositories r ON s.repo_id = r.id
              WHERE r.abs_path = ?1 AND s.file_rel_path = ?2
-             ORDER BY s.start_line",
+             ORDER BY s.start_line, s.id",
         )?;
         let rows = stmt.query_map(params![repo_path, file], SymbolRecord::from_row)?;
-        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+        let mut symbols = rows.collect::<Result<Vec<_>, _>>()?;
+        self.load_metadata(&mut symbols)?;
+        Ok(symbols)
     }
 
     pub fn get_symbol_detail(&self, repo_path: &str, symbol_id: i64) -> Result<SymbolRecord> {
-        self.conn.query_row(
-            "SELECT s.id, s.name, s.kind, s.signature, s.doc_comment, s.start_line, s.end_line, s.file_rel_path, s.parent_symbol_id
+        let mut sym = self.conn.query_row(
+            "SELECT s.id, s.name, s.kind, s.signature, s.doc_comment, s.start_line, s.end_line, s.file_rel_path, s.parent_symbol_id, s.doc_parts
              FROM symbols s
              JOIN repositories r ON s.repo_id = r.id
              WHERE r.abs_path = ?1 AND s.id = ?2",
             params![repo_path, symbol_id],
             SymbolRecord::from_row,
-        ).context("Symbol not found")
+        ).context("Symbol not found")?;
+        self.load_metadata(std::slice::from_mut(&mut sym))?;
+        Ok(sym)
+    }
+
+    /// Symbols matching every metadata filter, optionally narrowed to one kind
+    /// and to files under a path prefix.
+    pub fn find_symbols_by_meta(
+        &self,
+        repo_path: &str,
+        filters: &[MetaFilter],
+        kind: Option<&str>,
+        file_prefix: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SymbolRecord>> {
+        let mut sql = String::from(
+            "SELECT s.id, s.name, s.kind, s.signature, s.doc_comment, s.start_line, s.end_line, s.file_rel_path, s.parent_symbol_id, s.doc_parts
+             FROM symbols s
+             JOIN repositories r ON s.repo_id = r.id
+             WHERE r.abs_path = ?1",
+        );
+        let mut param_values: Vec<Box<dyn rusqlite::types::ToSql>> =
+            vec![Box::new(repo_path.to_string())];
+        for filter in filters {
+            param_values.push(Box::new(filter.key.clone()));
+            let key_param = param_values.len();
+            match &filter.value {
+                Some(value) => {
+                    param_values.push(Box::new(value.clone()));
+                    sql.push_str(&format!(
+                        " AND EXISTS (SELECT 1 FROM symbol_meta m WHERE m.symbol_id = s.id AND m.key = ?{key_param} AND m.value = ?{})",
+                        param_values.len()
+                    ));
+                }
+                None => sql.push_str(&format!(
+                    " AND EXISTS (SELECT 1 FROM symbol_meta m WHERE m.symbol_id = s.id AND m.key = ?{key_param})"
+                )),
+            }
+        }
+        if let Some(kind) = kind {
+            param_values.push(Box::new(kind.to_string()));
+            sql.push_str(&format!(" AND s.kind = ?{}", param_values.len()));
+        }
+        if let Some(prefix) = file_prefix {
+            let escaped = prefix
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            param_values.push(Box::new(format!("{escaped}%")));
+            sql.push_str(&format!(
+                " AND s.file_rel_path LIKE ?{} ESCAPE '\\'",
+                param_values.len()
+            ));
+        }
+        sql.push_str(&format!(
+            " ORDER BY s.file_rel_path, s.start_line LIMIT {limit}"
+        ));
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let params_ref: Vec<&dyn rusqlite::types::ToSql> =
+            param_values.iter().map(|p| p.as_ref()).collect();
+        let rows = stmt.query_map(params_ref.as_slice(), SymbolRecord::from_row)?;
+        let mut symbols = rows.collect::<Result<Vec<_>, _>>()?;
+        self.load_metadata(&mut symbols)?;
+        Ok(symbols)
+    }
+
+    fn load_metadata(&self, symbols: &mut [SymbolRecord]) -> Result<()> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT key, value FROM symbol_meta WHERE symbol_id = ?1 ORDER BY rowid",
+        )?;
+        for sym in symbols {
+            let rows = stmt.query_map(params![sym.id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            sym.metadata = rows.collect::<Result<Vec<_>, _>>()?;
+        }
+        Ok(())
     }
 
     pub fn search_symbols(
@@ -813,7 +1137,7 @@ impl SqliteStorage {
 
     pub fn get_references(&self, repo_path: &str, symbol_id: i64) -> Result<Vec<RefRecord>> {
         let mut stmt = self.conn.prepare(
-            "SELECT r.from_symbol_id, s.name, s.file_rel_path, r.to_symbol_id, r.to_name, r.ref_kind, r.line
+            "SELECT r.from_symbol_id, s.name, s.file_rel_path, r.to_symbol_id, r.to_name, r.ref_kind, r.line, r.resolution, r.confidence, r.candidates
              FROM refs r
              JOIN symbols s ON s.id = r.from_symbol_id
              JOIN repositories repo ON s.repo_id = repo.id
@@ -826,7 +1150,7 @@ impl SqliteStorage {
 
     pub fn get_dependencies(&self, repo_path: &str, symbol_id: i64) -> Result<Vec<RefRecord>> {
         let mut stmt = self.conn.prepare(
-            "SELECT r.from_symbol_id, NULL, NULL, r.to_symbol_id, r.to_name, r.ref_kind, r.line
+            "SELECT r.from_symbol_id, NULL, NULL, r.to_symbol_id, r.to_name, r.ref_kind, r.line, r.resolution, r.confidence, r.candidates
              FROM refs r
              JOIN symbols s ON s.id = r.from_symbol_id
              JOIN repositories repo ON s.repo_id = repo.id
@@ -837,6 +1161,43 @@ impl SqliteStorage {
         Ok(rows.collect::<Result<Vec<_>, _>>()?)
     }
 
+    /// References that are unresolved or resolved with low confidence, from a symbol
+    /// and the symbols inside it, from every symbol in a file, or both. `only` keeps
+    /// one of the two.
+    pub fn get_uncertain_refs(
+        &self,
+        repo_path: &str,
+        symbol_id: Option<i64>,
+        file: Option<&str>,
+        only: Option<Uncertainty>,
+    ) -> Result<Vec<RefRecord>> {
+        let mut stmt = self.conn.prepare(
+            "WITH RECURSIVE scope(id) AS (
+                SELECT id FROM symbols WHERE id = ?2
+                UNION ALL
+                SELECT s.id FROM symbols s JOIN scope ON s.parent_symbol_id = scope.id
+             )
+             SELECT r.from_symbol_id, s.name, s.file_rel_path, r.to_symbol_id, r.to_name, r.ref_kind, r.line, r.resolution, r.confidence, r.candidates
+             FROM refs r
+             JOIN symbols s ON s.id = r.from_symbol_id
+             JOIN repositories repo ON s.repo_id = repo.id
+             WHERE repo.abs_path = ?1
+             AND (s.id IN (SELECT id FROM scope) OR s.file_rel_path = ?3)
+             AND CASE ?4
+                WHEN 'ambiguous' THEN r.to_symbol_id IS NOT NULL AND r.confidence = 'low'
+                WHEN 'unresolved' THEN r.to_symbol_id IS NULL
+                ELSE r.to_symbol_id IS NULL OR r.confidence = 'low'
+             END
+             ORDER BY s.file_rel_path, r.line, r.to_name",
+        )?;
+        let only = only.as_ref().map(Uncertainty::as_str);
+        let rows = stmt.query_map(
+            params![repo_path, symbol_id, file, only],
+            RefRecord::from_row,
+        )?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
     pub fn get_index_status(&self, repo_path: &str) -> Result<IndexStatus> {
         let (repo_id, indexed_at): (i64, Option<String>) = self
             .conn
@@ -0,0 +1,730 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::Result;
+use rusqlite::params;
+
+use super::SqliteStorage;
+use crate::indexer::languages::module_import_path;
+
+/// How a reference was bound to its target, from the narrowest scope to the widest.
+/// Stored in `refs.resolution`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Resolution {
+    /// Defined in the referencing file
+    File,
+    /// Named by an import of the referencing file, or a link to the target's file
+    Import,
+    /// Defined in the same directory (module or package)
+    Module,
+    /// The only or best match anywhere else in the repository
+    Repo,
+}
+
+impl Resolution {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Resolution::File => "file",
+            Resolution::Import => "import",
+            Resolution::Module => "module",
+            Resolution::Repo => "repo",
+        }
+    }
+
+    /// The scope of a target found by name alone, without looking at imports.
+    pub(super) fn by_location(from_path: &str, to_path: &str) -> Self {
+        if from_path == to_path {
+            Resolution::File
+        } else if directory(from_path) == directory(to_path) {
+            Resolution::Module
+        } else {
+            Resolution::Repo
+        }
+    }
+}
+
+/// How sure a resolution is, given how many symbols the name could mean. Stored in
+/// `refs.confidence`, next to that number in `refs.candidates`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Confidence {
+    /// The only symbol with the name, or the only one in the referencing file or
+    /// among its imports
+    High,
+    /// The only one in the same directory, or the only definition among aliases
+    /// and re-exports; other symbols elsewhere share the name
+    Medium,
+    /// One of several equally likely symbols, or a guess across the repository
+    Low,
+}
+
+impl Confidence {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Confidence::High => "high",
+            Confidence::Medium => "medium",
+            Confidence::Low => "low",
+        }
+    }
+
+    /// `candidates` symbols matched the name; `tied` of them were as good a match
+    /// as the one picked.
+    pub(super) fn assess(resolution: Resolution, candidates: usize, tied: usize) -> Self {
+        match resolution {
+            _ if tied > 1 => Confidence::Low,
+            _ if candidates <= 1 => Confidence::High,
+            Resolution::File | Resolution::Import => Confidence::High,
+            Resolution::Module => Confidence::Medium,
+            Resolution::Repo => Confidence::Low,
+        }
+    }
+}
+
+/// Kinds that define a name rather than re-export or alias it.
+const DEFINITION_KINDS: &[&str] = &[
+    "class",
+    "fn",
+    "interface",
+    "type",
+    "enum",
+    "struct",
+    "trait",
+    "method",
+];
+
+/// Kinds that name data or prose rather than code (JSON and YAML keys, Markdown
+/// headings and code blocks). Only document mentions bind to them.
+const NON_CODE_KINDS: &[&str] = &["key", "section", "code"];
+
+/// Rust path segments naming a module by its position (`crate::a::B`,
+/// `super::B`) rather than by name, so they match any candidate.
+const RELATIVE_MODULES: &[&str] = &["crate", "super"];
+
+/// Extensions dropped from include and require paths (`shapes.h`, `lib/util.rb`)
+/// so they compare with file stems.
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "h", "hh", "hpp", "hxx", "c", "cc", "cpp", "rb", "py", "js", "mjs", "ts", "tsx", "php", "rs",
+    "go", "proto",
+];
+
+struct Candidate {
+    id: i64,
+    kind: String,
+    parent_id: Option<i64>,
+    parent_name: Option<String>,
+    rel_path: String,
+    family: String,
+    /// A declaration that points at its implementation (C/C++ prototypes)
+    prototype: bool,
+}
+
+struct Pending {
+    ref_id: i64,
+    to_name: String,
+    ref_kind: String,
+    from_id: i64,
+    from_parent: Option<i64>,
+    file_id: i64,
+    rel_path: String,
+    family: String,
+}
+
+struct Import {
+    path: String,
+    target: Option<i64>,
+}
+
+/// What a module-qualified import resolved to: the symbol, the file it is in, how
+/// many modules the import's path matched, and how many of those were as close as
+/// the one picked.
+struct ImportTarget {
+    id: i64,
+    rel_path: String,
+    candidates: usize,
+    tied: usize,
+}
+
+/// What a qualified reference says about its target's scope.
+enum Qualifier<'a> {
+    None,
+    /// `this.x`, `self.x`: a member of the enclosing type
+    Receiver,
+    /// `Point::new`, `geo.area`: a member of a type or module named so
+    Named(&'a str),
+}
+
+impl SqliteStorage {
+    /// Imports in languages that qualify them by module (see `module_import_path`)
+    /// resolve to that module's definition of the name rather than to any symbol
+    /// sharing it. A module that only imports the name (a Python package's
+    /// `__init__.py` re-exporting it) is followed to where it is defined. Re-exports
+    /// under an alias (`from .m import X as Y`) aren't followed.
+    pub(super) fn resolve_module_imports(&self, repo_id: i64) -> Result<usize> {
+        let mut stmt = self.conn.prepare(
+            "SELECT r.id, r.to_name, s.file_rel_path, f.language FROM refs r
+             JOIN symbols s ON s.id = r.from_symbol_id
+             JOIN files f ON f.id = s.file_id
+             WHERE r.to_symbol_id IS NULL
+             AND r.ref_kind = 'import'
+             AND s.repo_id = ?1",
+        )?;
+        let pending: Vec<(i64, String, String, String)> = stmt
+            .query_map(params![repo_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        drop(stmt);
+
+        let mut updated = 0usize;
+        for (ref_id, to_name, rel_path, language) in &pending {
+            if module_import_path(language, rel_path, to_name).is_none() {
+                continue;
+            }
+            let family = language_family(language);
+            if let Some(target) =
+                self.resolve_module_import(repo_id, family, rel_path, to_name, 0)?
+            {
+                let confidence =
+                    Confidence::assess(Resolution::Import, target.candidates, target.tied);
+                self.conn.execute(
+                    "UPDATE refs SET to_symbol_id = ?1, resolution = ?2, confidence = ?3, candidates = ?4
+                     WHERE id = ?5",
+                    params![
+                        target.id,
+                        Resolution::Import.as_str(),
+                        confidence.as_str(),
+                        target.candidates,
+                        ref_id
+                    ],
+                )?;
+                updated += 1;
+            }
+        }
+        Ok(updated)
+    }
+
+    fn resolve_module_import(
+        &self,
+        repo_id: i64,
+        family: &str,
+        rel_path: &str,
+        import: &str,
+        depth: usize,
+    ) -> Result<Option<ImportTarget>> {
+        // Re-export chains are short; the limit only guards against import cycles
+        if depth > 8 {
+            return Ok(None);
+        }
+        let language = self.file_language(repo_id, rel_path)?;
+        let Some(path) = module_import_path(&language, rel_path, import) else {
+            return Ok(None);
+        };
+        if let Some(module) = path.strip_suffix(".*") {
+            return self.module_symbol(repo_id, family, module);
+        }
+        // `import pkg.sub` and `from pkg import sub` name a module
+        if let Some(module) = self.module_symbol(repo_id, family, &path)? {
+            return Ok(Some(module));
+        }
+        let Some((module, name)) = path.rsplit_once('.') else {
+            return Ok(None);
+        };
+        let Some(module) = self.module_symbol(repo_id, family, module)? else {
+            return Ok(None);
+        };
+        let (module_id, module_file) = (module.id, &module.rel_path);
+
+        let defined = self
+            .conn
+            .query_row(
+                "SELECT s.id FROM symbols s
+                 WHERE s.file_id = (SELECT file_id FROM symbols WHERE id = ?1)
+                 AND s.parent_symbol_id IS NULL AND s.kind != 'mod' AND s.name = ?2
+                 LIMIT 1",
+                params![module_id, name],
+                |row| row.get::<_, i64>(0),
+            )
+            .ok();
+        if let Some(id) = defined {
+            return Ok(Some(ImportTarget { id, ..module }));
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT r.to_name FROM refs r
+             JOIN symbols s ON s.id = r.from_symbol_id
+             WHERE s.file_id = (SELECT file_id FROM symbols WHERE id = ?1)
+             AND s.name = '_imports' AND r.ref_kind = 'import'
+             AND (r.to_name = ?2 OR SUBSTR(r.to_name, -LENGTH(?2) - 1) = '.' || ?2)",
+        )?;
+        let reexports: Vec<String> = stmt
+            .query_map(params![module_id, name], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        drop(stmt);
+        for reexport in &reexports {
+            if let Some(target) =
+                self.resolve_module_import(repo_id, family, module_file, reexport, depth + 1)?
+            {
+                return Ok(Some(target));
+            }
+        }
+        Ok(None)
+    }
+
+    fn file_language(&self, repo_id: i64, rel_path: &str) -> Result<String> {
+        Ok(self.conn.query_row(
+            "SELECT language FROM files WHERE repo_id = ?1 AND rel_path = ?2",
+            params![repo_id, rel_path],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// The module symbol for a dotted module path, among files of the importing
+    /// language family. Absolute imports may omit leading directories
+    /// (`src/pkg/sub.py` is `pkg.sub` in a src layout), so a trailing match counts,
+    /// the closest first. Every module that matches is a candidate.
+    fn module_symbol(
+        &self,
+        repo_id: i64,
+        family: &str,
+        module: &str,
+    ) -> Result<Option<ImportTarget>> {
+        if module.is_empty() {
+            return Ok(None);
+        }
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT s.id, s.file_rel_path, LENGTH(s.name), f.language FROM symbols s
+             JOIN files f ON f.id = s.file_id
+             WHERE s.repo_id = ?1
+             AND s.kind = 'mod' AND s.parent_symbol_id IS NULL AND s.name != '_imports'
+             AND (s.name = ?2 OR SUBSTR(s.name, -LENGTH(?2) - 1) = '.' || ?2)
+             ORDER BY LENGTH(s.name), s.id",
+        )?;
+        let rows: Vec<(i64, String, usize, String)> = stmt
+            .query_map(params![repo_id, module], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let modules: Vec<_> = rows
+            .into_iter()
+            .filter(|(.., language)| language_family(language) == family)
+            .collect();
+        let Some((id, rel_path, closest, _)) = modules.first().cloned() else {
+            return Ok(None);
+        };
+        Ok(Some(ImportTarget {
+            id,
+            rel_path,
+            candidates: modules.len(),
+            tied: modules.iter().filter(|m| m.2 == closest).count(),
+        }))
+    }
+
+    /// Binds every unresolved reference (other than prototype definitions) to the
+    /// symbol its name most likely means, looking in the referencing file first, then
+    /// at what the file imports, then its directory, then the whole repository.
+    /// Within a scope, definitions win over re-exports and aliases, and bodies over
+    /// prototypes. Qualified names (`Point::new`, `this.save`) only bind to members of
+    /// a matching type or module. Records how many symbols matched and how sure the
+    /// pick is.
+    pub(super) fn resolve_by_scope(&self, repo_id: i64) -> Result<usize> {
+        let candidates = self.resolution_candidates(repo_id)?;
+        let imports = self.file_imports(repo_id)?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT r.id, r.to_name, r.ref_kind, s.id, s.parent_symbol_id, s.file_id, s.file_rel_path, f.language
+             FROM refs r
+             JOIN symbols s ON s.id = r.from_symbol_id
+             JOIN files f ON f.id = s.file_id
+             WHERE r.to_symbol_id IS NULL
+             AND r.ref_kind != 'definition'
+             AND s.repo_id = ?1",
+        )?;
+        let pending: Vec<Pending> = stmt
+            .query_map(params![repo_id], |row| {
+                Ok(Pending {
+                    ref_id: row.get(0)?,
+                    to_name: row.get(1)?,
+                    ref_kind: row.get(2)?,
+                    from_id: row.get(3)?,
+                    from_parent: row.get(4)?,
+                    file_id: row.get(5)?,
+                    rel_path: row.get(6)?,
+                    family: language_family(&row.get::<_, String>(7)?).to_string(),
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        drop(stmt);
+
+        let mut update = self.conn.prepare(
+            "UPDATE refs SET to_symbol_id = ?1, resolution = ?2, confidence = ?3, candidates = ?4
+             WHERE id = ?5",
+        )?;
+        let no_imports = Vec::new();
+        let mut updated = 0usize;
+        for p in &pending {
+            let (name, qualifier) = lookup_name(&p.to_name, &candidates);
+            let Some(named) = candidates.get(name) else {
+                continue;
+            };
+            let file_imports = imports.get(&p.file_id).unwrap_or(&no_imports);
+            let mention = p.ref_kind == "mention";
+            let mut ranked: Vec<_> = named
+                .iter()
+                // Code refers to code in its own language; documents mention anything
+                .filter(|c| mention || c.family == p.family)
+                .filter(|c| mention || !NON_CODE_KINDS.contains(&c.kind.as_str()))
+                .filter(|c| match qualifier {
+                    Qualifier::Named(q) => is_member_of(c, q),
+                    _ => true,
+                })
+                // A re-export (`export { X } from "./x"`) isn't what it imports
+                .filter(|c| p.ref_kind != "import" || c.id != p.from_id)
+                .map(|c| {
+                    let scope = scope_of(c, p, name, file_imports);
+                    let same_parent = c.parent_id.is_some() && c.parent_id == p.from_parent;
+                    let rank = (
+                        scope,
+                        !same_parent,
+                        !DEFINITION_KINDS.contains(&c.kind.as_str()),
+                        c.prototype,
+                    );
+                    (rank, c.id)
+                })
+                .collect();
+            ranked.sort();
+            let Some(&(best, id)) = ranked.first() else {
+                continue;
+            };
+            let tied = ranked.iter().take_while(|(rank, _)| *rank == best).count();
+            let confidence = Confidence::assess(best.0, ranked.len(), tied);
+            update.execute(params![
+                id,
+                best.0.as_str(),
+                confidence.as_str(),
+                ranked.len(),
+                p.ref_id
+            ])?;
+            updated += 1;
+        }
+        Ok(updated)
+    }
+
+    /// Deletes the mentions that matched no symbol or file: a backticked word in a
+    /// document (`npm`, `true`, `config.yaml`) only refers to something when the
+    /// repository has it.
+    pub(super) fn drop_unresolved_mentions(&self, repo_id: i64) -> Result<usize> {
+        Ok(self.conn.execute(
+            "DELETE FROM refs
+             WHERE ref_kind = 'mention' AND to_symbol_id IS NULL
+             AND from_symbol_id IN (SELECT id FROM symbols WHERE repo_id = ?1)",
+            params![repo_id],
+        )?)
+    }
+
+    fn resolution_candidates(&self, repo_id: i64) -> Result<HashMap<String, Vec<Candidate>>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT s.id, s.name, s.kind, s.parent_symbol_id, p.name, s.file_rel_path,
+                    EXISTS (SELECT 1 FROM refs d WHERE d.from_symbol_id = s.id AND d.ref_kind = 'definition'),
+                    f.language
+             FROM symbols s
+             JOIN files f ON f.id = s.file_id
+             LEFT JOIN symbols p ON p.id = s.parent_symbol_id
+             WHERE s.repo_id = ?1",
+        )?;
+        let mut rows = stmt.query(params![repo_id])?;
+        let mut candidates: HashMap<String, Vec<Candidate>> = HashMap::new();
+        while let Some(row) = rows.next()? {
+            candidates.entry(row.get(1)?).or_default().push(Candidate {
+                id: row.get(0)?,
+                kind: row.get(2)?,
+                parent_id: row.get(3)?,
+                parent_name: row.get(4)?,
+                rel_path: row.get(5)?,
+                family: language_family(&row.get::<_, String>(7)?).to_string(),
+                prototype: row.get(6)?,
+            });
+        }
+        Ok(candidates)
+    }
+
+    /// Import refs by the file they appear in, with their targets when
+    /// `resolve_module_imports` already found them.
+    fn file_imports(&self, repo_id: i64) -> Result<HashMap<i64, Vec<Import>>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT s.file_id, r.to_name, r.to_symbol_id FROM refs r
+             JOIN symbols s ON s.id = r.from_symbol_id
+             WHERE r.ref_kind = 'import' AND s.repo_id = ?1",
+        )?;
+        let mut rows = stmt.query(params![repo_id])?;
+        let mut imports: HashMap<i64, Vec<Import>> = HashMap::new();
+        while let Some(row) = rows.next()? {
+            imports.entry(row.get(0)?).or_default().push(Import {
+                path: row.get(1)?,
+                target: row.get(2)?,
+            });
+        }
+        Ok(imports)
+    }
+
+    /// Names of the symbols in a file, to tell which names an update adds or removes.
+    pub fn file_symbol_names(&self, file_id: i64) -> Result<HashSet<String>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT DISTINCT name FROM symbols WHERE file_id = ?1")?;
+        let rows = stmt.query_map(params![file_id], |row| row.get(0))?;
+        Ok(rows.collect::<std::result::Result<_, _>>()?)
+    }
+
+    /// Forgets how refs naming any of `names` were resolved, so the next
+    /// `resolve_references` ranks them again: a symbol added or removed under the
+    /// name can change which one a ref means, or how many it could mean. Qualified
+    /// names (`geo::Point`, `pkg.Point`) match by their last part.
+    pub fn unresolve_refs_named(&self, repo_id: i64, names: &HashSet<String>) -> Result<usize> {
+        if names.is_empty() {
+            return Ok(0);
+        }
+        let mut stmt = self.conn.prepare(
+            "SELECT r.id, r.to_name FROM refs r
+             JOIN symbols s ON s.id = r.from_symbol_id
+             WHERE r.resolution IS NOT NULL AND s.repo_id = ?1",
+        )?;
+        let resolved: Vec<(i64, String)> = stmt
+            .query_map(params![repo_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<std::result::Result<_, _>>()?;
+        drop(stmt);
+        let stale: Vec<i64> = resolved
+            .into_iter()
+            .filter(|(_, to_name)| names_ref(to_name, names))
+            .map(|(id, _)| id)
+            .collect();
+
+        let mut update = self.conn.prepare_cached(
+            "UPDATE refs SET to_symbol_id = NULL, resolution = NULL, confidence = NULL, candidates = NULL
+             WHERE id = ?1",
+        )?;
+        for id in &stale {
+            update.execute(params![id])?;
+        }
+        Ok(stale.len())
+    }
+}
+
+/// Whether a ref's name, or the last part of a qualified one, is one of `names`.
+fn names_ref(to_name: &str, names: &HashSet<String>) -> bool {
+    let last = to_name.rsplit("::").next().unwrap_or(to_name);
+    let last = last.rsplit('.').next().unwrap_or(last);
+    names.contains(to_name) || names.contains(last)
+}
+
+/// Languages whose symbols refer to each other: components import TypeScript,
+/// notebooks import Python modules, C++ calls C, Kotlin calls Java, and OpenAPI or
+/// config files are JSON, YAML or TOML alike. Any other language only sees itself.
+fn language_family(language: &str) -> &str {
+    match language {
+        "typescript" | "vue" | "svelte" => "javascript",
+        "python" | "notebook" => "python",
+        "c" | "cpp" => "c",
+        "java" | "kotlin" => "jvm",
+        "json" | "yaml" | "toml" => "data",
+        other => other,
+    }
+}
+
+/// The symbol name to look up for a reference, and what its qualifier requires of
+/// the target. Names are tried as written first, since some symbols are qualified
+/// themselves (C++ `Shape::area`, Python modules).
+fn lookup_name<'a, T>(
+    to_name: &'a str,
+    candidates: &HashMap<String, T>,
+) -> (&'a str, Qualifier<'a>) {
+    if candidates.contains_key(to_name) {
+        return (to_name, Qualifier::None);
+    }
+    let split = to_name
+        .rsplit_once("::")
+        .or_else(|| to_name.rsplit_once('.'));
+    let Some((qualifier, name)) = split else {
+        return (to_name, Qualifier::None);
+    };
+    match qualifier {
+        "this" | "self" => (name, Qualifier::Receiver),
+        _ => match qualifier.rsplit(['.', ':']).next().unwrap_or(qualifier) {
+            last if RELATIVE_MODULES.contains(&last) => (name, Qualifier::None),
+            last => (name, Qualifier::Named(last)),
+        },
+    }
+}
+
+/// Whether a candidate is a member of the type or module `qualifier` names: its
+/// parent (`impl Point`, `impl Shape for Point`, `class Point`, `mod geo`), its
+/// file or its directory (`geo/mod.rs`).
+fn is_member_of(c: &Candidate, qualifier: &str) -> bool {
+    let parent_type = c.parent_name.as_deref().map(|parent| {
+        let parent = parent.rsplit(" for ").next().unwrap_or(parent);
+        parent.split('<').next().unwrap_or(parent).trim()
+    });
+    parent_type == Some(qualifier) || in_module(c, qualifier)
+}
+
+fn scope_of(c: &Candidate, p: &Pending, name: &str, imports: &[Import]) -> Resolution {
+    match Resolution::by_location(&p.rel_path, &c.rel_path) {
+        Resolution::File => Resolution::File,
+        _ if imports.iter().any(|i| imports_candidate(i, c, name)) => Resolution::Import,
+        scope => scope,
+    }
+}
+
+/// Whether an import brings in this candidate: by its resolved target, by naming it
+/// (`import { Point }`, `use geo::Point`, `import geo.Point`, as long as the path
+/// fits the candidate's location), or by importing the module, package or file the
+/// candidate is in (`use geo::*`, `import "geo"`, `#include "geo.h"`).
+fn imports_candidate(import: &Import, c: &Candidate, name: &str) -> bool {
+    if import.target == Some(c.id) {
+        return true;
+    }
+    // A module-qualified import already resolved to something else
+    if import.target.is_some() {
+        return false;
+    }
+    let path = import.path.trim_matches(['"', '\'', '<', '>', '`']);
+    let path = strip_source_extension(path);
+    let path = path
+        .strip_suffix("::*")
+        .or_else(|| path.strip_suffix(".*"))
+        .or_else(|| path.strip_suffix("\\*"))
+        .unwrap_or(path);
+    let (module, last) = match path.rsplit_once(['.', ':', '\\', '/']) {
+        Some((module, last)) => (module.trim_end_matches(':'), last),
+        None => ("", path),
+    };
+    if last == name {
+        return module.is_empty() || RELATIVE_MODULES.contains(&module) || in_module(c, module);
+    }
+    in_module(c, path)
+}
+
+/// Whether a candidate lives in the module a path names, by the path's last segment:
+/// the candidate's file stem, directory or parent.
+fn in_module(c: &Candidate, module: &str) -> bool {
+    let last = module
+        .rsplit(['.', ':', '\\', '/'])
+        .find(|s| !s.is_empty())
+        .unwrap_or(module);
+    let dir = directory(&c.rel_path);
+    let dir_name = dir.rsplit('/').next().unwrap_or(dir);
+    file_stem(&c.rel_path) == last || dir_name == last || c.parent_name.as_deref() == Some(last)
+}
+
+fn strip_source_extension(path: &str) -> &str {
+    match path.rsplit_once('.') {
+        Some((stem, ext)) if SOURCE_EXTENSIONS.contains(&ext) => stem,
+        _ => path,
+    }
+}
+
+fn file_stem(rel_path: &str) -> &str {
+    Path::new(rel_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(rel_path)
+}
+
+fn directory(rel_path: &str) -> &str {
+    rel_path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(rel_path: &str, parent_name: Option<&str>) -> Candidate {
+        Candidate {
+            id: 1,
+            kind: "fn".to_string(),
+            parent_id: None,
+            parent_name: parent_name.map(str::to_string),
+            rel_path: rel_path.to_string(),
+            family: "javascript".to_string(),
+            prototype: false,
+        }
+    }
+
+    fn import(path: &str) -> Import {
+        Import {
+            path: path.to_string(),
+            target: None,
+        }
+    }
+
+    #[test]
+    fn test_lookup_name_qualifiers() {
+        let candidates: HashMap<String, ()> =
+            [("new".to_string(), ()), ("Shape::area".to_string(), ())].into();
+        assert!(matches!(
+            lookup_name("Point::new", &candidates),
+            ("new", Qualifier::Named("Point"))
+        ));
+        assert!(matches!(
+            lookup_name("geo::Point::new", &candidates),
+            ("new", Qualifier::Named("Point"))
+        ));
+        assert!(matches!(
+            lookup_name("super::Point", &candidates),
+            ("Point", Qualifier::None)
+        ));
+        assert!(matches!(
+            lookup_name("this.save", &candidates),
+            ("save", Qualifier::Receiver)
+        ));
+        assert!(matches!(
+            lookup_name("Shape::area", &candidates),
+            ("Shape::area", Qualifier::None)
+        ));
+    }
+
+    #[test]
+    fn test_is_member_of_parent_or_file() {
+        assert!(is_member_of(&candidate("src/a.rs", Some("Point")), "Point"));
+        assert!(is_member_of(
+            &candidate("src/a.rs", Some("Default for Point<T>")),
+            "Point"
+        ));
+        assert!(is_member_of(&candidate("src/geo.rs", None), "geo"));
+        assert!(is_member_of(&candidate("src/geo/mod.rs", None), "geo"));
+        assert!(!is_member_of(&candidate("src/a.rs", Some("Line")), "Point"));
+    }
+
+    #[test]
+    fn test_language_families() {
+        assert_eq!(language_family("vue"), language_family("typescript"));
+        assert_eq!(language_family("notebook"), language_family("python"));
+        assert_eq!(language_family("yaml"), language_family("json"));
+        assert_ne!(language_family("json"), language_family("typescript"));
+        assert_ne!(language_family("dockerfile"), language_family("python"));
+    }
+
+    #[test]
+    fn test_imports_candidate_by_name_or_module() {
+        let point = candidate("src/geo/point.ts", None);
+        assert!(imports_candidate(&import("Point"), &point, "Point"));
+        assert!(imports_candidate(&import("com.geo.Point"), &point, "Point"));
+        assert!(!imports_candidate(
+            &import("com.other.Point"),
+            &point,
+            "Point"
+        ));
+        assert!(imports_candidate(&import("geo::*"), &point, "Point"));
+        assert!(imports_candidate(
+            &import("crate::geo::point::Point"),
+            &point,
+            "Point"
+        ));
+        assert!(!imports_candidate(
+            &import("crate::other::Point"),
+            &point,
+            "Point"
+        ));
+        assert!(imports_candidate(&import("super::Point"), &point, "Point"));
+        assert!(imports_candidate(&import("\"point.h\""), &point, "Point"));
+        assert!(!imports_candidate(&import("Line"), &point, "Point"));
+    }
+}
@@ -46,6 +46,7 @@ CREATE TABLE IF NOT EXISTS symbols (
     kind            TEXT    NOT NULL,
     signature       TEXT,
     doc_comment     TEXT,
+    doc_parts       TEXT,
     start_line      INTEGER NOT NULL,
     end_line        INTEGER NOT NULL,
     parent_symbol_id INTEGER REFERENCES symbols(id) ON DELETE SET NULL,
@@ -59,6 +60,18 @@ CREATE INDEX IF NOT EXISTS idx_symbols_name    ON symbols(name);
 CREATE INDEX IF NOT EXISTS idx_symbols_kind    ON symbols(repo_id, kind);
 CREATE INDEX IF NOT EXISTS idx_symbols_parent  ON symbols(parent_symbol_id);
 
+-- ============================================================
+-- SYMBOL METADATA (visibility, attributes, derives, ...)
+-- ============================================================
+CREATE TABLE IF NOT EXISTS symbol_meta (
+    symbol_id   INTEGER NOT NULL REFERENCES symbols(id) ON DELETE CASCADE,
+    key         TEXT    NOT NULL,
+    value       TEXT    NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_symbol_meta_symbol ON symbol_meta(symbol_id);
+CREATE INDEX IF NOT EXISTS idx_symbol_meta_kv     ON symbol_meta(key, value);
+
 -- ============================================================
 -- REFERENCES (edges between symbols)
 -- ============================================================
@@ -69,6 +82,9 @@ CREATE TABLE IF NOT EXISTS refs (
     to_name         TEXT    NOT NULL,
     ref_kind        TEXT    NOT NULL,
     line            INTEGER,
+    resolution      TEXT,   -- file, import, module or repo: where the target was found
+    confidence      TEXT,   -- high, medium or low
+    candidates      INTEGER,-- how many symbols the name matched
     UNIQUE(from_symbol_id, to_name, ref_kind, line)
 );
 
@@ -1,7 +1,7 @@
 /// Splits code identifiers into searchable subwords.
 ///
-/// Handles camelCase, PascalCase, snake_case, SCREAMING_SNAKE_CASE,
-/// and acronym boundaries (e.g., HTMLParser → html parser).
+/// Handles camelCase, PascalCase, snake_case, SCREAMING_SNAKE_CASE, `::`- and `\`-qualified
+/// names and acronym boundaries (e.g., HTMLParser → html parser).
 /// Returns lowercased space-separated subwords plus the original name lowercased.
 ///
 /// Example: `"getUserById"` → `"get user by id getuserbyid"`
@@ -15,7 +15,7 @@ pub fn split_code_identifier(name: &str) -> String {
     for i in 0..len {
         let c = chars[i];
 
-        if c == '_' || c == '-' || c == '.' || c == ' ' {
+        if c == '_' || c == '-' || c == '.' || c == ':' || c == '\\' || c == ' ' {
             if !current.is_empty() {
                 words.push(std::mem::take(&mut current));
             }
@@ -125,6 +125,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_qualified_name() {
+        assert_eq!(
+            split_code_identifier("Circle::area"),
+            "circle area circle::area"
+        );
+        assert_eq!(
+            split_code_identifier("App\\Models"),
+            "app models app\\models"
+        );
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(split_code_identifier(""), "");
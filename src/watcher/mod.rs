@@ -9,8 +9,8 @@ use ignore::gitignore::Gitignore;
 use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use tokio::sync::mpsc;
 
-use crate::config::ConfigCache;
-use crate::indexer::{self, Indexer};
+use crate::config::{ConfigCache, IndexerConfig};
+use crate::indexer::{self, Indexer, QueryPacks};
 use crate::storage::{self, SqliteStorage};
 use debouncer::{Debouncer, FileChangeKind};
 
@@ -77,10 +77,10 @@ impl WatcherHandle {
 struct RepoWatcher {
     /// Dropping this stops the OS-level watcher.
     _watcher: RecommendedWatcher,
-    /// Ignore patterns from the repo's config.
-    ignore_patterns: Vec<String>,
-    /// Max file size from the repo's config.
-    max_file_size: u64,
+    /// Indexer settings from the repo's config.
+    config: Arc<IndexerConfig>,
+    /// Query packs compiled from the repo's config, reused by every batch.
+    queries: Arc<QueryPacks>,
     /// Gitignore matcher for filtering watched files.
     gitignore: Option<Gitignore>,
 }
@@ -103,16 +103,17 @@ pub async fn start(indexer: Arc<Indexer>, config_cache: Arc<ConfigCache>) -> Wat
         }
 
         tracing::info!(path = %repo_path, "Reindexing on startup");
-        let config = config_cache.get(repo_path);
+        let config = Arc::new(config_cache.get(repo_path).indexer);
+        let queries = Arc::new(QueryPacks::load(Path::new(repo_path), &config.queries));
         let indexer_clone = indexer.clone();
         let path = repo_path.clone();
-        let ignore = config.indexer.ignore.clone();
-        let max_size = config.indexer.max_file_size;
+        let index_config = config.clone();
+        let index_queries = queries.clone();
 
         // Block on reindex so the index is fresh before any tool responds
         let result = tokio::task::spawn_blocking(move || {
             let storage = SqliteStorage::open(&path)?;
-            indexer_clone.index(&path, &storage, &ignore, max_size)
+            indexer_clone.index(&path, &storage, &index_config, &index_queries)
         })
         .await;
 
@@ -138,7 +139,7 @@ pub async fn start(indexer: Arc<Indexer>, config_cache: Arc<ConfigCache>) -> Wat
         }
 
         // Start watching this repo
-        if let Some(rw) = create_repo_watcher(repo_path, &config_cache, &fs_tx) {
+        if let Some(rw) = create_repo_watcher(repo_path, config, queries, &fs_tx) {
             initial_watchers.insert(repo_path.clone(), rw);
         }
     }
@@ -170,12 +171,10 @@ fn load_gitignore(repo_path: &str) -> Option<Gitignore> {
 
 fn create_repo_watcher(
     repo_path: &str,
-    config_cache: &ConfigCache,
+    config: Arc<IndexerConfig>,
+    queries: Arc<QueryPacks>,
     fs_tx: &mpsc::Sender<FsEvent>,
 ) -> Option<RepoWatcher> {
-    let config = config_cache.get(repo_path);
-    let ignore_patterns = config.indexer.ignore.clone();
-    let max_file_size = config.indexer.max_file_size;
     let gitignore = load_gitignore(repo_path);
     let repo_path_owned = repo_path.to_string();
     let repo_path_buf = PathBuf::from(repo_path);
@@ -253,8 +252,8 @@ fn create_repo_watcher(
             tracing::info!(path = %repo_path, "Watching for file changes");
             Some(RepoWatcher {
                 _watcher: w,
-                ignore_patterns,
-                max_file_size,
+                config,
+                queries,
                 gitignore,
             })
         }
@@ -286,7 +285,9 @@ async fn event_loop(
                 match cmd {
                     Some(WatcherCommand::Watch { repo_path }) => {
                         if !watchers.contains_key(&repo_path) {
-                            if let Some(rw) = create_repo_watcher(&repo_path, &config_cache, &fs_tx) {
+                            let config = Arc::new(config_cache.get(&repo_path).indexer);
+                            let queries = Arc::new(QueryPacks::load(Path::new(&repo_path), &config.queries));
+                            if let Some(rw) = create_repo_watcher(&repo_path, config, queries, &fs_tx) {
                                 watchers.insert(repo_path.clone(), rw);
                             }
                         }
@@ -307,7 +308,7 @@ async fn event_loop(
                 if let Some(fs_event) = event {
                     // Check repo-specific ignore patterns
                     if let Some(rw) = watchers.get(&fs_event.repo_path) {
-                        if indexer::matches_ignore_pattern(&fs_event.rel_path, &rw.ignore_patterns) {
+                        if indexer::matches_ignore_pattern(&fs_event.rel_path, &rw.config.ignore) {
                             continue;
                         }
                         if let Some(ref gi) = rw.gitignore {
@@ -360,8 +361,8 @@ async fn event_loop(
 
                         let indexer = indexer.clone();
                         let repo = repo_path.clone();
-                        let ignore = rw.ignore_patterns.clone();
-                        let max_size = rw.max_file_size;
+                        let config = rw.config.clone();
+                        let queries = rw.queries.clone();
 
                         tokio::task::spawn_blocking(move || {
                             let storage = match SqliteStorage::open(&repo) {
@@ -374,7 +375,7 @@ async fn event_loop(
 
                             // Handle modified files
                             if !modified.is_empty() {
-                                match indexer.update_files(&repo, &modified, &storage, &ignore, max_size) {
+                                match indexer.update_files(&repo, &modified, &storage, &config, &queries) {
                                     Ok(stats) => {
                                         tracing::info!(
                                             repo = %repo,
@@ -0,0 +1,23 @@
+#include "geometry.hpp"
+
+namespace geo {
+
+Circle::Circle(double radius) : radius_(radius) {}
+
+double Circle::area() const {
+    return 3.14159 * radius_ * radius_;
+}
+
+std::string Circle::describe() const {
+    return "circle of area " + std::to_string(this->area());
+}
+
+double total_area(const ShapeList &shapes) {
+    double total = 0;
+    for (const Figure *shape : shapes) {
+        total += shape->area();
+    }
+    return clamp(total, 0.0, 1e9);
+}
+
+} // namespace geo
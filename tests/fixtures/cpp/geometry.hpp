@@ -0,0 +1,35 @@
+#pragma once
+
+#include <string>
+#include <vector>
+
+namespace geo {
+
+/// Anything with an area.
+class Figure {
+public:
+    virtual ~Figure() = default;
+    virtual double area() const = 0;
+};
+
+/// A circle with a radius.
+class Circle : public Figure {
+public:
+    explicit Circle(double radius);
+    double area() const override;
+    std::string describe() const;
+
+private:
+    double radius_;
+};
+
+using ShapeList = std::vector<Figure *>;
+
+template <typename T>
+T clamp(T value, T lo, T hi) {
+    return value < lo ? lo : (value > hi ? hi : value);
+}
+
+double total_area(const ShapeList &shapes);
+
+} // namespace geo
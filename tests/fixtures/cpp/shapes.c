@@ -0,0 +1,20 @@
+#include <math.h>
+#include "shapes.h"
+
+int shape_count = 0;
+
+static double circle_area(double r) {
+    return 3.14159 * SQUARE(r);
+}
+
+/* Computes the area of a shape. */
+double shape_area(const Shape *shape) {
+    if (shape->kind == SHAPE_CIRCLE) {
+        return circle_area(shape->size);
+    }
+    return shape->size * shape->size;
+}
+
+double point_distance(Point a, Point b) {
+    return sqrt(SQUARE(a.x - b.x) + SQUARE(a.y - b.y));
+}
@@ -0,0 +1,38 @@
+#ifndef SHAPES_H
+#define SHAPES_H
+
+#include <stddef.h>
+#include "util.h"
+
+#define MAX_SHAPES 64
+#define SQUARE(x) ((x) * (x))
+
+/* A point in 2D space. */
+typedef struct {
+    double x;
+    double y;
+} Point;
+
+/** Kinds of supported shapes. */
+enum ShapeKind {
+    SHAPE_CIRCLE,
+    SHAPE_RECT
+};
+
+struct Shape {
+    enum ShapeKind kind;
+    Point origin;
+    double size;
+};
+
+typedef struct Shape Shape;
+
+/* Computes the area of a shape. */
+double shape_area(const Shape *shape);
+
+/* Distance between two points. */
+double point_distance(Point a, Point b);
+
+extern int shape_count;
+
+#endif /* SHAPES_H */
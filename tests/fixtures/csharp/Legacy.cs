@@ -0,0 +1,16 @@
+namespace Example.Legacy
+{
+    using Example.Shapes;
+
+    /// <summary>
+    /// Kept for callers that still pass a <paramref name="radius"/> &amp; nothing else.
+    /// </summary>
+    internal class OldCircle : Circle
+    {
+        public OldCircle(double radius) : base(radius)
+        {
+        }
+
+        public override string Describe() => new Circle(Radius).Name;
+    }
+}
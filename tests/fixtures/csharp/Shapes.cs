@@ -0,0 +1,86 @@
+using System;
+using System.Collections.Generic;
+using Geo = Example.Geometry;
+
+namespace Example.Shapes;
+
+/// <summary>
+/// Something that has an area.
+/// </summary>
+public interface IShape : IComparable<IShape>
+{
+    /// <summary>Computes the area.</summary>
+    /// <returns>The area in square units.</returns>
+    double Area();
+
+    string Name { get; }
+}
+
+/// <summary>Raised when a shape changes.</summary>
+public delegate void ShapeChangedHandler(IShape shape, EventArgs args);
+
+public abstract class BaseShape
+{
+    public abstract string Describe();
+}
+
+/// <summary>Marks a type whose changes are audited.</summary>
+[AttributeUsage(AttributeTargets.Class)]
+public sealed class AuditedAttribute : Attribute
+{
+}
+
+/// <summary>
+/// A circle with a <see cref="Radius"/>.
+/// </summary>
+[Serializable]
+public class Circle : BaseShape, IShape, IDisposable
+{
+    public const double DefaultRadius = 1.0;
+
+    private readonly List<Point> _points = new List<Point>();
+
+    /// <summary>The radius.</summary>
+    public double Radius { get; set; }
+
+    public string Name => "circle";
+
+    public event ShapeChangedHandler Changed;
+
+    public Circle(double radius)
+    {
+        Radius = radius;
+    }
+
+    /// <inheritdoc />
+    public double Area()
+    {
+        return Math.PI * Square(Radius);
+    }
+
+    private static double Square(double x) => x * x;
+
+    public int CompareTo(IShape other) => Area().CompareTo(other.Area());
+
+    public void Dispose()
+    {
+        Changed?.Invoke(this, EventArgs.Empty);
+    }
+}
+
+[Audited]
+public record Point(double X, double Y);
+
+public struct Size : IEquatable<Size>
+{
+    public int Width;
+    public int Height;
+
+    public bool Equals(Size other) => Width == other.Width && Height == other.Height;
+}
+
+public enum Color
+{
+    Red,
+    Green = 2,
+}
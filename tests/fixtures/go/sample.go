@@ -0,0 +1,70 @@
+// Package sample demonstrates Go symbol extraction.
+package sample
+
+import (
+	"fmt"
+	str "strings"
+)
+
+import "errors"
+
+// MaxRetries is the maximum number of retries.
+const MaxRetries = 3
+
+const (
+	DefaultName = "ctxhelpr"
+	defaultPort int = 8080
+)
+
+var ErrNotFound = errors.New("not found")
+
+// Shape is anything with an area.
+type Shape interface {
+	Area() float64
+	Perimeter() float64
+}
+
+// Named embeds a name accessor.
+type Named interface {
+	Shape
+	Name() string
+}
+
+// Point is a point in 2D space.
+type Point struct {
+	X, Y float64
+	Label string
+}
+
+// Circle is a round shape.
+type Circle struct {
+	Point
+	Radius float64
+}
+
+// ID is an identifier alias.
+type ID = string
+
+// Area returns the circle's area.
+func (c *Circle) Area() float64 {
+	return computeArea(c.Radius)
+}
+
+func (c Circle) Perimeter() float64 {
+	return 2 * c.Radius
+}
+
+// Distance calculates distance between two points.
+func Distance(a, b Point) float64 {
+	dx := a.X - b.X
+	return dx
+}
+
+func Describe(s Shape) string {
+	name := str.ToUpper("shape")
+	return fmt.Sprintf("%s: %f", name, s.Area())
+}
+
+func NewCircle(r float64) *Circle {
+	return &Circle{Radius: r}
+}
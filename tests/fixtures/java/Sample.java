@@ -0,0 +1,63 @@
+package com.example.shapes;
+
+import java.util.List;
+import java.util.Map;
+import static java.lang.Math.sqrt;
+import com.example.util.*;
+
+/**
+ * Something that has an area.
+ */
+public interface Shape extends Comparable<Shape> {
+    double area();
+}
+
+/** A circle. */
+@Entity
+@Table(name = "circles")
+public class Circle extends BaseShape implements Shape, Serializable {
+    /** Default radius. */
+    public static final double DEFAULT_RADIUS = 1.0;
+
+    @Column
+    private double radius;
+
+    public Circle(double radius) {
+        this.radius = radius;
+    }
+
+    @Override
+    public double area() {
+        return Math.PI * square(radius);
+    }
+
+    private static double square(double x) {
+        return x * x;
+    }
+
+    public static class Builder {
+        public Circle build() {
+            return new Circle(DEFAULT_RADIUS);
+        }
+    }
+}
+
+public enum Color {
+    RED,
+    GREEN;
+
+    public String lower() {
+        return name().toLowerCase();
+    }
+}
+
+@Audited("points")
+public record Point(double x, double y) implements Shape {
+    public double area() {
+        return 0;
+    }
+}
+
+public @interface Audited {
+    String value() default "";
+}
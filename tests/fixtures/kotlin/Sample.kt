@@ -0,0 +1,53 @@
+package com.example.shapes
+
+import kotlin.math.PI
+import kotlin.math.sqrt as squareRoot
+import com.example.util.*
+
+/**
+ * Something that has an area.
+ */
+interface Shape : Comparable<Shape> {
+    fun area(): Double
+}
+
+/** Base for all shapes. */
+abstract class BaseShape(val name: String)
+
+/** A circle. */
+@Serializable
+class Circle(val radius: Double) : BaseShape("circle"), Shape {
+    var label: String = "circle"
+
+    override fun area(): Double {
+        return PI * square(radius)
+    }
+
+    private fun square(x: Double): Double = x * x
+
+    companion object Factory {
+        const val DEFAULT_RADIUS = 1.0
+
+        fun unit(): Circle = Circle(DEFAULT_RADIUS)
+    }
+}
+
+data class Point(val x: Double, val y: Double)
+
+enum class Color {
+    RED,
+    GREEN;
+
+    fun lower(): String = name.lowercase()
+}
+
+object Registry {
+    fun register(shape: Shape) {
+        println(shape.area())
+    }
+}
+
+/** Top-level helper. */
+fun describe(shape: Shape): String = "Shape with area ${shape.area()}"
+
+val DEFAULT_SHAPE: Shape = Circle(2.0)
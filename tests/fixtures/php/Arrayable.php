@@ -0,0 +1,11 @@
+<?php
+
+namespace App\Contracts;
+
+interface Arrayable extends \Countable
+{
+    /**
+     * Get the instance as an array.
+     */
+    public function toArray(): array;
+}
@@ -0,0 +1,16 @@
+<?php
+
+namespace App\Concerns;
+
+/**
+ * Keeps `updated_at` current.
+ */
+trait HasTimestamps
+{
+    protected ?\DateTimeImmutable $updatedAt = null;
+
+    public function touch(): void
+    {
+        $this->updatedAt = new \DateTimeImmutable();
+    }
+}
@@ -0,0 +1,14 @@
+<?php
+
+namespace App\Models;
+
+enum Status: string
+{
+    case Active = 'active';
+    case Banned = 'banned';
+
+    public function label(): string
+    {
+        return ucfirst($this->value);
+    }
+}
@@ -0,0 +1,57 @@
+<?php
+
+declare(strict_types=1);
+
+namespace App\Models;
+
+use App\Concerns\HasTimestamps;
+use App\Contracts\Arrayable as ArrayableContract;
+use Illuminate\Database\Eloquent\{Model, SoftDeletes};
+use function App\Support\format_name;
+
+/**
+ * A registered user.
+ *
+ * @property string $name
+ */
+#[Audited]
+class User extends Model implements ArrayableContract, \JsonSerializable
+{
+    use HasTimestamps, SoftDeletes;
+
+    public const ROLE_ADMIN = 'admin';
+
+    /** @var string[] */
+    protected array $fillable = ['name', 'email'];
+
+    private ?Status $status = null;
+
+    public function __construct(private string $name, public readonly string $email)
+    {
+    }
+
+    /**
+     * Display name for the UI.
+     */
+    public function displayName(): string
+    {
+        return format_name($this->name);
+    }
+
+    public function toArray(): array
+    {
+        return ['name' => $this->displayName(), 'status' => $this->status?->label()];
+    }
+
+    public static function make(string $name): static
+    {
+        $user = new static($name, '');
+        self::boot();
+        return $user;
+    }
+
+    public function jsonSerialize(): mixed
+    {
+        return $this->toArray();
+    }
+}
@@ -0,0 +1,20 @@
+<?php
+
+namespace App\Support {
+    use App\Models\User;
+
+    const DEFAULT_NAME = 'guest';
+
+    /**
+     * Normalizes a display name.
+     */
+    function format_name(string $name): string
+    {
+        return ucfirst(trim($name));
+    }
+
+    function admin(): User
+    {
+        return User::make(DEFAULT_NAME);
+    }
+}
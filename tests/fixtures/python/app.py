@@ -0,0 +1,36 @@
+"""Web handlers exercising imports, decorators and annotations."""
+
+import os.path as osp
+from dataclasses import dataclass, field
+from typing import Literal, Optional
+from .sample import Animal, Dog as Puppy
+from . import sample
+from .shapes import Circle, scale
+from ..shared.models import *
+
+import pytest
+from flask import Flask
+
+app = Flask(__name__)
+
+
+@dataclass
+class Owner:
+    name: str
+    pets: list[Animal] = field(default_factory=list)
+    favourite: Optional["Dog"] = None
+
+    @property
+    def pet_count(self) -> int:
+        return len(self.pets)
+
+
+@app.route("/owners/<name>")
+def show_owner(name: str, owner: Owner | None = None, mode: Literal["Dog"] = "Dog") -> Optional[Owner]:
+    from json import dumps
+    return owner
+
+
+@pytest.fixture
+def owner() -> Owner:
+    return Owner(name=osp.basename("Ada"))
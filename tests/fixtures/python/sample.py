@@ -37,3 +37,55 @@ class Dog(Animal):
 @staticmethod
 def standalone_decorated() -> None:
     pass
+
+def adopt(name: str, owner: str) -> bool:
+    """Adopt an animal.
+
+    Args:
+        name: The animal to adopt.
+        owner (str): Who takes it home.
+
+    Returns:
+        True once the papers are signed.
+
+    Raises:
+        ValueError: If the owner is empty.
+    """
+    return bool(owner)
+
+def rehome(name: str, shelter: str) -> str:
+    """Move an animal to another shelter.
+
+    Shelters share one registry, so the animal keeps its records.
+
+    Args:
+        name: The animal to move.
+        shelter: Where it goes.
+
+    Moving twice in a day is allowed.
+
+    Returns:
+        The new shelter's address.
+    """
+    return shelter
+
+def weigh(name: str, unit: str) -> float:
+    """Weigh an animal.
+
+    Scales are calibrated every morning.
+
+    Parameters
+    ----------
+    name : str
+        The animal on the scale.
+    unit : str
+        Kilograms or pounds.
+
+    Readings are rounded to one decimal.
+
+    Returns
+    -------
+    float
+        The weight in the requested unit.
+    """
+    return 0.0
@@ -0,0 +1,6 @@
+"""Shapes that pets can be drawn in."""
+
+from .circle import Circle, unit_circle
+from .circle import _scale as scale
+
+__all__ = ["Circle", "unit_circle"]
@@ -0,0 +1,17 @@
+from ..sample import Animal
+
+
+class Circle:
+    """A circle around an animal."""
+
+    def __init__(self, radius: float, animal: Animal) -> None:
+        self.radius = radius
+        self.animal = animal
+
+
+def unit_circle(animal: Animal) -> Circle:
+    return Circle(_scale(1.0), animal)
+
+
+def _scale(value: float) -> float:
+    return value
@@ -0,0 +1,57 @@
+; Mirrors the built-in Python extractor on tests/fixtures/python.
+
+; Methods come before functions so a method's `@name` is claimed as a method
+(class_definition
+  body: (block
+    [
+      (function_definition
+        name: (identifier) @name
+        parameters: (parameters) @signature
+        return_type: (_)? @signature
+        body: (block . (expression_statement (string) @doc)?)) @definition.method
+      (decorated_definition
+        definition: (function_definition
+          name: (identifier) @name
+          parameters: (parameters) @signature
+          return_type: (_)? @signature
+          body: (block . (expression_statement (string) @doc)?)) @definition.method)
+    ]))
+
+(module
+  [
+    (function_definition
+      name: (identifier) @name
+      parameters: (parameters) @signature
+      return_type: (_)? @signature
+      body: (block . (expression_statement (string) @doc)?)) @definition.function
+    (decorated_definition
+      definition: (function_definition
+        name: (identifier) @name
+        parameters: (parameters) @signature
+        return_type: (_)? @signature
+        body: (block . (expression_statement (string) @doc)?)) @definition.function)
+  ])
+
+(class_definition
+  name: (identifier) @name
+  body: (block . (expression_statement (string) @doc)?)) @definition.class
+
+(class_definition
+  superclasses: (argument_list [(identifier) (attribute)] @reference.extends))
+
+(module
+  (expression_statement
+    (assignment
+      left: (identifier) @name
+      type: (_)? @signature)) @definition.constant
+  (#match? @name "^[A-Z0-9_]+$"))
+
+(class_definition
+  body: (block
+    (expression_statement
+      (assignment
+        left: (identifier) @name)) @definition.constant)
+  (#match? @name "^[A-Z0-9_]+$"))
+
+(call
+  function: [(identifier) (attribute)] @reference.call)
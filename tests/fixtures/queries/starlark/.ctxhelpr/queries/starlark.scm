@@ -0,0 +1,39 @@
+; Bazel's Starlark, parsed with the Python grammar.
+
+(module
+  (function_definition
+    name: (identifier) @name
+    parameters: (parameters) @signature
+    body: (block . (expression_statement (string) @doc)?)) @definition.function)
+
+; `go_service(name = "server", ...)` declares the target `server`, documented by
+; the comments right above it when there are any
+(module
+  (comment)+ @doc
+  .
+  (expression_statement
+    (call
+      arguments: (argument_list
+        (keyword_argument
+          name: (identifier) @_key
+          value: (string (string_content) @name))))) @definition.constant
+  (#eq? @_key "name"))
+
+(module
+  (expression_statement
+    (call
+      arguments: (argument_list
+        (keyword_argument
+          name: (identifier) @_key
+          value: (string (string_content) @name))))) @definition.constant
+  (#eq? @_key "name"))
+
+(call
+  function: (identifier) @reference.call
+  (#not-eq? @reference.call "load"))
+
+; `load("//tools:defs.bzl", "go_service")` imports the file and each symbol
+(call
+  function: (identifier) @_load
+  arguments: (argument_list (string (string_content) @reference.import))
+  (#eq? @_load "load"))
@@ -0,0 +1,6 @@
+"""Macros shared by BUILD files."""
+
+def go_service(name, srcs, deps = []):
+    """Builds a Go binary and its container image."""
+    go_binary(name = name, srcs = srcs, deps = deps)
+    container_image(name = name + "_image", binary = name)
@@ -0,0 +1,4 @@
+export function helper(): void {}
+
+// Not the `run` main.ts imports
+export function run(): void {}
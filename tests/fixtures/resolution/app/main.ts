@@ -0,0 +1,16 @@
+import { run } from "../jobs/worker";
+
+export function start(): void {
+  run();
+  helper();
+  format();
+}
+
+function format(): string {
+  return "";
+}
+
+export function stop(): void {
+  schedule();
+  teardown();
+}
@@ -0,0 +1,3 @@
+export function run(): void {}
+
+export function schedule(): void {}
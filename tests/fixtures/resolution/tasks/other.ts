@@ -0,0 +1,7 @@
+export function helper(): void {}
+
+export function format(): string {
+  return "other";
+}
+
+export function schedule(): void {}
@@ -0,0 +1,31 @@
+pub struct Line;
+
+impl Line {
+    pub fn new() -> Self {
+        Line
+    }
+
+    fn norm(&self) -> f64 {
+        1.0
+    }
+}
+
+pub struct Point;
+
+impl Point {
+    pub fn new() -> Self {
+        Point
+    }
+
+    fn norm(&self) -> f64 {
+        0.0
+    }
+
+    pub fn length(&self) -> f64 {
+        self.norm()
+    }
+}
+
+pub fn origin() -> Point {
+    Point::new()
+}
@@ -0,0 +1,49 @@
+# Shared behaviour for records that can be published
+module Publishable
+  def publish!
+    update(published: true)
+  end
+end
+
+# A blog post
+class Post < ApplicationRecord
+  include Publishable
+  extend Searchable
+  prepend Auditing
+
+  # Rendered excerpt, not persisted
+  attr_accessor :preview, :draft_notes
+  attr_reader :word_count
+
+  belongs_to :author, class_name: "User"
+  has_many :comments, dependent: :destroy
+  has_many :categories, through: :categorizations
+  has_one :cover_image
+  belongs_to :commentable, polymorphic: true
+
+  # Published posts, newest first
+  scope :published, -> { where(published: true).order(created_at: :desc) }
+
+  validates :title, :body, presence: true
+  validate :title_is_unique
+
+  before_save :normalize_title, :count_words
+
+  private
+
+  def normalize_title
+    self.title = title.strip
+  end
+
+  def count_words
+    @word_count = body.split.size
+  end
+
+  def title_is_unique
+    errors.add(:title, "taken") if Post.exists?(title: title)
+  end
+end
+
+class User < ApplicationRecord
+  has_many :posts, foreign_key: :author_id
+end
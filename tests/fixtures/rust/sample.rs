@@ -1,4 +1,8 @@
+use std::collections::HashMap;
+use crate::utils::{self, clamp as clamp_value};
+
 /// A simple point in 2D space
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct Point {
     pub x: f64,
     pub y: f64,
@@ -52,8 +56,77 @@ pub static GLOBAL_NAME: &str = "ctxhelpr";
 /// An alias for results
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// A line between two points
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+}
+
+pub(crate) fn largest<T: HasArea>(shapes: &[T]) -> Option<&T> {
+    shapes.first()
+}
+
 pub mod utils {
+    use super::{Point, Shape as Kind};
+
     pub fn clamp(val: f64, min: f64, max: f64) -> f64 {
         if val < min { min } else if val > max { max } else { val }
     }
 }
+
+/// Squares an expression
+#[macro_export]
+macro_rules! square {
+    ($x:expr) => {
+        $x * $x
+    };
+}
+
+#[tokio::main]
+async fn main() {
+    helper();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let p = Point { x: 0.0, y: 0.0 };
+        let d = square!(distance(&p, &p));
+        assert_eq!(d, 0.0);
+    }
+}
+
+#[doc = "Parses a shape from its name."]
+/**
+ * # Arguments
+ *
+ * * `name` - The shape's name, like `circle`
+ *
+ * # Errors
+ *
+ * Returns an error if the name is unknown.
+ */
+pub fn parse_shape(name: &str) -> Result<Shape> {
+    todo!()
+}
+
+pub mod io {
+    //! Reading and writing shapes.
+    #![doc = "Only JSON for now."]
+}
+
+/// The corners of a polygon, in order
+pub struct Corners<T> {
+    points: Vec<T>,
+}
+
+impl<T> Iterator for Corners<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.points.pop()
+    }
+}
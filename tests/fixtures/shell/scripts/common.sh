@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+# Shared helpers for the deploy scripts.
+
+export DEPLOY_ENV="${DEPLOY_ENV:-staging}"
+export -n OLD_FLAG
+readonly LOG_PREFIX="[deploy]"
+
+# Prints a prefixed log line.
+log() {
+  echo "$LOG_PREFIX $*"
+}
+
+function die {
+  log "error: $1"
+  exit 1
+}
@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+source "$(dirname "$0")/common.sh"
+. ./scripts/env.sh
+
+export IMAGE_TAG
+IMAGE_TAG=$(git rev-parse --short HEAD)
+
+# Builds and pushes the image.
+build_image() {
+  log "building $IMAGE_TAG"
+  docker build -t "app:$IMAGE_TAG" .
+  ./scripts/migrate.sh --dry-run
+}
+
+main() {
+  build_image
+  zsh scripts/notify.zsh "deployed"
+  die "unreachable"
+}
+
+main "$@"
@@ -0,0 +1,5 @@
+#!/bin/sh
+run_migrations() {
+  echo "migrating"
+}
+run_migrations
@@ -0,0 +1,17 @@
+-- Accounts that can sign in.
+CREATE TABLE users (
+  id BIGSERIAL PRIMARY KEY,
+  email VARCHAR(255) NOT NULL UNIQUE,
+  created_at TIMESTAMP DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS public.posts (
+  id BIGSERIAL PRIMARY KEY,
+  author_id BIGINT NOT NULL REFERENCES users(id),
+  title TEXT,
+  CONSTRAINT fk_editor FOREIGN KEY (editor_id) REFERENCES users (id)
+);
+
+CREATE UNIQUE INDEX idx_posts_title ON posts (title);
+
+ALTER TABLE users ADD COLUMN display_name TEXT;
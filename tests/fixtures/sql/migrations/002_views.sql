@@ -0,0 +1,17 @@
+/* Posts with their author's email. */
+CREATE OR REPLACE VIEW post_authors AS
+SELECT p.id, p.title, u.email
+FROM posts p
+JOIN users u ON u.id = p.author_id;
+
+CREATE FUNCTION touch_updated_at() RETURNS trigger AS $$
+BEGIN
+  NEW.updated_at = now();
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER posts_touch BEFORE UPDATE ON posts
+FOR EACH ROW EXECUTE FUNCTION touch_updated_at();
+
+ALTER TABLE posts ADD COLUMN updated_at TIMESTAMP;
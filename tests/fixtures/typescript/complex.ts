@@ -52,3 +52,10 @@ class AdminUserRepository extends UserRepository {
     return this.findAll();
   }
 }
+
+interface Admin extends User {
+  permissions: string[];
+}
+
+/** Anyone who can sign in */
+type Account = User | Admin | null;
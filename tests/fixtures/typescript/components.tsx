@@ -0,0 +1,23 @@
+import { UserDto } from "./users.controller";
+
+/** A card showing one user */
+export function UserCard({ user }: { user: UserDto }) {
+  return (
+    <div className="card">
+      <Avatar url={user.avatarUrl} />
+      <span>{user.name}</span>
+    </div>
+  );
+}
+
+export const Avatar = ({ url }: { url: string }) => <img src={url} />;
+
+export function UserList({ users }: { users: UserDto[] }) {
+  return (
+    <Layout.Section>
+      {users.map((u) => (
+        <UserCard key={u.id} user={u} />
+      ))}
+    </Layout.Section>
+  );
+}
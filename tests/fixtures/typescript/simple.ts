@@ -44,3 +44,15 @@ const DEFAULT_PORT: number = 3000;
 const greet = (name: string): string => {
   return `Hello, ${name}`;
 };
+
+/**
+ * Parses a port number. Accepts strings from the environment.
+ * @param {string} value - The raw value
+ * @param {number} [fallback=3000] Used when the value
+ *   is missing
+ * @returns {number} The port
+ * @throws {RangeError} When the port is out of range
+ */
+function parsePort(value: string, fallback: number = DEFAULT_PORT): number {
+  return Number(value) || fallback;
+}
@@ -0,0 +1,28 @@
+export class UserDto {
+  id: number;
+  name: string;
+  avatarUrl: string;
+}
+
+/** Serves the users API */
+@Controller("/users")
+export class UsersController {
+  @Inject()
+  private readonly repo: UserRepository;
+
+  /** Lists every user */
+  @Get()
+  @Roles("admin")
+  findAll(): UserDto[] {
+    return this.repo.all();
+  }
+}
+
+@Injectable()
+class UsersService {}
+
+@Entity()
+export class UserEntity {
+  @Column()
+  email: string;
+}
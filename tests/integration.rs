@@ -1,6 +1,7 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use ctxhelpr::indexer::Indexer;
+use ctxhelpr::config::{Config, IndexerConfig, QueriesConfig, QueryLanguageConfig};
+use ctxhelpr::indexer::{Indexer, QueryPacks};
 use ctxhelpr::storage::{self, SqliteStorage};
 
 fn empty_dir() -> tempfile::TempDir {
@@ -27,12 +28,69 @@ fn markdown_fixtures_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/markdown")
 }
 
+fn go_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/go")
+}
+
+fn java_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/java")
+}
+
+fn kotlin_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/kotlin")
+}
+
+fn cpp_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/cpp")
+}
+
+fn csharp_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/csharp")
+}
+
+fn php_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/php")
+}
+
+fn sfc_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sfc")
+}
+
+fn data_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/data")
+}
+
+fn api_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/api")
+}
+
+fn shell_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/shell")
+}
+
+fn sql_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sql")
+}
+
+fn query_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/queries")
+}
+
+fn resolution_fixtures_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/resolution")
+}
+
 fn index_lang_fixtures(path: PathBuf) -> (SqliteStorage, String) {
     let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
     let path_str = path.to_str().unwrap().to_string();
     let indexer = Indexer::new();
     let stats = indexer
-        .index(&path_str, &storage, &[], u64::MAX)
+        .index(
+            &path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("Indexing failed");
     assert!(stats.files_total > 0, "No files were processed");
     assert!(stats.symbols_count > 0, "No symbols were extracted");
@@ -45,7 +103,12 @@ fn index_fixtures() -> (SqliteStorage, String) {
     let path_str = path.to_str().unwrap().to_string();
     let indexer = Indexer::new();
     let stats = indexer
-        .index(&path_str, &storage, &[], u64::MAX)
+        .index(
+            &path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("Indexing failed");
     assert!(stats.files_total > 0, "No files were processed");
     assert!(stats.symbols_count > 0, "No symbols were extracted");
@@ -60,10 +123,15 @@ fn test_index_repository() {
     let indexer = Indexer::new();
 
     let stats = indexer
-        .index(path_str, &storage, &[], u64::MAX)
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("Indexing failed");
 
-    assert_eq!(stats.files_total, 9, "Should index 9 TypeScript files");
+    assert_eq!(stats.files_total, 11, "Should index 11 TypeScript files");
     assert!(stats.symbols_count > 10, "Should extract multiple symbols");
     assert!(stats.refs_count > 0, "Should extract references");
     assert_eq!(stats.files_deleted, 0);
@@ -78,13 +146,23 @@ fn test_incremental_reindex() {
 
     // First index
     let stats1 = indexer
-        .index(path_str, &storage, &[], u64::MAX)
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("First index failed");
     assert!(stats1.files_total > 0);
 
     // Second index - same files, nothing changed
     let stats2 = indexer
-        .index(path_str, &storage, &[], u64::MAX)
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("Second index failed");
     assert_eq!(
         stats2.files_unchanged, stats1.files_total,
@@ -401,8 +479,8 @@ fn test_update_files() {
             &path_str,
             &["simple.ts".to_string()],
             &storage,
-            &[],
-            u64::MAX,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
         )
         .expect("update_files failed");
 
@@ -421,8 +499,8 @@ fn test_update_files_new_file() {
             &path_str,
             &["simple.ts".to_string()],
             &storage,
-            &[],
-            u64::MAX,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
         )
         .expect("update_files failed");
 
@@ -439,8 +517,8 @@ fn test_update_files_nonexistent_file() {
             &path_str,
             &["nonexistent_file.ts".to_string()],
             &storage,
-            &[],
-            u64::MAX,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
         )
         .expect("update_files should handle missing files gracefully");
 
@@ -456,7 +534,12 @@ fn test_index_empty_directory() {
     let indexer = Indexer::new();
 
     let stats = indexer
-        .index(path_str, &storage, &[], u64::MAX)
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("Indexing empty dir failed");
 
     assert_eq!(stats.files_total, 0, "No files in empty dir");
@@ -510,6 +593,179 @@ fn test_search_no_results() {
     );
 }
 
+#[test]
+fn test_jsx_component_usages_are_refs() {
+    let (storage, path_str) = index_fixtures();
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "components.tsx")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+    let calls = |name: &str| -> Vec<String> {
+        storage
+            .get_dependencies(&path_str, find(name).id)
+            .expect("get_dependencies failed")
+            .into_iter()
+            .filter(|r| r.ref_kind == "call")
+            .map(|r| r.to_name)
+            .collect()
+    };
+
+    // HTML elements aren't refs; member tags keep their path
+    assert_eq!(calls("UserCard"), vec!["Avatar"]);
+    assert!(calls("UserList").contains(&"Layout.Section".to_string()));
+    assert!(calls("UserList").contains(&"UserCard".to_string()));
+    assert!(calls("Avatar").is_empty());
+
+    let card = find("UserCard");
+    let rendered_by: Vec<i64> = storage
+        .get_references(&path_str, card.id)
+        .expect("get_references failed")
+        .iter()
+        .map(|r| r.from_symbol_id)
+        .collect();
+    assert_eq!(rendered_by, vec![find("UserList").id]);
+}
+
+#[test]
+fn test_ts_decorators_as_metadata() {
+    let (storage, path_str) = index_fixtures();
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "users.controller.ts")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+    let decorators = |name: &str| -> Vec<String> {
+        find(name)
+            .metadata
+            .iter()
+            .filter(|(k, _)| k == "decorator")
+            .map(|(_, v)| v.clone())
+            .collect()
+    };
+
+    assert_eq!(decorators("UsersController"), vec!["Controller"]);
+    assert_eq!(decorators("UsersService"), vec!["Injectable"]);
+    assert_eq!(decorators("UserEntity"), vec!["Entity"]);
+    assert_eq!(decorators("findAll"), vec!["Get", "Roles"]);
+    assert_eq!(decorators("repo"), vec!["Inject"]);
+    assert_eq!(decorators("email"), vec!["Column"]);
+    assert!(decorators("UserDto").is_empty());
+
+    // Decorators don't hide doc comments
+    assert_eq!(
+        find("UsersController").doc_comment.as_deref(),
+        Some("Serves the users API")
+    );
+    assert_eq!(
+        find("findAll").doc_comment.as_deref(),
+        Some("Lists every user")
+    );
+
+    let controllers = storage
+        .find_symbols_by_meta(
+            &path_str,
+            &[storage::MetaFilter::parse("decorator=Controller")],
+            Some("class"),
+            None,
+            100,
+        )
+        .expect("find_symbols_by_meta failed");
+    assert_eq!(
+        controllers
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["UsersController"]
+    );
+}
+
+#[test]
+fn test_ts_type_positions_are_refs() {
+    let (storage, path_str) = index_fixtures();
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "complex.ts")
+        .expect("get_file_symbols failed");
+    let find = |name: &str, parent: Option<&str>| {
+        let parent_id = parent.map(|p| {
+            symbols
+                .iter()
+                .find(|s| s.name == p && s.parent_symbol_id.is_none())
+                .unwrap_or_else(|| panic!("Should find '{p}'"))
+                .id
+        });
+        symbols
+            .iter()
+            .find(|s| s.name == name && s.parent_symbol_id == parent_id)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+    let deps = |name: &str, parent: Option<&str>, kind: &str| -> Vec<String> {
+        storage
+            .get_dependencies(&path_str, find(name, parent).id)
+            .expect("get_dependencies failed")
+            .into_iter()
+            .filter(|r| r.ref_kind == kind)
+            .map(|r| r.to_name)
+            .collect()
+    };
+
+    // Parameter, return, property and generic argument types; builtins and
+    // type parameters are skipped
+    assert_eq!(
+        deps("findById", Some("UserRepository"), "type_ref"),
+        vec!["User"]
+    );
+    assert_eq!(
+        deps("constructor", Some("UserRepository"), "type_ref"),
+        vec!["Database"]
+    );
+    assert_eq!(
+        deps("cache", Some("UserRepository"), "type_ref"),
+        vec!["User"]
+    );
+    assert!(deps("findById", Some("Repository"), "type_ref").is_empty());
+    assert_eq!(deps("UserRepository", None, "type_ref"), vec!["User"]);
+    assert_eq!(
+        deps("UserRepository", None, "implements"),
+        vec!["Repository"]
+    );
+    assert_eq!(
+        deps("AdminUserRepository", None, "extends"),
+        vec!["UserRepository"]
+    );
+    assert_eq!(deps("Admin", None, "extends"), vec!["User"]);
+    // Union members
+    assert_eq!(deps("Account", None, "type_ref"), vec!["Admin", "User"]);
+
+    // Where is `UserDto` used: a return type and two destructured prop types
+    let dto = storage
+        .get_file_symbols(&path_str, "users.controller.ts")
+        .expect("get_file_symbols failed")
+        .into_iter()
+        .find(|s| s.name == "UserDto")
+        .expect("Should find UserDto");
+    let mut used_by: Vec<String> = storage
+        .get_references(&path_str, dto.id)
+        .expect("get_references failed")
+        .into_iter()
+        .filter(|r| r.ref_kind == "type_ref")
+        .filter_map(|r| r.from_name)
+        .collect();
+    used_by.sort();
+    assert_eq!(used_by, vec!["UserCard", "UserList", "findAll"]);
+}
+
 // ==================== Python Tests ====================
 
 #[test]
@@ -520,10 +776,18 @@ fn test_python_index_repository() {
     let indexer = Indexer::new();
 
     let stats = indexer
-        .index(path_str, &storage, &[], u64::MAX)
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("Indexing failed");
 
-    assert_eq!(stats.files_total, 1, "Should index 1 Python file");
+    assert_eq!(
+        stats.files_total, 5,
+        "Should index 4 Python files and 1 notebook"
+    );
     assert!(stats.symbols_count > 0, "Should extract symbols");
 }
 
@@ -636,6 +900,289 @@ fn test_python_constants() {
     );
 }
 
+#[test]
+fn test_python_imports_decorators_and_type_refs() {
+    let (storage, path_str) = index_lang_fixtures(python_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "app.py")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+    let deps = |name: &str, kind: &str| -> Vec<String> {
+        storage
+            .get_dependencies(&path_str, find(name).id)
+            .expect("get_dependencies failed")
+            .into_iter()
+            .filter(|r| r.ref_kind == kind)
+            .map(|r| r.to_name)
+            .collect()
+    };
+    let decorators = |name: &str| -> Vec<String> {
+        find(name)
+            .metadata
+            .iter()
+            .filter(|(k, _)| k == "decorator")
+            .map(|(_, v)| v.clone())
+            .collect()
+    };
+
+    assert_eq!(
+        deps("_imports", "import"),
+        vec![
+            "..shared.models.*",
+            ".sample",
+            ".sample.Animal",
+            ".sample.Dog",
+            ".shapes.Circle",
+            ".shapes.scale",
+            "dataclasses.dataclass",
+            "dataclasses.field",
+            "flask.Flask",
+            "os.path",
+            "pytest",
+            "typing.Literal",
+            "typing.Optional",
+        ]
+    );
+    assert_eq!(deps("show_owner", "import"), vec!["json.dumps"]);
+    let animal = storage
+        .get_dependencies(&path_str, find("_imports").id)
+        .expect("get_dependencies failed")
+        .into_iter()
+        .find(|r| r.to_name == ".sample.Animal")
+        .expect("Should import Animal");
+    assert!(
+        animal.to_symbol_id.is_some(),
+        "Relative imports resolve to the imported class"
+    );
+
+    assert_eq!(decorators("Owner"), vec!["dataclass"]);
+    assert_eq!(decorators("pet_count"), vec!["property"]);
+    assert_eq!(decorators("show_owner"), vec!["app.route"]);
+    assert_eq!(decorators("owner"), vec!["pytest.fixture"]);
+
+    // Field annotations, forward references included; builtins are skipped
+    assert_eq!(deps("Owner", "type_ref"), vec!["Animal", "Dog"]);
+    // `Literal["Dog"]` holds a value, not a forward reference
+    assert_eq!(deps("show_owner", "type_ref"), vec!["Owner"]);
+    assert_eq!(deps("owner", "type_ref"), vec!["Owner"]);
+    assert!(deps("pet_count", "type_ref").is_empty());
+
+    let decorated = storage
+        .find_symbols_by_meta(
+            &path_str,
+            &[storage::MetaFilter::parse("decorator=pytest.fixture")],
+            None,
+            None,
+            100,
+        )
+        .expect("find_symbols_by_meta failed");
+    assert_eq!(
+        decorated
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["owner"]
+    );
+}
+
+#[test]
+fn test_python_modules_exports_and_reexports() {
+    let (storage, path_str) = index_lang_fixtures(python_fixtures_path());
+
+    let module = |file: &str| {
+        storage
+            .get_file_symbols(&path_str, file)
+            .expect("get_file_symbols failed")
+            .into_iter()
+            .find(|s| s.kind == "mod" && s.name != "_imports")
+            .unwrap_or_else(|| panic!("{file} should have a module symbol"))
+    };
+    let exports = |file: &str| -> Vec<String> {
+        module(file)
+            .metadata
+            .into_iter()
+            .filter(|(k, _)| k == "export")
+            .map(|(_, v)| v)
+            .collect()
+    };
+
+    let sample = module("sample.py");
+    assert_eq!(sample.name, "sample");
+    assert_eq!(
+        sample.doc_comment.as_deref(),
+        Some("Sample Python module for testing.")
+    );
+    assert_eq!(module("shapes/circle.py").name, "shapes.circle");
+    let package = module("shapes/__init__.py");
+    assert_eq!(package.name, "shapes");
+    assert_eq!(
+        package.doc_comment.as_deref(),
+        Some("Shapes that pets can be drawn in.")
+    );
+
+    // `__all__` wins over re-exported names; otherwise leading underscores are private
+    assert_eq!(exports("shapes/__init__.py"), vec!["Circle", "unit_circle"]);
+    assert_eq!(exports("shapes/circle.py"), vec!["Circle", "unit_circle"]);
+    let circle_symbols = storage
+        .get_file_symbols(&path_str, "shapes/circle.py")
+        .expect("get_file_symbols failed");
+    let is_pub = |name: &str| {
+        circle_symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+            .metadata
+            .contains(&("vis".to_string(), "pub".to_string()))
+    };
+    assert!(is_pub("Circle"));
+    assert!(is_pub("unit_circle"));
+    assert!(!is_pub("_scale"));
+
+    // `from .shapes import Circle` follows the package's re-export to its definition
+    let app_symbols = storage
+        .get_file_symbols(&path_str, "app.py")
+        .expect("get_file_symbols failed");
+    let imports = app_symbols
+        .iter()
+        .find(|s| s.name == "_imports")
+        .expect("app.py should have imports");
+    let deps = storage
+        .get_dependencies(&path_str, imports.id)
+        .expect("get_dependencies failed");
+    let target = |to_name: &str| {
+        let sym_id = deps
+            .iter()
+            .find(|r| r.to_name == to_name)
+            .and_then(|r| r.to_symbol_id)
+            .unwrap_or_else(|| panic!("{to_name} should resolve"));
+        storage
+            .get_symbol_detail(&path_str, sym_id)
+            .expect("get_symbol_detail failed")
+    };
+    let circle = target(".shapes.Circle");
+    assert_eq!(
+        (circle.name.as_str(), circle.file_rel_path.as_str()),
+        ("Circle", "shapes/circle.py")
+    );
+    // The ref only records `_scale`, so the alias it's re-exported under can't be followed
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == ".shapes.scale" && r.to_symbol_id.is_none())
+    );
+    let sample_import = target(".sample");
+    assert_eq!(sample_import.id, sample.id);
+    // Parent-relative imports climb out of the package
+    let animal = storage
+        .get_dependencies(
+            &path_str,
+            module_imports(&storage, &path_str, "shapes/circle.py"),
+        )
+        .expect("get_dependencies failed")
+        .into_iter()
+        .find(|r| r.to_name == "..sample.Animal")
+        .and_then(|r| r.to_symbol_id)
+        .expect("..sample.Animal should resolve");
+    assert_eq!(
+        storage
+            .get_symbol_detail(&path_str, animal)
+            .expect("get_symbol_detail failed")
+            .file_rel_path,
+        "sample.py"
+    );
+}
+
+fn module_imports(storage: &SqliteStorage, path_str: &str, file: &str) -> i64 {
+    storage
+        .get_file_symbols(path_str, file)
+        .expect("get_file_symbols failed")
+        .into_iter()
+        .find(|s| s.name == "_imports")
+        .unwrap_or_else(|| panic!("{file} should have imports"))
+        .id
+}
+
+#[test]
+fn test_jupyter_notebook_cells() {
+    let (storage, path_str) = index_lang_fixtures(python_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "notebooks/explore.ipynb")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+    let cell = |name: &str| -> (String, String) {
+        let meta = &find(name).metadata;
+        let get = |key: &str| {
+            meta.iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+                .unwrap_or_else(|| panic!("'{name}' should record {key}"))
+        };
+        (get("cell"), get("cell_line"))
+    };
+
+    // Cells follow each other in notebook order
+    let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "Exploring shapes",
+            "_imports",
+            "RADIUS",
+            "fit",
+            "Results are plotted below.",
+            "Plot",
+            "draw"
+        ]
+    );
+
+    // Markdown cells are sections, named by their heading or first line
+    assert_eq!(find("Exploring shapes").kind, "section");
+    assert_eq!(cell("Exploring shapes"), ("1".into(), "1".into()));
+    assert_eq!(cell("Results are plotted below."), ("4".into(), "1".into()));
+
+    // Lines map back to the cell and the line within it; magics and shell lines are skipped
+    assert_eq!(cell("_imports"), ("2".into(), "2".into()));
+    assert_eq!(cell("RADIUS"), ("3".into(), "2".into()));
+    assert_eq!(cell("fit"), ("3".into(), "4".into()));
+    assert_eq!(cell("Plot"), ("5".into(), "1".into()));
+    assert_eq!(cell("draw"), ("5".into(), "2".into()));
+    assert_eq!(
+        find("fit").doc_comment.as_deref(),
+        Some("Smallest circle around a dog.")
+    );
+
+    // Imports resolve like a Python file's; calls reach across cells
+    let deps = storage
+        .get_dependencies(&path_str, find("_imports").id)
+        .expect("get_dependencies failed");
+    for name in ["sample.Dog", "shapes.Circle", "shapes.unit_circle"] {
+        assert!(
+            deps.iter()
+                .any(|r| r.to_name == name && r.to_symbol_id.is_some()),
+            "{name} should resolve, got: {deps:?}"
+        );
+    }
+    let draw_calls = storage
+        .get_dependencies(&path_str, find("draw").id)
+        .expect("get_dependencies failed");
+    assert!(
+        draw_calls
+            .iter()
+            .any(|r| r.to_name == "fit" && r.to_symbol_id == Some(find("fit").id))
+    );
+}
+
 // ==================== Rust Tests ====================
 
 #[test]
@@ -646,10 +1193,18 @@ fn test_rust_index_repository() {
     let indexer = Indexer::new();
 
     let stats = indexer
-        .index(path_str, &storage, &[], u64::MAX)
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("Indexing failed");
 
-    assert_eq!(stats.files_total, 1, "Should index 1 Rust file");
+    assert_eq!(
+        stats.files_total, 2,
+        "Should index 1 Rust file and 1 Markdown doc"
+    );
     assert!(stats.symbols_count > 0, "Should extract symbols");
 }
 
@@ -798,6 +1353,59 @@ fn test_rust_doc_comments() {
     );
 }
 
+#[test]
+fn test_rust_file_module_docs() {
+    let dir = empty_dir();
+    for (file, source) in [
+        (
+            "src/lib.rs",
+            "//! Shape parsing.\n//!\n//! Reads shapes from text.\n\nmod net;\n",
+        ),
+        (
+            "src/net/mod.rs",
+            "// Licensed under MIT\n#![allow(dead_code)]\n#![doc = \"Networking.\"]\n\npub fn connect() {}\n",
+        ),
+        ("src/net/tcp.rs", "pub fn listen() {}\n"),
+    ] {
+        let path = dir.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, source).unwrap();
+    }
+    let (storage, path_str) = index_lang_fixtures(dir.path().to_path_buf());
+    let module = |file: &str| {
+        let symbols = storage
+            .get_file_symbols(&path_str, file)
+            .expect("get_file_symbols failed");
+        let sym = symbols
+            .into_iter()
+            .find(|s| s.kind == "mod" && s.name != "_imports")
+            .unwrap_or_else(|| panic!("{file} should have a module symbol"));
+        (sym.name, sym.start_line, sym.doc_comment)
+    };
+
+    assert_eq!(
+        module("src/lib.rs"),
+        (
+            "crate".to_string(),
+            1,
+            Some("Shape parsing.\n\nReads shapes from text.".to_string())
+        )
+    );
+    assert_eq!(
+        module("src/net/mod.rs"),
+        ("net".to_string(), 1, Some("Networking.".to_string()))
+    );
+    assert_eq!(module("src/net/tcp.rs"), ("tcp".to_string(), 1, None));
+
+    let lib = storage.get_file_symbols(&path_str, "src/lib.rs").unwrap();
+    let detail = storage
+        .get_symbol_detail(&path_str, lib[0].id)
+        .expect("get_symbol_detail failed");
+    let doc = detail.doc.expect("Parts should be stored with the symbol");
+    assert_eq!(doc.summary, "Shape parsing.");
+    assert_eq!(doc.description.as_deref(), Some("Reads shapes from text."));
+}
+
 #[test]
 fn test_rust_modules_types_constants() {
     let (storage, path_str) = index_lang_fixtures(rust_fixtures_path());
@@ -819,81 +1427,551 @@ fn test_rust_modules_types_constants() {
     );
 }
 
-// ==================== Ruby Tests ====================
-
 #[test]
-fn test_ruby_index_repository() {
-    let path = ruby_fixtures_path();
-    let path_str = path.to_str().unwrap();
-    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
-    let indexer = Indexer::new();
+fn test_rust_use_declarations() {
+    let (storage, path_str) = index_lang_fixtures(rust_fixtures_path());
 
-    let stats = indexer
-        .index(path_str, &storage, &[], u64::MAX)
-        .expect("Indexing failed");
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.rs")
+        .expect("get_file_symbols failed");
+    let imports_of = |name: &str| -> Vec<String> {
+        let sym = symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"));
+        storage
+            .get_dependencies(&path_str, sym.id)
+            .expect("get_dependencies failed")
+            .into_iter()
+            .filter(|r| r.ref_kind == "import")
+            .map(|r| r.to_name)
+            .collect()
+    };
+
+    let mut file_imports = imports_of("_imports");
+    file_imports.sort();
+    assert_eq!(
+        file_imports,
+        vec![
+            "crate::utils",
+            "crate::utils::clamp",
+            "std::collections::HashMap"
+        ]
+    );
+    let mut module_imports = imports_of("utils");
+    module_imports.sort();
+    assert_eq!(module_imports, vec!["super::Point", "super::Shape"]);
 
-    assert_eq!(stats.files_total, 1, "Should index 1 Ruby file");
-    assert!(stats.symbols_count > 0, "Should extract symbols");
+    let point = symbols.iter().find(|s| s.name == "Point").unwrap();
+    let importers = storage
+        .get_references(&path_str, point.id)
+        .expect("get_references failed");
+    assert!(
+        importers
+            .iter()
+            .any(|r| r.from_name.as_deref() == Some("utils") && r.ref_kind == "import"),
+        "The utils module should import Point, got: {importers:?}"
+    );
 }
 
 #[test]
-fn test_ruby_class_with_methods() {
-    let (storage, path_str) = index_lang_fixtures(ruby_fixtures_path());
-
-    let symbols = storage
-        .get_file_symbols(&path_str, "sample.rb")
-        .expect("get_file_symbols failed");
-
-    let animal = symbols
-        .iter()
-        .find(|s| s.name == "Animal")
-        .expect("Should find 'Animal'");
-    assert_eq!(animal.kind, "class");
+fn test_rust_use_paths_pick_the_imported_module() {
+    let dir = empty_dir();
+    for (file, source) in [
+        (
+            "src/app.rs",
+            "use crate::geo::Point;\n\nfn origin() -> Point {\n    Point\n}\n",
+        ),
+        ("src/geo/mod.rs", "pub struct Point;\n"),
+        ("src/shapes.rs", "pub struct Point;\n"),
+    ] {
+        let path = dir.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, source).unwrap();
+    }
+    let (storage, path_str) = index_lang_fixtures(dir.path().to_path_buf());
+    let target = |name: &str| {
+        let sym = storage
+            .get_file_symbols(&path_str, "src/app.rs")
+            .expect("get_file_symbols failed")
+            .into_iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"));
+        let r = storage
+            .get_dependencies(&path_str, sym.id)
+            .expect("get_dependencies failed")
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| panic!("{name} should refer to something"));
+        let file = r.to_symbol_id.map(|id| {
+            storage
+                .get_symbol_detail(&path_str, id)
+                .unwrap()
+                .file_rel_path
+        });
+        (r.to_name, file, r.resolution)
+    };
 
-    let children: Vec<_> = symbols
-        .iter()
-        .filter(|s| s.parent_symbol_id == Some(animal.id))
-        .collect();
-    let method_names: Vec<&str> = children.iter().map(|s| s.name.as_str()).collect();
-    assert!(
-        method_names.contains(&"speak"),
-        "Animal should have 'speak' method, got: {:?}",
-        method_names
+    assert_eq!(
+        target("_imports"),
+        (
+            "crate::geo::Point".to_string(),
+            Some("src/geo/mod.rs".to_string()),
+            Some("import".to_string())
+        )
     );
-    assert!(
-        method_names.contains(&"initialize"),
-        "Animal should have 'initialize' method, got: {:?}",
-        method_names
+    assert_eq!(
+        target("origin"),
+        (
+            "Point".to_string(),
+            Some("src/geo/mod.rs".to_string()),
+            Some("import".to_string())
+        )
     );
 }
 
 #[test]
-fn test_ruby_inheritance() {
-    let (storage, path_str) = index_lang_fixtures(ruby_fixtures_path());
-
-    let symbols = storage
-        .get_file_symbols(&path_str, "sample.rb")
+fn test_rust_mod_declarations_import_their_files() {
+    let dir = empty_dir();
+    for (file, source) in [
+        ("src/lib.rs", "mod net;\npub mod shapes;\n"),
+        ("src/net/mod.rs", "pub fn connect() {}\n"),
+        ("src/shapes.rs", "pub struct Circle;\n"),
+    ] {
+        let path = dir.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, source).unwrap();
+    }
+    let (storage, path_str) = index_lang_fixtures(dir.path().to_path_buf());
+
+    let lib = storage
+        .get_file_symbols(&path_str, "src/lib.rs")
         .expect("get_file_symbols failed");
+    let names: Vec<&str> = lib.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["crate", "_imports"], "No stubs for `mod x;`");
 
-    let dog = symbols
+    let imports = lib
         .iter()
-        .find(|s| s.name == "Dog")
-        .expect("Should find 'Dog'");
-    assert_eq!(dog.kind, "class");
-
-    let refs = storage
-        .get_dependencies(&path_str, dog.id)
-        .expect("get_dependencies failed");
-    assert!(
-        refs.iter().any(|r| r.to_name == "Animal"),
-        "Dog should extend Animal, got: {:?}",
-        refs.iter().map(|r| &r.to_name).collect::<Vec<_>>()
+        .flat_map(|s| storage.get_dependencies(&path_str, s.id).unwrap())
+        .map(|r| {
+            let file = r.to_symbol_id.map(|id| {
+                storage
+                    .get_symbol_detail(&path_str, id)
+                    .unwrap()
+                    .file_rel_path
+            });
+            (r.to_name, file, r.resolution)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        imports,
+        vec![
+            (
+                "net".to_string(),
+                Some("src/net/mod.rs".to_string()),
+                Some("import".to_string())
+            ),
+            (
+                "shapes".to_string(),
+                Some("src/shapes.rs".to_string()),
+                Some("import".to_string())
+            ),
+        ]
     );
 }
 
 #[test]
-fn test_ruby_module() {
-    let (storage, path_str) = index_lang_fixtures(ruby_fixtures_path());
+fn test_rust_trait_impls_and_type_refs() {
+    let (storage, path_str) = index_lang_fixtures(rust_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.rs")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+    let refs_of = |name: &str| -> Vec<(String, String, Option<i64>)> {
+        storage
+            .get_dependencies(&path_str, find(name).id)
+            .expect("get_dependencies failed")
+            .into_iter()
+            .map(|r| (r.ref_kind, r.to_name, r.to_symbol_id))
+            .collect()
+    };
+
+    let impl_refs = refs_of("HasArea for Shape");
+    assert!(
+        impl_refs.contains(&(
+            "implements".to_string(),
+            "HasArea".to_string(),
+            Some(find("HasArea").id)
+        )),
+        "impl should implement HasArea, got: {impl_refs:?}"
+    );
+    assert!(
+        impl_refs.contains(&(
+            "type_ref".to_string(),
+            "Shape".to_string(),
+            Some(find("Shape").id)
+        )),
+        "impl should be linked to Shape, got: {impl_refs:?}"
+    );
+
+    let distance_refs = refs_of("distance");
+    assert!(
+        distance_refs
+            .iter()
+            .any(|(kind, name, _)| kind == "type_ref" && name == "Point"),
+        "distance takes Points, got: {distance_refs:?}"
+    );
+    assert!(
+        !distance_refs.iter().any(|(_, name, _)| name == "f64"),
+        "Primitives are not type refs"
+    );
+
+    // Generic bounds name the trait, the type parameter itself is skipped
+    let largest_refs = refs_of("largest");
+    assert!(
+        largest_refs
+            .iter()
+            .any(|(kind, name, _)| kind == "type_ref" && name == "HasArea")
+    );
+    assert!(!largest_refs.iter().any(|(_, name, _)| name == "T"));
+
+    // So are the impl's parameters in its methods and associated types
+    assert_eq!(refs_of("Item"), vec![]);
+    assert!(!refs_of("next").iter().any(|(_, name, _)| name == "T"));
+
+    let start_refs = refs_of("start");
+    assert_eq!(
+        start_refs,
+        vec![(
+            "type_ref".to_string(),
+            "Point".to_string(),
+            Some(find("Point").id)
+        )]
+    );
+
+    let implementors = storage
+        .get_references(&path_str, find("HasArea").id)
+        .expect("get_references failed");
+    assert!(
+        implementors
+            .iter()
+            .any(|r| r.ref_kind == "implements"
+                && r.from_name.as_deref() == Some("HasArea for Shape")),
+        "HasArea should list its impl, got: {implementors:?}"
+    );
+}
+
+#[test]
+fn test_rust_macros_and_symbol_metadata() {
+    let (storage, path_str) = index_lang_fixtures(rust_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.rs")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+    let meta = |name: &str| -> Vec<String> {
+        find(name)
+            .metadata
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect()
+    };
+
+    let square = find("square");
+    assert_eq!(square.kind, "macro");
+    assert_eq!(square.signature.as_deref(), Some("($x:expr)"));
+    assert_eq!(square.doc_comment.as_deref(), Some("Squares an expression"));
+    assert_eq!(meta("square"), vec!["vis=pub", "attr=macro_export"]);
+
+    // Attributes between the doc comment and the item don't hide the doc
+    assert_eq!(
+        find("Point").doc_comment.as_deref(),
+        Some("A simple point in 2D space")
+    );
+    assert_eq!(
+        meta("Point"),
+        vec![
+            "vis=pub",
+            "derive=Debug",
+            "derive=Clone",
+            "derive=Serialize"
+        ]
+    );
+    assert_eq!(meta("largest"), vec!["vis=pub(crate)"]);
+    assert!(meta("helper").is_empty(), "Private items have no metadata");
+    assert_eq!(meta("main"), vec!["attr=tokio::main"]);
+    assert_eq!(meta("tests"), vec!["cfg=test"]);
+    assert_eq!(meta("test_distance"), vec!["attr=test"]);
+
+    let deps = storage
+        .get_dependencies(&path_str, find("test_distance").id)
+        .expect("get_dependencies failed");
+    let square_call = deps
+        .iter()
+        .find(|r| r.to_name == "square")
+        .expect("Invoking square! should be a call");
+    assert_eq!(square_call.ref_kind, "call");
+    assert_eq!(square_call.to_symbol_id, Some(square.id));
+    assert!(
+        !deps.iter().any(|r| r.to_name == "assert_eq"),
+        "std macros are not refs"
+    );
+
+    let names = |filters: &[&str], kind: Option<&str>| -> Vec<String> {
+        let filters: Vec<_> = filters
+            .iter()
+            .map(|f| storage::MetaFilter::parse(f))
+            .collect();
+        storage
+            .find_symbols_by_meta(&path_str, &filters, kind, None, 100)
+            .expect("find_symbols_by_meta failed")
+            .into_iter()
+            .map(|s| s.name)
+            .collect()
+    };
+    assert_eq!(names(&["derive=Serialize"], None), vec!["Point"]);
+    assert_eq!(names(&["attr=test"], None), vec!["test_distance"]);
+    assert_eq!(
+        names(&["vis=pub"], Some("struct")),
+        vec!["Point", "Segment", "Corners"]
+    );
+    assert_eq!(names(&["vis=pub", "derive"], None), vec!["Point"]);
+    assert!(names(&["derive=Serialize", "vis=pub(crate)"], None).is_empty());
+}
+
+#[test]
+fn test_find_symbols_by_meta_file_prefix_is_literal() {
+    let dir = empty_dir();
+    for crate_dir in ["my_app", "myXapp", "my%app"] {
+        std::fs::create_dir_all(dir.path().join(crate_dir)).unwrap();
+        std::fs::write(
+            dir.path().join(crate_dir).join("lib.rs"),
+            "pub fn run() {}\n",
+        )
+        .unwrap();
+    }
+    let (storage, path_str) = index_lang_fixtures(dir.path().to_path_buf());
+
+    let files = |prefix: &str| -> Vec<String> {
+        storage
+            .find_symbols_by_meta(
+                &path_str,
+                &[storage::MetaFilter::parse("vis=pub")],
+                None,
+                Some(prefix),
+                100,
+            )
+            .expect("find_symbols_by_meta failed")
+            .into_iter()
+            .map(|s| s.file_rel_path)
+            .collect()
+    };
+    assert_eq!(files("my_app/"), vec!["my_app/lib.rs"]);
+    assert_eq!(files("my%app/"), vec!["my%app/lib.rs"]);
+    assert_eq!(files("my").len(), 3);
+}
+
+#[test]
+fn test_structured_doc_comments() {
+    fn detail(path: PathBuf, file: &str, name: &str) -> (serde_json::Value, serde_json::Value) {
+        let (storage, path_str) = index_lang_fixtures(path);
+        let symbols = storage
+            .get_file_symbols(&path_str, file)
+            .expect("get_file_symbols failed");
+        let fmt =
+            ctxhelpr::output::CompactFormatter::new(&ctxhelpr::config::OutputConfig::default());
+        let brief: serde_json::Value = serde_json::from_str(
+            &ctxhelpr::output::OutputFormatter::format_file_symbols(&fmt, file, &symbols),
+        )
+        .unwrap();
+        let brief = brief["syms"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["n"] == name)
+            .cloned()
+            .unwrap_or_else(|| panic!("Should list '{name}'"));
+        let id = symbols.iter().find(|s| s.name == name).unwrap().id;
+        let sym = storage.get_symbol_detail(&path_str, id).unwrap();
+        let detail =
+            ctxhelpr::output::OutputFormatter::format_symbol_detail(&fmt, &sym, &[], &[], &[], &[]);
+        (brief, serde_json::from_str(&detail).unwrap())
+    }
+
+    // Rust: `#[doc]` attributes and `/** */` blocks, rustdoc headings
+    let (brief, doc) = detail(rust_fixtures_path(), "sample.rs", "parse_shape");
+    assert_eq!(brief["doc"], "Parses a shape from its name.");
+    assert_eq!(doc["doc"], "Parses a shape from its name.");
+    assert_eq!(
+        doc["params"],
+        serde_json::json!([{"n": "name", "d": "The shape's name, like `circle`"}])
+    );
+    assert_eq!(
+        doc["throws"],
+        serde_json::json!([{"d": "Returns an error if the name is unknown."}])
+    );
+    assert!(doc.get("desc").is_none() && doc.get("ret").is_none());
+
+    // Inner `//!` and `#![doc]` docs of an inline module
+    let (_, doc) = detail(rust_fixtures_path(), "sample.rs", "io");
+    assert_eq!(doc["doc"], "Reading and writing shapes.");
+    assert_eq!(doc["desc"], "Only JSON for now.");
+
+    // JSDoc tags
+    let (brief, doc) = detail(fixtures_path(), "simple.ts", "parsePort");
+    assert_eq!(brief["doc"], "Parses a port number.");
+    assert_eq!(doc["desc"], "Accepts strings from the environment.");
+    assert_eq!(
+        doc["params"],
+        serde_json::json!([
+            {"n": "value", "d": "The raw value"},
+            {"n": "fallback", "d": "Used when the value is missing"},
+        ])
+    );
+    assert_eq!(doc["ret"], "The port");
+    assert_eq!(
+        doc["throws"],
+        serde_json::json!([{"n": "RangeError", "d": "When the port is out of range"}])
+    );
+
+    // Google-style docstring sections
+    let (brief, doc) = detail(python_fixtures_path(), "sample.py", "adopt");
+    assert_eq!(brief["doc"], "Adopt an animal.");
+    assert_eq!(
+        doc["params"],
+        serde_json::json!([
+            {"n": "name", "d": "The animal to adopt."},
+            {"n": "owner", "d": "Who takes it home."},
+        ])
+    );
+    assert_eq!(doc["ret"], "True once the papers are signed.");
+    assert_eq!(
+        doc["throws"],
+        serde_json::json!([{"n": "ValueError", "d": "If the owner is empty."}])
+    );
+
+    // Paragraphs around Google and NumPy sections stay prose
+    let (brief, doc) = detail(python_fixtures_path(), "sample.py", "rehome");
+    assert_eq!(brief["doc"], "Move an animal to another shelter.");
+    assert_eq!(
+        doc["desc"],
+        "Shelters share one registry, so the animal keeps its records.\n\nMoving twice in a day is allowed."
+    );
+    assert_eq!(
+        doc["params"],
+        serde_json::json!([
+            {"n": "name", "d": "The animal to move."},
+            {"n": "shelter", "d": "Where it goes."},
+        ])
+    );
+    assert_eq!(doc["ret"], "The new shelter's address.");
+
+    let (brief, doc) = detail(python_fixtures_path(), "sample.py", "weigh");
+    assert_eq!(brief["doc"], "Weigh an animal.");
+    assert_eq!(
+        doc["desc"],
+        "Scales are calibrated every morning.\n\nReadings are rounded to one decimal."
+    );
+    assert_eq!(
+        doc["params"],
+        serde_json::json!([
+            {"n": "name", "d": "The animal on the scale."},
+            {"n": "unit", "d": "Kilograms or pounds."},
+        ])
+    );
+}
+
+// ==================== Ruby Tests ====================
+
+#[test]
+fn test_ruby_index_repository() {
+    let path = ruby_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 2, "Should index 2 Ruby files");
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+}
+
+#[test]
+fn test_ruby_class_with_methods() {
+    let (storage, path_str) = index_lang_fixtures(ruby_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.rb")
+        .expect("get_file_symbols failed");
+
+    let animal = symbols
+        .iter()
+        .find(|s| s.name == "Animal")
+        .expect("Should find 'Animal'");
+    assert_eq!(animal.kind, "class");
+
+    let children: Vec<_> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(animal.id))
+        .collect();
+    let method_names: Vec<&str> = children.iter().map(|s| s.name.as_str()).collect();
+    assert!(
+        method_names.contains(&"speak"),
+        "Animal should have 'speak' method, got: {:?}",
+        method_names
+    );
+    assert!(
+        method_names.contains(&"initialize"),
+        "Animal should have 'initialize' method, got: {:?}",
+        method_names
+    );
+}
+
+#[test]
+fn test_ruby_inheritance() {
+    let (storage, path_str) = index_lang_fixtures(ruby_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.rb")
+        .expect("get_file_symbols failed");
+
+    let dog = symbols
+        .iter()
+        .find(|s| s.name == "Dog")
+        .expect("Should find 'Dog'");
+    assert_eq!(dog.kind, "class");
+
+    let refs = storage
+        .get_dependencies(&path_str, dog.id)
+        .expect("get_dependencies failed");
+    assert!(
+        refs.iter().any(|r| r.to_name == "Animal"),
+        "Dog should extend Animal, got: {:?}",
+        refs.iter().map(|r| &r.to_name).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_ruby_module() {
+    let (storage, path_str) = index_lang_fixtures(ruby_fixtures_path());
 
     let symbols = storage
         .get_file_symbols(&path_str, "sample.rb")
@@ -944,6 +2022,135 @@ fn test_ruby_constants() {
     );
 }
 
+#[test]
+fn test_ruby_mixins_attrs_and_rails_dsl() {
+    let (storage, path_str) = index_lang_fixtures(ruby_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "post.rb")
+        .expect("get_file_symbols failed");
+    let post = symbols
+        .iter()
+        .find(|s| s.name == "Post")
+        .expect("Should find 'Post'");
+    let member = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name && s.parent_symbol_id == Some(post.id))
+            .unwrap_or_else(|| panic!("Post should have '{name}'"))
+    };
+    let deps = |id: i64| {
+        storage
+            .get_dependencies(&path_str, id)
+            .expect("get_dependencies failed")
+    };
+    let dep_names = |id: i64, kind: &str| -> Vec<String> {
+        deps(id)
+            .into_iter()
+            .filter(|r| r.ref_kind == kind)
+            .map(|r| r.to_name)
+            .collect()
+    };
+
+    assert_eq!(
+        dep_names(post.id, "mixin"),
+        vec!["Auditing", "Publishable", "Searchable"]
+    );
+    let publishable = symbols
+        .iter()
+        .find(|s| s.name == "Publishable")
+        .expect("Should find 'Publishable'");
+    assert!(
+        deps(post.id)
+            .iter()
+            .any(|r| r.to_name == "Publishable" && r.to_symbol_id == Some(publishable.id))
+    );
+
+    for (name, signature) in [
+        ("preview", "attr_accessor"),
+        ("draft_notes", "attr_accessor"),
+        ("word_count", "attr_reader"),
+    ] {
+        let attr = member(name);
+        assert_eq!(attr.kind, "var");
+        assert_eq!(attr.signature.as_deref(), Some(signature));
+    }
+    assert_eq!(
+        member("preview").doc_comment.as_deref(),
+        Some("Rendered excerpt, not persisted")
+    );
+
+    // Associations link to their model, by class_name or by convention
+    for (name, dsl, model) in [
+        ("author", "belongs_to", "User"),
+        ("comments", "has_many", "Comment"),
+        ("categories", "has_many", "Category"),
+        ("cover_image", "has_one", "CoverImage"),
+    ] {
+        let association = member(name);
+        assert_eq!(association.kind, "var");
+        assert_eq!(
+            association.metadata,
+            vec![("dsl".to_string(), dsl.to_string())]
+        );
+        assert_eq!(dep_names(association.id, "type_ref"), vec![model]);
+    }
+    assert!(
+        dep_names(member("commentable").id, "type_ref").is_empty(),
+        "Polymorphic associations have no single model"
+    );
+    let user = symbols
+        .iter()
+        .find(|s| s.name == "User")
+        .expect("Should find 'User'");
+    assert!(
+        deps(member("author").id)
+            .iter()
+            .any(|r| r.to_symbol_id == Some(user.id))
+    );
+
+    let published = member("published");
+    assert_eq!(published.kind, "method");
+    assert_eq!(
+        published.doc_comment.as_deref(),
+        Some("Published posts, newest first")
+    );
+    assert!(dep_names(published.id, "call").contains(&"where".to_string()));
+
+    let validate = member("validate");
+    assert_eq!(dep_names(validate.id, "call"), vec!["title_is_unique"]);
+    assert!(dep_names(member("validates").id, "call").is_empty());
+    let callback = member("before_save");
+    assert_eq!(
+        callback.signature.as_deref(),
+        Some("before_save :normalize_title, :count_words")
+    );
+    let normalize = member("normalize_title");
+    assert!(
+        deps(callback.id)
+            .iter()
+            .any(|r| r.ref_kind == "call" && r.to_symbol_id == Some(normalize.id)),
+        "Callbacks should call the methods they name"
+    );
+
+    let associations = storage
+        .find_symbols_by_meta(
+            &path_str,
+            &[storage::MetaFilter::parse("dsl=has_many")],
+            None,
+            None,
+            100,
+        )
+        .expect("find_symbols_by_meta failed");
+    assert_eq!(
+        associations
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["comments", "categories", "posts"]
+    );
+}
+
 // ==================== Code-Aware Search Tests ====================
 
 #[test]
@@ -956,139 +2163,3438 @@ fn test_search_camel_case_subword() {
         .expect("search failed");
     let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
     assert!(
-        names
-            .iter()
-            .any(|n| n.contains("User") || n.contains("user")),
-        "Searching 'user' should find camelCase/PascalCase symbols containing 'user', got: {:?}",
-        names
+        names
+            .iter()
+            .any(|n| n.contains("User") || n.contains("user")),
+        "Searching 'user' should find camelCase/PascalCase symbols containing 'user', got: {:?}",
+        names
+    );
+}
+
+#[test]
+fn test_search_finds_pascal_case_class() {
+    let (storage, path_str) = index_fixtures();
+
+    // Searching for "repository" should find "UserRepository" and "AdminUserRepository"
+    let results = storage
+        .search_symbols(&path_str, "repository", 20)
+        .expect("search failed");
+    let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+    assert!(
+        names.contains(&"UserRepository"),
+        "Searching 'repository' should find 'UserRepository', got: {:?}",
+        names
+    );
+}
+
+#[test]
+fn test_search_finds_snake_case_parts() {
+    let (storage, path_str) = index_lang_fixtures(python_fixtures_path());
+
+    // Searching for "retries" should find "MAX_RETRIES"
+    let results = storage
+        .search_symbols(&path_str, "retries", 20)
+        .expect("search failed");
+    let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+    assert!(
+        names.contains(&"MAX_RETRIES"),
+        "Searching 'retries' should find 'MAX_RETRIES', got: {:?}",
+        names
+    );
+}
+
+#[test]
+fn test_search_prefix_on_subwords() {
+    let (storage, path_str) = index_fixtures();
+
+    // Prefix search "repo*" should find UserRepository via name_tokens
+    let results = storage
+        .search_symbols(&path_str, "repo*", 20)
+        .expect("search failed");
+    let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+    assert!(
+        names.iter().any(|n| n.contains("Repository")),
+        "Prefix search 'repo*' should find Repository symbols, got: {:?}",
+        names
+    );
+}
+
+// ==================== Markdown Tests ====================
+
+#[test]
+fn test_markdown_index_repository() {
+    let path = markdown_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 1, "Should index 1 Markdown file");
+    assert!(stats.symbols_count > 0, "Should extract sections");
+}
+
+#[test]
+fn test_markdown_heading_extraction() {
+    let (storage, path_str) = index_lang_fixtures(markdown_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.md")
+        .expect("get_file_symbols failed");
+
+    let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+    assert!(
+        names.contains(&"Project Overview"),
+        "Should find 'Project Overview' heading, got: {:?}",
+        names
+    );
+    assert!(
+        names.contains(&"Installation"),
+        "Should find 'Installation' heading, got: {:?}",
+        names
+    );
+}
+
+#[test]
+fn test_markdown_heading_hierarchy() {
+    let (storage, path_str) = index_lang_fixtures(markdown_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.md")
+        .expect("get_file_symbols failed");
+
+    // H1 "Project Overview" should be top-level
+    let overview = symbols
+        .iter()
+        .find(|s| s.name == "Project Overview")
+        .expect("Should find 'Project Overview'");
+    assert_eq!(overview.kind, "section");
+    assert!(
+        overview.parent_symbol_id.is_none(),
+        "H1 should be top-level (no parent)"
+    );
+
+    // H2 "Installation" should be a child of H1
+    let installation = symbols
+        .iter()
+        .find(|s| s.name == "Installation")
+        .expect("Should find 'Installation'");
+    assert_eq!(
+        installation.parent_symbol_id,
+        Some(overview.id),
+        "H2 'Installation' should be child of H1 'Project Overview'"
+    );
+
+    // H3 "Prerequisites" should be a child of H2 "Installation"
+    let prereqs = symbols
+        .iter()
+        .find(|s| s.name == "Prerequisites")
+        .expect("Should find 'Prerequisites'");
+    assert_eq!(
+        prereqs.parent_symbol_id,
+        Some(installation.id),
+        "H3 'Prerequisites' should be child of H2 'Installation'"
+    );
+}
+
+#[test]
+fn test_markdown_code_fences_links_and_front_matter() {
+    let (storage, path_str) = index_lang_fixtures(rust_fixtures_path());
+
+    let doc = storage
+        .get_file_symbols(&path_str, "docs/geometry.md")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        doc.iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+
+    // Front matter describes the document's first section; list items repeat the key
+    let geometry = find("Geometry");
+    assert_eq!(
+        geometry.metadata,
+        vec![
+            ("title".to_string(), "Geometry primitives".to_string()),
+            ("status".to_string(), "accepted".to_string()),
+            ("tags".to_string(), "geometry".to_string()),
+            ("tags".to_string(), "math".to_string()),
+            ("authors".to_string(), "Ada".to_string()),
+            ("authors".to_string(), "Grace".to_string()),
+        ]
+    );
+
+    // Fenced code blocks are children of their section, named by language
+    let distances = find("Distances");
+    let rust_block = find("rust");
+    assert_eq!(rust_block.kind, "code");
+    assert_eq!(rust_block.parent_symbol_id, Some(distances.id));
+    assert_eq!(
+        rust_block.signature.as_deref(),
+        Some("let d = distance(&a, &b);")
+    );
+    assert_eq!(find("code").parent_symbol_id, Some(find("Examples").id));
+
+    // Backticked identifiers name symbols; commands, anchors and words that name no
+    // symbol (`unsafe`) aren't mentions
+    let mentions = |sym: &ctxhelpr::storage::SymbolRecord| -> Vec<String> {
+        storage
+            .get_dependencies(&path_str, sym.id)
+            .expect("get_dependencies failed")
+            .into_iter()
+            .filter(|r| r.ref_kind == "mention")
+            .map(|r| r.to_name)
+            .collect()
+    };
+    assert_eq!(
+        mentions(distances),
+        vec!["HasArea", "Point", "area", "distance", "helper"]
+    );
+    // Intra-repo links resolve relative to the doc, anchors dropped; URLs are skipped
+    assert_eq!(mentions(geometry), vec!["Point", "sample.rs", "sample.rs"]);
+
+    let code = storage
+        .get_file_symbols(&path_str, "sample.rs")
+        .expect("get_file_symbols failed");
+    let link = storage
+        .get_dependencies(&path_str, geometry.id)
+        .expect("get_dependencies failed")
+        .into_iter()
+        .find(|r| r.to_name == "sample.rs")
+        .expect("Should link to sample.rs");
+    let target = link.to_symbol_id.expect("File link should resolve");
+    assert!(code.iter().any(|s| s.id == target));
+
+    // `get_references` on a function shows the docs that mention it
+    let distance = code
+        .iter()
+        .find(|s| s.name == "distance")
+        .expect("Should find distance");
+    let mentioned_by: Vec<String> = storage
+        .get_references(&path_str, distance.id)
+        .expect("get_references failed")
+        .into_iter()
+        .filter(|r| r.ref_kind == "mention")
+        .filter_map(|r| r.from_name)
+        .collect();
+    assert_eq!(mentioned_by, vec!["Distances"]);
+}
+
+// ==================== Go Tests ====================
+
+#[test]
+fn test_go_index_repository() {
+    let path = go_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 1, "Should index 1 Go file");
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+}
+
+#[test]
+fn test_go_package_imports() {
+    let (storage, path_str) = index_lang_fixtures(go_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.go")
+        .expect("get_file_symbols failed");
+
+    let package = symbols
+        .iter()
+        .find(|s| s.name == "sample")
+        .expect("Should find 'sample' package");
+    assert_eq!(package.kind, "mod");
+    assert!(
+        package
+            .doc_comment
+            .as_deref()
+            .unwrap_or("")
+            .contains("demonstrates"),
+        "Package should have doc comment"
+    );
+
+    let deps = storage
+        .get_dependencies(&path_str, package.id)
+        .expect("get_dependencies failed");
+    let imports: Vec<&str> = deps
+        .iter()
+        .filter(|r| r.ref_kind == "import")
+        .map(|r| r.to_name.as_str())
+        .collect();
+    assert!(
+        imports.contains(&"fmt"),
+        "Should import fmt, got: {:?}",
+        imports
+    );
+    assert!(
+        imports.contains(&"strings"),
+        "Aliased import should keep its path, got: {:?}",
+        imports
+    );
+    assert!(
+        imports.contains(&"errors"),
+        "Should import errors, got: {:?}",
+        imports
+    );
+}
+
+#[test]
+fn test_go_struct_with_fields() {
+    let (storage, path_str) = index_lang_fixtures(go_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.go")
+        .expect("get_file_symbols failed");
+
+    let point = symbols
+        .iter()
+        .find(|s| s.name == "Point")
+        .expect("Should find 'Point'");
+    assert_eq!(point.kind, "struct");
+    assert!(
+        point
+            .doc_comment
+            .as_deref()
+            .unwrap_or("")
+            .contains("2D space"),
+        "Point should have doc comment"
+    );
+
+    let field_names: Vec<&str> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(point.id))
+        .map(|s| s.name.as_str())
+        .collect();
+    assert!(field_names.contains(&"X"), "Point should have 'X' field");
+    assert!(field_names.contains(&"Y"), "Point should have 'Y' field");
+    assert!(
+        field_names.contains(&"Label"),
+        "Point should have 'Label' field"
+    );
+}
+
+#[test]
+fn test_go_methods_nested_under_receiver() {
+    let (storage, path_str) = index_lang_fixtures(go_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.go")
+        .expect("get_file_symbols failed");
+
+    let circle = symbols
+        .iter()
+        .find(|s| s.name == "Circle")
+        .expect("Should find 'Circle'");
+
+    let methods: Vec<_> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(circle.id) && s.kind == "method")
+        .collect();
+    let method_names: Vec<&str> = methods.iter().map(|s| s.name.as_str()).collect();
+    assert!(
+        method_names.contains(&"Area"),
+        "Pointer receiver method should nest under Circle, got: {:?}",
+        method_names
+    );
+    assert!(
+        method_names.contains(&"Perimeter"),
+        "Value receiver method should nest under Circle, got: {:?}",
+        method_names
+    );
+
+    let area = methods.iter().find(|s| s.name == "Area").unwrap();
+    assert!(
+        area.signature.as_deref().unwrap_or("").contains("*Circle"),
+        "Method signature should include receiver"
+    );
+
+    let deps = storage
+        .get_dependencies(&path_str, circle.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Point" && r.ref_kind == "extends"),
+        "Embedded Point should be recorded, got: {:?}",
+        deps.iter().map(|r| &r.to_name).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_go_interface_method_set() {
+    let (storage, path_str) = index_lang_fixtures(go_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.go")
+        .expect("get_file_symbols failed");
+
+    let shape = symbols
+        .iter()
+        .find(|s| s.name == "Shape")
+        .expect("Should find 'Shape'");
+    assert_eq!(shape.kind, "interface");
+
+    let method_names: Vec<&str> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(shape.id))
+        .map(|s| s.name.as_str())
+        .collect();
+    assert!(method_names.contains(&"Area"));
+    assert!(method_names.contains(&"Perimeter"));
+
+    let named = symbols
+        .iter()
+        .find(|s| s.name == "Named")
+        .expect("Should find 'Named'");
+    let deps = storage
+        .get_dependencies(&path_str, named.id)
+        .expect("get_dependencies failed");
+    let shape_dep = deps
+        .iter()
+        .find(|r| r.to_name == "Shape")
+        .expect("Named should embed Shape");
+    assert_eq!(shape_dep.to_symbol_id, Some(shape.id));
+}
+
+#[test]
+fn test_go_consts_vars_and_types() {
+    let (storage, path_str) = index_lang_fixtures(go_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.go")
+        .expect("get_file_symbols failed");
+
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+    assert_eq!(find("MaxRetries").kind, "const");
+    assert!(find("MaxRetries").doc_comment.is_some());
+    assert_eq!(find("DefaultName").kind, "const");
+    assert_eq!(find("defaultPort").kind, "const");
+    assert_eq!(find("ErrNotFound").kind, "var");
+    assert_eq!(find("ID").kind, "type");
+}
+
+#[test]
+fn test_go_calls_and_type_refs() {
+    let (storage, path_str) = index_lang_fixtures(go_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "sample.go")
+        .expect("get_file_symbols failed");
+
+    let describe = symbols
+        .iter()
+        .find(|s| s.name == "Describe")
+        .expect("Should find 'Describe'");
+    let deps = storage
+        .get_dependencies(&path_str, describe.id)
+        .expect("get_dependencies failed");
+
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "fmt.Sprintf" && r.ref_kind == "call"),
+        "Describe should call fmt.Sprintf"
+    );
+    let shape_ref = deps
+        .iter()
+        .find(|r| r.to_name == "Shape" && r.ref_kind == "type_ref")
+        .expect("Describe should reference Shape in its parameters");
+    assert!(shape_ref.to_symbol_id.is_some());
+    assert!(
+        !deps.iter().any(|r| r.to_name == "string"),
+        "Builtin types should not become refs"
+    );
+
+    let new_circle = symbols
+        .iter()
+        .find(|s| s.name == "NewCircle")
+        .expect("Should find 'NewCircle'");
+    let deps = storage
+        .get_dependencies(&path_str, new_circle.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Circle" && r.ref_kind == "type_ref"),
+        "NewCircle should reference Circle in its result type"
+    );
+}
+
+// ==================== Java Tests ====================
+
+#[test]
+fn test_java_index_repository() {
+    let path = java_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 1, "Should index 1 Java file");
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+}
+
+#[test]
+fn test_java_package_imports() {
+    let (storage, path_str) = index_lang_fixtures(java_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Sample.java")
+        .expect("get_file_symbols failed");
+
+    let package = symbols
+        .iter()
+        .find(|s| s.name == "com.example.shapes")
+        .expect("Should find package symbol");
+    assert_eq!(package.kind, "mod");
+
+    let deps = storage
+        .get_dependencies(&path_str, package.id)
+        .expect("get_dependencies failed");
+    let imports: Vec<&str> = deps
+        .iter()
+        .filter(|r| r.ref_kind == "import")
+        .map(|r| r.to_name.as_str())
+        .collect();
+    assert!(
+        imports.contains(&"java.util.List"),
+        "Should import java.util.List, got: {:?}",
+        imports
+    );
+    assert!(
+        imports.contains(&"java.lang.Math.sqrt"),
+        "Static import should keep its path, got: {:?}",
+        imports
+    );
+    assert!(
+        imports.contains(&"com.example.util.*"),
+        "Wildcard import should be recorded, got: {:?}",
+        imports
+    );
+}
+
+#[test]
+fn test_java_class_hierarchy() {
+    let (storage, path_str) = index_lang_fixtures(java_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Sample.java")
+        .expect("get_file_symbols failed");
+
+    let circle = symbols
+        .iter()
+        .find(|s| s.name == "Circle" && s.kind == "class")
+        .expect("Should find 'Circle' class");
+    assert_eq!(circle.doc_comment.as_deref(), Some("A circle."));
+
+    let deps = storage
+        .get_dependencies(&path_str, circle.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "BaseShape" && r.ref_kind == "extends"),
+        "Circle should extend BaseShape"
+    );
+    let shape = symbols
+        .iter()
+        .find(|s| s.name == "Shape")
+        .expect("Should find 'Shape'");
+    assert_eq!(shape.kind, "interface");
+    let shape_dep = deps
+        .iter()
+        .find(|r| r.to_name == "Shape" && r.ref_kind == "implements")
+        .expect("Circle should implement Shape");
+    assert_eq!(shape_dep.to_symbol_id, Some(shape.id));
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Serializable" && r.ref_kind == "implements"),
+        "Circle should implement Serializable"
+    );
+
+    let shape_deps = storage
+        .get_dependencies(&path_str, shape.id)
+        .expect("get_dependencies failed");
+    assert!(
+        shape_deps
+            .iter()
+            .any(|r| r.to_name == "Comparable" && r.ref_kind == "extends"),
+        "Interfaces extend their super-interfaces"
+    );
+}
+
+#[test]
+fn test_java_members() {
+    let (storage, path_str) = index_lang_fixtures(java_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Sample.java")
+        .expect("get_file_symbols failed");
+
+    let circle = symbols
+        .iter()
+        .find(|s| s.name == "Circle" && s.kind == "class")
+        .expect("Should find 'Circle' class");
+    let members: Vec<_> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(circle.id))
+        .collect();
+    let find = |name: &str, kind: &str| {
+        members
+            .iter()
+            .find(|s| s.name == name && s.kind == kind)
+            .unwrap_or_else(|| panic!("Circle should have {kind} '{name}'"))
+    };
+
+    assert_eq!(
+        find("DEFAULT_RADIUS", "const").signature.as_deref(),
+        Some("double")
+    );
+    assert_eq!(find("radius", "var").signature.as_deref(), Some("double"));
+    assert_eq!(
+        find("Circle", "method").signature.as_deref(),
+        Some("(double radius)")
+    );
+    assert_eq!(
+        find("square", "method").signature.as_deref(),
+        Some("(double x) double")
+    );
+    find("area", "method");
+    find("Builder", "class");
+}
+
+#[test]
+fn test_java_enum_record_and_annotation() {
+    let (storage, path_str) = index_lang_fixtures(java_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Sample.java")
+        .expect("get_file_symbols failed");
+
+    let color = symbols
+        .iter()
+        .find(|s| s.name == "Color")
+        .expect("Should find 'Color'");
+    assert_eq!(color.kind, "enum");
+    let constants: Vec<&str> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(color.id) && s.kind == "const")
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(constants, vec!["RED", "GREEN"]);
+    assert!(
+        symbols
+            .iter()
+            .any(|s| s.parent_symbol_id == Some(color.id) && s.name == "lower"),
+        "Enum methods should be children of the enum"
+    );
+
+    let point = symbols
+        .iter()
+        .find(|s| s.name == "Point")
+        .expect("Should find 'Point'");
+    assert_eq!(point.kind, "struct");
+    assert_eq!(point.signature.as_deref(), Some("(double x, double y)"));
+    let components: Vec<&str> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(point.id) && s.kind == "var")
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(components, vec!["x", "y"]);
+
+    let audited = symbols
+        .iter()
+        .find(|s| s.name == "Audited")
+        .expect("Should find 'Audited'");
+    assert_eq!(audited.kind, "interface");
+    assert_eq!(audited.signature.as_deref(), Some("@interface"));
+
+    let refs = storage
+        .get_references(&path_str, audited.id)
+        .expect("get_references failed");
+    assert!(
+        refs.iter().any(|r| r.from_name.as_deref() == Some("Point")),
+        "Annotation usage should reference the annotation type"
+    );
+}
+
+#[test]
+fn test_java_calls() {
+    let (storage, path_str) = index_lang_fixtures(java_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Sample.java")
+        .expect("get_file_symbols failed");
+
+    let build = symbols
+        .iter()
+        .find(|s| s.name == "build")
+        .expect("Should find 'build'");
+    let deps = storage
+        .get_dependencies(&path_str, build.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Circle" && r.ref_kind == "call"),
+        "Constructor invocation should be a call"
+    );
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Circle" && r.ref_kind == "type_ref"),
+        "Return type should be a type ref"
+    );
+
+    let area = symbols
+        .iter()
+        .find(|s| s.name == "area" && s.kind == "method" && s.start_line > 20)
+        .expect("Should find Circle.area");
+    let deps = storage
+        .get_dependencies(&path_str, area.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "square" && r.ref_kind == "call"),
+        "area should call square"
+    );
+}
+
+// ==================== Kotlin Tests ====================
+
+#[test]
+fn test_kotlin_index_repository() {
+    let path = kotlin_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 1, "Should index 1 Kotlin file");
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+}
+
+#[test]
+fn test_kotlin_package_imports() {
+    let (storage, path_str) = index_lang_fixtures(kotlin_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Sample.kt")
+        .expect("get_file_symbols failed");
+
+    let package = symbols
+        .iter()
+        .find(|s| s.name == "com.example.shapes")
+        .expect("Should find package symbol");
+    assert_eq!(package.kind, "mod");
+
+    let deps = storage
+        .get_dependencies(&path_str, package.id)
+        .expect("get_dependencies failed");
+    let imports: Vec<&str> = deps
+        .iter()
+        .filter(|r| r.ref_kind == "import")
+        .map(|r| r.to_name.as_str())
+        .collect();
+    assert!(
+        imports.contains(&"kotlin.math.PI"),
+        "Should import kotlin.math.PI, got: {:?}",
+        imports
+    );
+    assert!(
+        imports.contains(&"kotlin.math.sqrt"),
+        "Aliased import should keep its path, got: {:?}",
+        imports
+    );
+    assert!(
+        imports.contains(&"com.example.util.*"),
+        "Wildcard import should be recorded, got: {:?}",
+        imports
+    );
+}
+
+#[test]
+fn test_kotlin_class_hierarchy() {
+    let (storage, path_str) = index_lang_fixtures(kotlin_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Sample.kt")
+        .expect("get_file_symbols failed");
+
+    let circle = symbols
+        .iter()
+        .find(|s| s.name == "Circle" && s.kind == "class")
+        .expect("Should find 'Circle' class");
+    assert_eq!(circle.doc_comment.as_deref(), Some("A circle."));
+    assert_eq!(circle.signature.as_deref(), Some("(val radius: Double)"));
+
+    let deps = storage
+        .get_dependencies(&path_str, circle.id)
+        .expect("get_dependencies failed");
+    let base = symbols
+        .iter()
+        .find(|s| s.name == "BaseShape")
+        .expect("Should find 'BaseShape'");
+    let base_dep = deps
+        .iter()
+        .find(|r| r.to_name == "BaseShape" && r.ref_kind == "extends")
+        .expect("Superclass constructor call should be an extends ref");
+    assert_eq!(base_dep.to_symbol_id, Some(base.id));
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Shape" && r.ref_kind == "implements"),
+        "Circle should implement Shape"
+    );
+
+    let shape = symbols
+        .iter()
+        .find(|s| s.name == "Shape")
+        .expect("Should find 'Shape'");
+    assert_eq!(shape.kind, "interface");
+    let shape_deps = storage
+        .get_dependencies(&path_str, shape.id)
+        .expect("get_dependencies failed");
+    assert!(
+        shape_deps
+            .iter()
+            .any(|r| r.to_name == "Comparable" && r.ref_kind == "extends"),
+        "Interfaces extend their super-interfaces"
+    );
+}
+
+#[test]
+fn test_kotlin_members_and_companion() {
+    let (storage, path_str) = index_lang_fixtures(kotlin_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Sample.kt")
+        .expect("get_file_symbols failed");
+
+    let circle = symbols
+        .iter()
+        .find(|s| s.name == "Circle" && s.kind == "class")
+        .expect("Should find 'Circle' class");
+    let members: Vec<_> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(circle.id))
+        .collect();
+    let find = |name: &str, kind: &str| {
+        members
+            .iter()
+            .find(|s| s.name == name && s.kind == kind)
+            .unwrap_or_else(|| panic!("Circle should have {kind} '{name}'"))
+    };
+
+    assert_eq!(find("radius", "var").signature.as_deref(), Some("Double"));
+    assert_eq!(find("label", "var").signature.as_deref(), Some("String"));
+    assert_eq!(
+        find("square", "method").signature.as_deref(),
+        Some("(x: Double): Double")
+    );
+    find("area", "method");
+
+    let factory = find("Factory", "class");
+    assert_eq!(factory.signature.as_deref(), Some("companion object"));
+    let factory_members: Vec<(&str, &str)> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(factory.id))
+        .map(|s| (s.name.as_str(), s.kind.as_str()))
+        .collect();
+    assert!(factory_members.contains(&("DEFAULT_RADIUS", "const")));
+    assert!(factory_members.contains(&("unit", "method")));
+}
+
+#[test]
+fn test_kotlin_objects_enums_and_functions() {
+    let (storage, path_str) = index_lang_fixtures(kotlin_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Sample.kt")
+        .expect("get_file_symbols failed");
+
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+
+    let registry = find("Registry");
+    assert_eq!(registry.kind, "class");
+    assert_eq!(registry.signature.as_deref(), Some("object"));
+
+    let color = find("Color");
+    assert_eq!(color.kind, "enum");
+    let entries: Vec<&str> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(color.id) && s.kind == "const")
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(entries, vec!["RED", "GREEN"]);
+
+    let point = find("Point");
+    assert_eq!(point.kind, "class");
+    assert_eq!(
+        point.signature.as_deref(),
+        Some("(val x: Double, val y: Double)")
+    );
+
+    let describe = find("describe");
+    assert_eq!(describe.kind, "fn");
+    assert_eq!(describe.doc_comment.as_deref(), Some("Top-level helper."));
+    let deps = storage
+        .get_dependencies(&path_str, describe.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Shape" && r.ref_kind == "type_ref"),
+        "describe should reference Shape in its parameters"
+    );
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "shape.area" && r.ref_kind == "call"),
+        "describe should call shape.area"
+    );
+    assert!(
+        !deps.iter().any(|r| r.to_name == "String"),
+        "Builtin types should not become refs"
+    );
+
+    assert_eq!(find("DEFAULT_SHAPE").kind, "var");
+}
+
+// ==================== C/C++ Tests ====================
+
+#[test]
+fn test_cpp_index_repository() {
+    let path = cpp_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 4, "Should index 1 C and 3 C++ files");
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+
+    // Headers are recorded as C++, the grammar they're parsed with
+    let overview = storage.get_overview(path_str).expect("get_overview failed");
+    let mut languages = overview.languages.clone();
+    languages.sort();
+    assert_eq!(
+        languages,
+        vec![("c".to_string(), 1), ("cpp".to_string(), 3)]
+    );
+}
+
+#[test]
+fn test_c_header_symbols() {
+    let (storage, path_str) = index_lang_fixtures(cpp_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "shapes.h")
+        .expect("get_file_symbols failed");
+
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+
+    assert!(
+        !symbols.iter().any(|s| s.name == "SHAPES_H"),
+        "Include guard should not become a symbol"
+    );
+    assert_eq!(find("MAX_SHAPES").kind, "macro");
+    assert_eq!(find("MAX_SHAPES").signature.as_deref(), Some("64"));
+    assert_eq!(find("SQUARE").signature.as_deref(), Some("(x)"));
+
+    let point = find("Point");
+    assert_eq!(
+        point.kind, "struct",
+        "Anonymous typedef'd struct takes the typedef name"
+    );
+    assert_eq!(point.doc_comment.as_deref(), Some("A point in 2D space."));
+    let fields: Vec<&str> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(point.id))
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(fields, vec!["x", "y"]);
+
+    let kind = find("ShapeKind");
+    assert_eq!(kind.kind, "enum");
+    assert_eq!(
+        kind.doc_comment.as_deref(),
+        Some("Kinds of supported shapes.")
+    );
+    assert_eq!(
+        symbols.iter().filter(|s| s.name == "Shape").count(),
+        1,
+        "`typedef struct Shape Shape;` should not duplicate the struct"
+    );
+
+    let proto = find("shape_area");
+    assert_eq!(proto.kind, "fn");
+    assert_eq!(
+        proto.signature.as_deref(),
+        Some("(const Shape *shape) double")
+    );
+}
+
+#[test]
+fn test_c_includes() {
+    let (storage, path_str) = index_lang_fixtures(cpp_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "shapes.c")
+        .expect("get_file_symbols failed");
+    let imports_sym = symbols
+        .iter()
+        .find(|s| s.name == "_imports")
+        .expect("Should have an _imports symbol");
+
+    let deps = storage
+        .get_dependencies(&path_str, imports_sym.id)
+        .expect("get_dependencies failed");
+    let imports: Vec<&str> = deps
+        .iter()
+        .filter(|r| r.ref_kind == "import")
+        .map(|r| r.to_name.as_str())
+        .collect();
+    assert_eq!(imports, vec!["math.h", "shapes.h"]);
+}
+
+#[test]
+fn test_c_prototype_links_to_definition() {
+    let (storage, path_str) = index_lang_fixtures(cpp_fixtures_path());
+
+    let header = storage
+        .get_file_symbols(&path_str, "shapes.h")
+        .expect("get_file_symbols failed");
+    let source = storage
+        .get_file_symbols(&path_str, "shapes.c")
+        .expect("get_file_symbols failed");
+
+    for (name, kind) in [
+        ("shape_area", "fn"),
+        ("point_distance", "fn"),
+        ("shape_count", "var"),
+    ] {
+        let decl = header
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Header should declare '{name}'"));
+        let def = source
+            .iter()
+            .find(|s| s.name == name && s.kind == kind)
+            .unwrap_or_else(|| panic!("Source should define '{name}'"));
+
+        let deps = storage
+            .get_dependencies(&path_str, decl.id)
+            .expect("get_dependencies failed");
+        let link = deps
+            .iter()
+            .find(|r| r.ref_kind == "definition")
+            .unwrap_or_else(|| panic!("'{name}' should have a definition ref"));
+        assert_eq!(
+            link.to_symbol_id,
+            Some(def.id),
+            "'{name}' should link to its body"
+        );
+    }
+
+    // Calls resolve to the body rather than the prototype
+    let shape_area = source
+        .iter()
+        .find(|s| s.name == "shape_area")
+        .expect("Should find shape_area");
+    let deps = storage
+        .get_dependencies(&path_str, shape_area.id)
+        .expect("get_dependencies failed");
+    let circle_area = source
+        .iter()
+        .find(|s| s.name == "circle_area")
+        .expect("Should find circle_area");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "circle_area" && r.to_symbol_id == Some(circle_area.id)),
+        "shape_area should call circle_area"
+    );
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Shape" && r.ref_kind == "type_ref"),
+        "shape_area should reference Shape"
+    );
+}
+
+#[test]
+fn test_cpp_namespaces_and_classes() {
+    let (storage, path_str) = index_lang_fixtures(cpp_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "geometry.hpp")
+        .expect("get_file_symbols failed");
+
+    let geo = symbols
+        .iter()
+        .find(|s| s.name == "geo")
+        .expect("Should find namespace 'geo'");
+    assert_eq!(geo.kind, "mod");
+
+    let circle = symbols
+        .iter()
+        .find(|s| s.name == "Circle" && s.kind == "class")
+        .expect("Should find class 'Circle'");
+    assert_eq!(circle.parent_symbol_id, Some(geo.id));
+    assert_eq!(
+        circle.doc_comment.as_deref(),
+        Some("A circle with a radius.")
+    );
+
+    let members: Vec<(&str, &str)> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(circle.id))
+        .map(|s| (s.name.as_str(), s.kind.as_str()))
+        .collect();
+    assert!(
+        members.contains(&("Circle", "method")),
+        "got: {:?}",
+        members
+    );
+    assert!(members.contains(&("area", "method")), "got: {:?}", members);
+    assert!(
+        members.contains(&("describe", "method")),
+        "got: {:?}",
+        members
+    );
+    assert!(members.contains(&("radius_", "var")), "got: {:?}", members);
+
+    let deps = storage
+        .get_dependencies(&path_str, circle.id)
+        .expect("get_dependencies failed");
+    let figure = symbols
+        .iter()
+        .find(|s| s.name == "Figure")
+        .expect("Should find class 'Figure'");
+    let base = deps
+        .iter()
+        .find(|r| r.ref_kind == "extends")
+        .expect("Circle should extend Figure");
+    assert_eq!(base.to_symbol_id, Some(figure.id));
+
+    let clamp = symbols
+        .iter()
+        .find(|s| s.name == "clamp")
+        .expect("Should find template 'clamp'");
+    assert_eq!(
+        clamp.signature.as_deref(),
+        Some("template <typename T> (T value, T lo, T hi) T")
+    );
+    let clamp_deps = storage
+        .get_dependencies(&path_str, clamp.id)
+        .expect("get_dependencies failed");
+    assert!(
+        !clamp_deps.iter().any(|r| r.to_name == "T"),
+        "Template parameters should not become type refs"
+    );
+
+    let alias = symbols
+        .iter()
+        .find(|s| s.name == "ShapeList")
+        .expect("Should find 'ShapeList'");
+    assert_eq!(alias.kind, "type");
+}
+
+#[test]
+fn test_cpp_member_declarations_link_to_out_of_line_definitions() {
+    let (storage, path_str) = index_lang_fixtures(cpp_fixtures_path());
+
+    let header = storage
+        .get_file_symbols(&path_str, "geometry.hpp")
+        .expect("get_file_symbols failed");
+    let source = storage
+        .get_file_symbols(&path_str, "geometry.cpp")
+        .expect("get_file_symbols failed");
+
+    let circle = header
+        .iter()
+        .find(|s| s.name == "Circle" && s.kind == "class")
+        .expect("Should find class 'Circle'");
+    let decl = header
+        .iter()
+        .find(|s| s.name == "area" && s.parent_symbol_id == Some(circle.id))
+        .expect("Should find Circle::area declaration");
+    let def = source
+        .iter()
+        .find(|s| s.name == "Circle::area")
+        .expect("Should find Circle::area definition");
+    assert_eq!(def.kind, "method");
+
+    let deps = storage
+        .get_dependencies(&path_str, decl.id)
+        .expect("get_dependencies failed");
+    let link = deps
+        .iter()
+        .find(|r| r.ref_kind == "definition")
+        .expect("Declaration should have a definition ref");
+    assert_eq!(link.to_name, "geo::Circle::area");
+    assert_eq!(link.to_symbol_id, Some(def.id));
+
+    // Namespaced free function declared in the header, defined inside `namespace geo`
+    let total_decl = header
+        .iter()
+        .find(|s| s.name == "total_area")
+        .expect("Should find total_area declaration");
+    let total_def = source
+        .iter()
+        .find(|s| s.name == "total_area")
+        .expect("Should find total_area definition");
+    let deps = storage
+        .get_dependencies(&path_str, total_decl.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.ref_kind == "definition" && r.to_symbol_id == Some(total_def.id)),
+        "total_area prototype should link to its body"
+    );
+
+    // Pure virtual and defaulted members have nothing to link to
+    let figure = header
+        .iter()
+        .find(|s| s.name == "Figure")
+        .expect("Should find class 'Figure'");
+    let pure = header
+        .iter()
+        .find(|s| s.name == "area" && s.parent_symbol_id == Some(figure.id))
+        .expect("Should find Figure::area");
+    let deps = storage
+        .get_dependencies(&path_str, pure.id)
+        .expect("get_dependencies failed");
+    assert!(!deps.iter().any(|r| r.ref_kind == "definition"));
+}
+
+#[test]
+fn test_cpp_calls() {
+    let (storage, path_str) = index_lang_fixtures(cpp_fixtures_path());
+
+    let source = storage
+        .get_file_symbols(&path_str, "geometry.cpp")
+        .expect("get_file_symbols failed");
+
+    let describe = source
+        .iter()
+        .find(|s| s.name == "Circle::describe")
+        .expect("Should find Circle::describe");
+    let deps = storage
+        .get_dependencies(&path_str, describe.id)
+        .expect("get_dependencies failed");
+    let calls: Vec<&str> = deps
+        .iter()
+        .filter(|r| r.ref_kind == "call")
+        .map(|r| r.to_name.as_str())
+        .collect();
+    assert!(calls.contains(&"std::to_string"), "got: {:?}", calls);
+    assert!(calls.contains(&"this.area"), "got: {:?}", calls);
+
+    let total = source
+        .iter()
+        .find(|s| s.name == "total_area")
+        .expect("Should find total_area");
+    let deps = storage
+        .get_dependencies(&path_str, total.id)
+        .expect("get_dependencies failed");
+    assert!(deps.iter().any(|r| r.to_name == "shape.area"));
+    let clamp_call = deps
+        .iter()
+        .find(|r| r.to_name == "clamp")
+        .expect("total_area should call clamp");
+    assert!(clamp_call.to_symbol_id.is_some());
+}
+
+// ==================== C# Tests ====================
+
+#[test]
+fn test_csharp_index_repository() {
+    let path = csharp_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 2, "Should index 2 C# files");
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+}
+
+#[test]
+fn test_csharp_namespaces_and_usings() {
+    let (storage, path_str) = index_lang_fixtures(csharp_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Shapes.cs")
+        .expect("get_file_symbols failed");
+
+    let namespace = symbols
+        .iter()
+        .find(|s| s.name == "Example.Shapes")
+        .expect("Should find file-scoped namespace");
+    assert_eq!(namespace.kind, "mod");
+    let circle = symbols
+        .iter()
+        .find(|s| s.name == "Circle")
+        .expect("Should find 'Circle'");
+    assert_eq!(
+        circle.parent_symbol_id,
+        Some(namespace.id),
+        "Declarations after a file-scoped namespace belong to it"
+    );
+
+    let imports = symbols
+        .iter()
+        .find(|s| s.name == "_imports")
+        .expect("Should find _imports symbol");
+    let deps = storage
+        .get_dependencies(&path_str, imports.id)
+        .expect("get_dependencies failed");
+    let names: Vec<&str> = deps
+        .iter()
+        .filter(|r| r.ref_kind == "import")
+        .map(|r| r.to_name.as_str())
+        .collect();
+    assert!(
+        names.contains(&"System.Collections.Generic"),
+        "got: {:?}",
+        names
+    );
+    assert!(
+        names.contains(&"Example.Geometry"),
+        "Aliased using should import its target, got: {:?}",
+        names
+    );
+
+    let legacy = storage
+        .get_file_symbols(&path_str, "Legacy.cs")
+        .expect("get_file_symbols failed");
+    let block_ns = legacy
+        .iter()
+        .find(|s| s.name == "Example.Legacy")
+        .expect("Should find block namespace");
+    assert!(
+        legacy
+            .iter()
+            .any(|s| s.name == "OldCircle" && s.parent_symbol_id == Some(block_ns.id)),
+        "Classes should nest under their namespace"
+    );
+}
+
+#[test]
+fn test_csharp_xml_doc_comments() {
+    let (storage, path_str) = index_lang_fixtures(csharp_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Shapes.cs")
+        .expect("get_file_symbols failed");
+    let doc = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+            .doc_comment
+            .clone()
+    };
+
+    assert_eq!(
+        doc("IShape").as_deref(),
+        Some("Something that has an area.")
+    );
+    assert_eq!(
+        doc("Circle").as_deref(),
+        Some("A circle with a Radius."),
+        "<see cref> should be inlined"
+    );
+    assert_eq!(
+        doc("Area").as_deref(),
+        Some("Computes the area."),
+        "Only the summary should be kept"
+    );
+    assert_eq!(doc("BaseShape"), None);
+
+    let legacy = storage
+        .get_file_symbols(&path_str, "Legacy.cs")
+        .expect("get_file_symbols failed");
+    let old = legacy
+        .iter()
+        .find(|s| s.name == "OldCircle")
+        .expect("Should find 'OldCircle'");
+    assert_eq!(
+        old.doc_comment.as_deref(),
+        Some("Kept for callers that still pass a radius & nothing else.")
+    );
+}
+
+#[test]
+fn test_csharp_type_hierarchy() {
+    let (storage, path_str) = index_lang_fixtures(csharp_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Shapes.cs")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+
+    let circle = find("Circle");
+    assert_eq!(circle.kind, "class");
+    let deps = storage
+        .get_dependencies(&path_str, circle.id)
+        .expect("get_dependencies failed");
+    let base = deps
+        .iter()
+        .find(|r| r.to_name == "BaseShape" && r.ref_kind == "extends")
+        .expect("Circle should extend BaseShape");
+    assert_eq!(base.to_symbol_id, Some(find("BaseShape").id));
+    let ishape = deps
+        .iter()
+        .find(|r| r.to_name == "IShape" && r.ref_kind == "implements")
+        .expect("Circle should implement IShape");
+    assert_eq!(ishape.to_symbol_id, Some(find("IShape").id));
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "IDisposable" && r.ref_kind == "implements")
+    );
+
+    let shape_deps = storage
+        .get_dependencies(&path_str, find("IShape").id)
+        .expect("get_dependencies failed");
+    assert!(
+        shape_deps
+            .iter()
+            .any(|r| r.to_name == "IComparable" && r.ref_kind == "extends"),
+        "Interfaces extend their base interfaces"
+    );
+
+    let size = find("Size");
+    assert_eq!(size.kind, "struct");
+    let size_deps = storage
+        .get_dependencies(&path_str, size.id)
+        .expect("get_dependencies failed");
+    assert!(
+        size_deps
+            .iter()
+            .any(|r| r.to_name == "IEquatable" && r.ref_kind == "implements"),
+        "Structs implement their bases"
+    );
+
+    let legacy = storage
+        .get_file_symbols(&path_str, "Legacy.cs")
+        .expect("get_file_symbols failed");
+    let old = legacy
+        .iter()
+        .find(|s| s.name == "OldCircle")
+        .expect("Should find 'OldCircle'");
+    let old_deps = storage
+        .get_dependencies(&path_str, old.id)
+        .expect("get_dependencies failed");
+    let extends = old_deps
+        .iter()
+        .find(|r| r.ref_kind == "extends")
+        .expect("OldCircle should extend Circle");
+    assert_eq!(extends.to_symbol_id, Some(circle.id));
+}
+
+#[test]
+fn test_csharp_members() {
+    let (storage, path_str) = index_lang_fixtures(csharp_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Shapes.cs")
+        .expect("get_file_symbols failed");
+
+    let circle = symbols
+        .iter()
+        .find(|s| s.name == "Circle" && s.kind == "class")
+        .expect("Should find 'Circle' class");
+    let members: Vec<_> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(circle.id))
+        .collect();
+    let find = |name: &str, kind: &str| {
+        members
+            .iter()
+            .find(|s| s.name == name && s.kind == kind)
+            .unwrap_or_else(|| panic!("Circle should have {kind} '{name}'"))
+    };
+
+    assert_eq!(
+        find("DefaultRadius", "const").signature.as_deref(),
+        Some("double")
+    );
+    assert_eq!(
+        find("_points", "var").signature.as_deref(),
+        Some("List<Point>")
+    );
+    let radius = find("Radius", "var");
+    assert_eq!(radius.signature.as_deref(), Some("double"));
+    assert_eq!(radius.doc_comment.as_deref(), Some("The radius."));
+    assert_eq!(
+        find("Changed", "var").signature.as_deref(),
+        Some("event ShapeChangedHandler")
+    );
+    assert_eq!(
+        find("Circle", "method").signature.as_deref(),
+        Some("(double radius)")
+    );
+    assert_eq!(
+        find("Square", "method").signature.as_deref(),
+        Some("(double x) double")
+    );
+    find("Name", "var");
+    find("Dispose", "method");
+
+    let handler = symbols
+        .iter()
+        .find(|s| s.name == "ShapeChangedHandler")
+        .expect("Should find delegate");
+    assert_eq!(handler.kind, "type");
+    assert_eq!(
+        handler.signature.as_deref(),
+        Some("delegate (IShape shape, EventArgs args) void")
+    );
+
+    let point = symbols
+        .iter()
+        .find(|s| s.name == "Point")
+        .expect("Should find 'Point'");
+    assert_eq!(point.kind, "struct");
+    assert_eq!(point.signature.as_deref(), Some("(double X, double Y)"));
+    let components: Vec<&str> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(point.id))
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(components, vec!["X", "Y"]);
+
+    let color = symbols
+        .iter()
+        .find(|s| s.name == "Color")
+        .expect("Should find 'Color'");
+    assert_eq!(color.kind, "enum");
+    let constants: Vec<&str> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(color.id) && s.kind == "const")
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(constants, vec!["Red", "Green"]);
+}
+
+#[test]
+fn test_csharp_calls_and_type_refs() {
+    let (storage, path_str) = index_lang_fixtures(csharp_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Shapes.cs")
+        .expect("get_file_symbols failed");
+    let find = |name: &str, kind: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name && s.kind == kind)
+            .unwrap_or_else(|| panic!("Should find {kind} '{name}'"))
+    };
+
+    let circle = find("Circle", "class");
+    let area = symbols
+        .iter()
+        .find(|s| s.name == "Area" && s.parent_symbol_id == Some(circle.id))
+        .expect("Should find Circle.Area");
+    let deps = storage
+        .get_dependencies(&path_str, area.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Square" && r.ref_kind == "call"),
+        "Area should call Square, got: {:?}",
+        deps.iter().map(|r| &r.to_name).collect::<Vec<_>>()
+    );
+
+    let dispose = find("Dispose", "method");
+    let deps = storage
+        .get_dependencies(&path_str, dispose.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Changed.Invoke" && r.ref_kind == "call"),
+        "Null-conditional calls should be recorded"
+    );
+
+    let compare = find("CompareTo", "method");
+    let deps = storage
+        .get_dependencies(&path_str, compare.id)
+        .expect("get_dependencies failed");
+    let shape_ref = deps
+        .iter()
+        .find(|r| r.to_name == "IShape" && r.ref_kind == "type_ref")
+        .expect("Parameter types should be type refs");
+    assert_eq!(shape_ref.to_symbol_id, Some(find("IShape", "interface").id));
+
+    let audited = find("AuditedAttribute", "class");
+    let refs = storage
+        .get_references(&path_str, audited.id)
+        .expect("get_references failed");
+    assert!(
+        refs.iter().any(|r| r.from_name.as_deref() == Some("Point")),
+        "[Audited] should reference AuditedAttribute"
+    );
+}
+
+// ==================== PHP Tests ====================
+
+#[test]
+fn test_php_index_repository() {
+    let path = php_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 5, "Should index 5 PHP files");
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+}
+
+#[test]
+fn test_php_namespaces_and_imports() {
+    let (storage, path_str) = index_lang_fixtures(php_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "User.php")
+        .expect("get_file_symbols failed");
+
+    let namespace = symbols
+        .iter()
+        .find(|s| s.name == "App\\Models")
+        .expect("Should find namespace symbol");
+    assert_eq!(namespace.kind, "mod");
+    let user = symbols
+        .iter()
+        .find(|s| s.name == "User")
+        .expect("Should find 'User'");
+    assert_eq!(user.parent_symbol_id, Some(namespace.id));
+    assert_eq!(
+        user.doc_comment.as_deref(),
+        Some("A registered user.\n@property string $name")
+    );
+
+    let imports = symbols
+        .iter()
+        .find(|s| s.name == "_imports")
+        .expect("Should find _imports symbol");
+    let deps = storage
+        .get_dependencies(&path_str, imports.id)
+        .expect("get_dependencies failed");
+    let names: Vec<&str> = deps
+        .iter()
+        .filter(|r| r.ref_kind == "import")
+        .map(|r| r.to_name.as_str())
+        .collect();
+    for expected in [
+        "App\\Concerns\\HasTimestamps",
+        "App\\Contracts\\Arrayable",
+        "Illuminate\\Database\\Eloquent\\Model",
+        "Illuminate\\Database\\Eloquent\\SoftDeletes",
+        "App\\Support\\format_name",
+    ] {
+        assert!(
+            names.contains(&expected),
+            "Missing import {expected}, got: {:?}",
+            names
+        );
+    }
+
+    let helpers = storage
+        .get_file_symbols(&path_str, "helpers.php")
+        .expect("get_file_symbols failed");
+    let support = helpers
+        .iter()
+        .find(|s| s.name == "App\\Support")
+        .expect("Should find braced namespace");
+    for name in ["format_name", "admin", "DEFAULT_NAME"] {
+        assert!(
+            helpers
+                .iter()
+                .any(|s| s.name == name && s.parent_symbol_id == Some(support.id)),
+            "'{name}' should be inside App\\Support"
+        );
+    }
+}
+
+#[test]
+fn test_php_class_hierarchy_and_traits() {
+    let (storage, path_str) = index_lang_fixtures(php_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "User.php")
+        .expect("get_file_symbols failed");
+    let user = symbols
+        .iter()
+        .find(|s| s.name == "User")
+        .expect("Should find 'User'");
+    assert_eq!(user.kind, "class");
+
+    let deps = storage
+        .get_dependencies(&path_str, user.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Model" && r.ref_kind == "extends")
+    );
+    let arrayable = deps
+        .iter()
+        .find(|r| r.to_name == "Arrayable" && r.ref_kind == "implements")
+        .expect("Aliased interface should resolve to its declared name");
+    assert!(arrayable.to_symbol_id.is_some());
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "JsonSerializable" && r.ref_kind == "implements"),
+        "Fully qualified names should be shortened"
+    );
+
+    let mixins: Vec<_> = deps.iter().filter(|r| r.ref_kind == "mixin").collect();
+    assert_eq!(
+        mixins
+            .iter()
+            .map(|r| r.to_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["HasTimestamps", "SoftDeletes"]
+    );
+    let traits = storage
+        .get_file_symbols(&path_str, "HasTimestamps.php")
+        .expect("get_file_symbols failed");
+    let trait_sym = traits
+        .iter()
+        .find(|s| s.name == "HasTimestamps")
+        .expect("Should find trait");
+    assert_eq!(trait_sym.kind, "trait");
+    assert_eq!(
+        mixins[0].to_symbol_id,
+        Some(trait_sym.id),
+        "Trait usage should link to the trait"
+    );
+
+    let contracts = storage
+        .get_file_symbols(&path_str, "Arrayable.php")
+        .expect("get_file_symbols failed");
+    let interface = contracts
+        .iter()
+        .find(|s| s.name == "Arrayable")
+        .expect("Should find interface");
+    assert_eq!(interface.kind, "interface");
+}
+
+#[test]
+fn test_php_members() {
+    let (storage, path_str) = index_lang_fixtures(php_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "User.php")
+        .expect("get_file_symbols failed");
+    let user = symbols
+        .iter()
+        .find(|s| s.name == "User")
+        .expect("Should find 'User'");
+    let members: Vec<_> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(user.id))
+        .collect();
+    let find = |name: &str, kind: &str| {
+        members
+            .iter()
+            .find(|s| s.name == name && s.kind == kind)
+            .unwrap_or_else(|| panic!("User should have {kind} '{name}'"))
+    };
+
+    assert_eq!(
+        find("ROLE_ADMIN", "const").signature.as_deref(),
+        Some("'admin'")
+    );
+    let fillable = find("fillable", "var");
+    assert_eq!(fillable.signature.as_deref(), Some("array"));
+    assert_eq!(fillable.doc_comment.as_deref(), Some("@var string[]"));
+    assert_eq!(find("status", "var").signature.as_deref(), Some("?Status"));
+    assert_eq!(
+        find("email", "var").signature.as_deref(),
+        Some("string"),
+        "Promoted constructor parameters are properties"
+    );
+    let display = find("displayName", "method");
+    assert_eq!(display.signature.as_deref(), Some("(): string"));
+    assert_eq!(
+        display.doc_comment.as_deref(),
+        Some("Display name for the UI.")
+    );
+    assert_eq!(
+        find("make", "method").signature.as_deref(),
+        Some("(string $name): static")
+    );
+
+    let enums = storage
+        .get_file_symbols(&path_str, "Status.php")
+        .expect("get_file_symbols failed");
+    let status = enums
+        .iter()
+        .find(|s| s.name == "Status")
+        .expect("Should find enum");
+    assert_eq!(status.kind, "enum");
+    assert_eq!(status.signature.as_deref(), Some(": string"));
+    let cases: Vec<&str> = enums
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(status.id) && s.kind == "const")
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(cases, vec!["Active", "Banned"]);
+}
+
+#[test]
+fn test_php_calls_and_type_refs() {
+    let (storage, path_str) = index_lang_fixtures(php_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "User.php")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+
+    let deps = storage
+        .get_dependencies(&path_str, find("displayName").id)
+        .expect("get_dependencies failed");
+    let format = deps
+        .iter()
+        .find(|r| r.to_name == "format_name" && r.ref_kind == "call")
+        .expect("displayName should call format_name");
+    assert!(
+        format.to_symbol_id.is_some(),
+        "Imported function should resolve"
+    );
+
+    let deps = storage
+        .get_dependencies(&path_str, find("toArray").id)
+        .expect("get_dependencies failed");
+    let this_call = deps
+        .iter()
+        .find(|r| r.to_name == "this.displayName")
+        .expect("$this->displayName() should be recorded as this.displayName");
+    assert_eq!(this_call.to_symbol_id, Some(find("displayName").id));
+    assert!(
+        deps.iter().any(|r| r.to_name == "this.status.label"),
+        "Nullsafe calls should be recorded"
+    );
+
+    let deps = storage
+        .get_dependencies(&path_str, find("make").id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter().any(|r| r.to_name == "this.boot"),
+        "self:: calls map to this."
+    );
+
+    let helpers = storage
+        .get_file_symbols(&path_str, "helpers.php")
+        .expect("get_file_symbols failed");
+    let admin = helpers
+        .iter()
+        .find(|s| s.name == "admin")
+        .expect("Should find 'admin'");
+    let deps = storage
+        .get_dependencies(&path_str, admin.id)
+        .expect("get_dependencies failed");
+    let user_ref = deps
+        .iter()
+        .find(|r| r.to_name == "User" && r.ref_kind == "type_ref")
+        .expect("Return types should be type refs");
+    assert_eq!(user_ref.to_symbol_id, Some(find("User").id));
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "User.make" && r.ref_kind == "call"),
+        "Static calls should keep the class name"
+    );
+}
+
+// ==================== Vue/Svelte Tests ====================
+
+#[test]
+fn test_sfc_index_repository() {
+    let (storage, path_str) = index_lang_fixtures(sfc_fixtures_path());
+
+    for (file, component) in [
+        ("UserCard.vue", "UserCard"),
+        ("TagChip.vue", "TagChip"),
+        ("Counter.svelte", "Counter"),
+    ] {
+        let symbols = storage
+            .get_file_symbols(&path_str, file)
+            .expect("get_file_symbols failed");
+        let sym = symbols
+            .iter()
+            .find(|s| s.name == component)
+            .unwrap_or_else(|| panic!("{file} should expose component '{component}'"));
+        assert_eq!(sym.kind, "class");
+        assert_eq!(sym.signature.as_deref(), Some("component"));
+        assert_eq!(sym.parent_symbol_id, None);
+    }
+}
+
+#[test]
+fn test_vue_script_setup_line_offsets() {
+    let (storage, path_str) = index_lang_fixtures(sfc_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "UserCard.vue")
+        .expect("get_file_symbols failed");
+    let component = symbols
+        .iter()
+        .find(|s| s.name == "UserCard")
+        .expect("Should find component");
+
+    let format = symbols
+        .iter()
+        .find(|s| s.name == "formatName")
+        .expect("Should find 'formatName' from <script setup>");
+    assert_eq!(format.parent_symbol_id, Some(component.id));
+    assert_eq!(
+        format.start_line, 25,
+        "Lines should be relative to the .vue file"
+    );
+    assert_eq!(format.end_line, 27);
+    assert_eq!(format.signature.as_deref(), Some("(user: User): string"));
+    assert_eq!(
+        format.doc_comment.as_deref(),
+        Some("Formats the user's full name.")
+    );
+
+    let edit = symbols
+        .iter()
+        .find(|s| s.name == "edit")
+        .expect("Should find 'edit'");
+    let deps = storage
+        .get_dependencies(&path_str, edit.id)
+        .expect("get_dependencies failed");
+    let select = symbols
+        .iter()
+        .find(|s| s.name == "select")
+        .expect("Should find 'select'");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "select" && r.to_symbol_id == Some(select.id))
+    );
+
+    let imports = symbols
+        .iter()
+        .find(|s| s.name == "_imports")
+        .expect("Script imports should be recorded");
+    assert_eq!(imports.parent_symbol_id, None);
+}
+
+#[test]
+fn test_vue_template_component_refs() {
+    let (storage, path_str) = index_lang_fixtures(sfc_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "UserCard.vue")
+        .expect("get_file_symbols failed");
+    let component = symbols
+        .iter()
+        .find(|s| s.name == "UserCard")
+        .expect("Should find component");
+    let deps = storage
+        .get_dependencies(&path_str, component.id)
+        .expect("get_dependencies failed");
+    let used: Vec<&str> = deps.iter().map(|r| r.to_name.as_str()).collect();
+
+    assert!(used.contains(&"UserAvatar"), "got: {:?}", used);
+    assert!(
+        used.contains(&"BaseButton"),
+        "kebab-case tags should be normalized, got: {:?}",
+        used
+    );
+    assert!(!used.contains(&"div"), "HTML elements are not components");
+    assert!(!used.contains(&"Template"), "<template> is not a component");
+
+    let chip = deps
+        .iter()
+        .find(|r| r.to_name == "TagChip")
+        .expect("Should reference TagChip");
+    assert_eq!(chip.ref_kind, "call");
+    let tag_chip = storage
+        .get_file_symbols(&path_str, "TagChip.vue")
+        .expect("get_file_symbols failed")
+        .into_iter()
+        .find(|s| s.name == "TagChip")
+        .expect("Should find TagChip component");
+    assert_eq!(
+        chip.to_symbol_id,
+        Some(tag_chip.id),
+        "Template usage should resolve to the child component"
+    );
+}
+
+#[test]
+fn test_svelte_component() {
+    let (storage, path_str) = index_lang_fixtures(sfc_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Counter.svelte")
+        .expect("get_file_symbols failed");
+    let component = symbols
+        .iter()
+        .find(|s| s.name == "Counter")
+        .expect("Should find component");
+
+    let max = symbols
+        .iter()
+        .find(|s| s.name == "MAX_COUNT")
+        .expect("Should find module-context export");
+    assert_eq!(max.start_line, 2);
+    let increment = symbols
+        .iter()
+        .find(|s| s.name == "increment")
+        .expect("Should find 'increment'");
+    assert_eq!(increment.start_line, 12);
+    assert_eq!(increment.parent_symbol_id, Some(component.id));
+
+    let deps = storage
+        .get_dependencies(&path_str, increment.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "clamp" && r.to_symbol_id.is_some()),
+        "Script calls should resolve to other modules"
+    );
+
+    let template_deps = storage
+        .get_dependencies(&path_str, component.id)
+        .expect("get_dependencies failed");
+    let buttons: Vec<Option<i64>> = template_deps
+        .iter()
+        .filter(|r| r.to_name == "Button")
+        .map(|r| r.line)
+        .collect();
+    assert_eq!(
+        buttons,
+        vec![Some(18), Some(24)],
+        "Components inside blocks should be found"
+    );
+    assert!(
+        template_deps.iter().all(|r| r.to_name != "MAX"),
+        "Expressions in blocks should not be mistaken for tags"
+    );
+}
+
+// ==================== YAML/TOML/JSON Tests ====================
+
+#[test]
+fn test_data_index_repository() {
+    let (storage, path_str) = index_lang_fixtures(data_fixtures_path());
+
+    for file in [
+        "package.json",
+        "Cargo.toml",
+        "docker-compose.yml",
+        "deployment.yaml",
+        ".github/workflows/ci.yml",
+    ] {
+        let symbols = storage
+            .get_file_symbols(&path_str, file)
+            .expect("get_file_symbols failed");
+        assert!(!symbols.is_empty(), "{file} should have keys");
+        assert!(symbols.iter().all(|s| s.kind == "key"));
+    }
+}
+
+#[test]
+fn test_json_keys() {
+    let (storage, path_str) = index_lang_fixtures(data_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "package.json")
+        .expect("get_file_symbols failed");
+    let scripts = symbols
+        .iter()
+        .find(|s| s.name == "scripts")
+        .expect("Should find 'scripts'");
+    assert_eq!(scripts.parent_symbol_id, None);
+    assert_eq!(scripts.signature, None);
+    assert_eq!((scripts.start_line, scripts.end_line), (4, 8));
+
+    let build = symbols
+        .iter()
+        .find(|s| s.name == "build")
+        .expect("Should find 'build' script");
+    assert_eq!(build.parent_symbol_id, Some(scripts.id));
+    assert_eq!(build.signature.as_deref(), Some("vite build"));
+
+    let files = symbols
+        .iter()
+        .find(|s| s.name == "files")
+        .expect("Should find 'files'");
+    assert_eq!(files.signature.as_deref(), Some(r#"["dist", "README.md"]"#));
+
+    let results = storage
+        .search_symbols(&path_str, "scripts", 20)
+        .expect("search_symbols failed");
+    assert!(results.iter().any(|r| r.file_rel_path == "package.json"));
+}
+
+#[test]
+fn test_toml_keys() {
+    let (storage, path_str) = index_lang_fixtures(data_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "Cargo.toml")
+        .expect("get_file_symbols failed");
+    let find_under = |name: &str, parent: Option<i64>| {
+        symbols
+            .iter()
+            .find(|s| s.name == name && s.parent_symbol_id == parent)
+            .unwrap_or_else(|| panic!("Should find key '{name}'"))
+    };
+
+    let features = find_under("features", None);
+    assert_eq!(
+        find_under("json", Some(features.id)).signature.as_deref(),
+        Some(r#"["dep:serde_json"]"#)
+    );
+
+    let dependencies = find_under("dependencies", None);
+    let serde = find_under("serde", Some(dependencies.id));
+    assert_eq!(
+        find_under("version", Some(serde.id)).signature.as_deref(),
+        Some("1"),
+        "Inline tables should nest"
+    );
+
+    let profile = find_under("profile", None);
+    let release = find_under("release", Some(profile.id));
+    assert_eq!(
+        find_under("lto", Some(release.id)).signature.as_deref(),
+        Some("true"),
+        "Dotted table headers should nest"
+    );
+
+    let bin = find_under("bin", None);
+    assert_eq!(
+        find_under("name", Some(bin.id)).signature.as_deref(),
+        Some("demo-cli")
+    );
+}
+
+#[test]
+fn test_yaml_keys() {
+    let (storage, path_str) = index_lang_fixtures(data_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "docker-compose.yml")
+        .expect("get_file_symbols failed");
+    let services = symbols
+        .iter()
+        .find(|s| s.name == "services")
+        .expect("Should find 'services'");
+    let names: Vec<&str> = symbols
+        .iter()
+        .filter(|s| s.parent_symbol_id == Some(services.id))
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["web", "db"]);
+    let image = symbols
+        .iter()
+        .find(|s| s.name == "image")
+        .expect("Should find 'image'");
+    assert_eq!(image.signature.as_deref(), Some("demo/web:latest"));
+
+    let workflow = storage
+        .get_file_symbols(&path_str, ".github/workflows/ci.yml")
+        .expect("get_file_symbols failed");
+    let jobs = workflow
+        .iter()
+        .find(|s| s.name == "jobs")
+        .expect("Should find 'jobs'");
+    assert!(
+        workflow
+            .iter()
+            .any(|s| s.name == "test" && s.parent_symbol_id == Some(jobs.id)),
+        "Workflow jobs should be keys"
+    );
+
+    let manifests = storage
+        .get_file_symbols(&path_str, "deployment.yaml")
+        .expect("get_file_symbols failed");
+    let kinds: Vec<Option<&str>> = manifests
+        .iter()
+        .filter(|s| s.name == "kind")
+        .map(|s| s.signature.as_deref())
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![Some("Deployment"), Some("Service")],
+        "Each YAML document should be indexed"
+    );
+}
+
+#[test]
+fn test_data_key_depth_limit() {
+    let path = data_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    Indexer::new()
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig {
+                max_key_depth: 1,
+                ..IndexerConfig::default()
+            },
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    let symbols = storage
+        .get_file_symbols(path_str, "package.json")
+        .expect("get_file_symbols failed");
+    assert!(symbols.iter().any(|s| s.name == "scripts"));
+    assert!(
+        symbols.iter().all(|s| s.parent_symbol_id.is_none()),
+        "Depth 1 should keep only top-level keys"
+    );
+
+    let (storage, path_str) = index_lang_fixtures(data_fixtures_path());
+    let symbols = storage
+        .get_file_symbols(&path_str, "package.json")
+        .expect("get_file_symbols failed");
+    assert!(symbols.iter().any(|s| s.name == "region"));
+    assert!(
+        !symbols.iter().any(|s| s.name == "primary"),
+        "Keys deeper than the default depth should be skipped"
+    );
+}
+
+// ==================== Protobuf/GraphQL/OpenAPI Tests ====================
+
+#[test]
+fn test_api_index_repository() {
+    let path = api_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 6, "Should index 6 API definition files");
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+}
+
+#[test]
+fn test_proto_messages_and_services() {
+    let (storage, path_str) = index_lang_fixtures(api_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "users.proto")
+        .expect("get_file_symbols failed");
+
+    let package = symbols
+        .iter()
+        .find(|s| s.name == "acme.users.v1")
+        .expect("Should find package");
+    assert_eq!(package.kind, "mod");
+    let imports: Vec<String> = storage
+        .get_dependencies(&path_str, package.id)
+        .expect("get_dependencies failed")
+        .into_iter()
+        .filter(|r| r.ref_kind == "import")
+        .map(|r| r.to_name)
+        .collect();
+    assert!(imports.contains(&"common.proto".to_string()));
+
+    let user = symbols
+        .iter()
+        .find(|s| s.name == "User" && s.kind == "struct")
+        .expect("Should find message User");
+    assert_eq!(user.doc_comment.as_deref(), Some("A registered account."));
+    let field_sig = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name && s.parent_symbol_id == Some(user.id))
+            .and_then(|s| s.signature.as_deref())
+    };
+    assert_eq!(field_sig("addresses"), Some("repeated Address"));
+    assert_eq!(field_sig("labeled"), Some("map<string, Address>"));
+    assert_eq!(
+        field_sig("page"),
+        Some("PageInfo"),
+        "oneof fields are listed"
+    );
+    assert!(
+        symbols
+            .iter()
+            .any(|s| s.name == "Address" && s.parent_symbol_id == Some(user.id)),
+        "Nested messages should be children"
+    );
+
+    let role = symbols
+        .iter()
+        .find(|s| s.name == "Role" && s.kind == "enum")
+        .expect("Should find enum Role");
+    assert!(
+        symbols
+            .iter()
+            .any(|s| s.name == "ROLE_ADMIN" && s.parent_symbol_id == Some(role.id))
+    );
+
+    let service = symbols
+        .iter()
+        .find(|s| s.name == "UserService")
+        .expect("Should find service");
+    assert_eq!(service.kind, "interface");
+    let list = symbols
+        .iter()
+        .find(|s| s.name == "ListUsers")
+        .expect("Should find rpc ListUsers");
+    assert_eq!(list.kind, "method");
+    assert_eq!(list.parent_symbol_id, Some(service.id));
+    assert_eq!(
+        list.signature.as_deref(),
+        Some("(GetUserRequest) returns (stream ListUsersResponse)")
+    );
+}
+
+#[test]
+fn test_proto_type_refs_across_files() {
+    let (storage, path_str) = index_lang_fixtures(api_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "users.proto")
+        .expect("get_file_symbols failed");
+    let response = symbols
+        .iter()
+        .find(|s| s.name == "ListUsersResponse")
+        .expect("Should find ListUsersResponse");
+    let deps = storage
+        .get_dependencies(&path_str, response.id)
+        .expect("get_dependencies failed");
+    let page_info = deps
+        .iter()
+        .find(|r| r.to_name == "PageInfo" && r.ref_kind == "type_ref")
+        .expect("Should reference PageInfo");
+    assert!(
+        page_info.to_symbol_id.is_some(),
+        "PageInfo from common.proto should resolve"
+    );
+
+    let get_user = symbols
+        .iter()
+        .find(|s| s.name == "GetUser")
+        .expect("Should find rpc GetUser");
+    let rpc_deps: Vec<String> = storage
+        .get_dependencies(&path_str, get_user.id)
+        .expect("get_dependencies failed")
+        .into_iter()
+        .map(|r| r.to_name)
+        .collect();
+    assert!(rpc_deps.contains(&"GetUserRequest".to_string()));
+    assert!(rpc_deps.contains(&"User".to_string()));
+}
+
+#[test]
+fn test_graphql_schema() {
+    let (storage, path_str) = index_lang_fixtures(api_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "schema.graphql")
+        .expect("get_file_symbols failed");
+
+    let node = symbols
+        .iter()
+        .find(|s| s.name == "Node")
+        .expect("Should find interface Node");
+    assert_eq!(node.kind, "interface");
+    assert_eq!(
+        node.doc_comment.as_deref(),
+        Some("Something that can be looked up by ID.")
+    );
+
+    let user = symbols
+        .iter()
+        .find(|s| s.name == "User" && s.start_line == 8)
+        .expect("Should find type User");
+    assert_eq!(user.kind, "class");
+    let deps = storage
+        .get_dependencies(&path_str, user.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Timestamped" && r.ref_kind == "implements")
+    );
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "Role" && r.ref_kind == "type_ref")
+    );
+    assert!(
+        !deps.iter().any(|r| r.to_name == "String"),
+        "Built-in scalars should not be referenced"
+    );
+
+    let posts = symbols
+        .iter()
+        .find(|s| s.name == "posts")
+        .expect("Should find field with arguments");
+    assert_eq!(posts.kind, "method");
+    assert_eq!(
+        posts.signature.as_deref(),
+        Some("(first: Int = 10): [Post!]!")
+    );
+
+    let query_user = symbols
+        .iter()
+        .find(|s| s.name == "user" && s.kind == "method")
+        .expect("Query fields should be methods");
+    assert_eq!(query_user.doc_comment.as_deref(), Some("Looks up a user."));
+
+    let union = symbols
+        .iter()
+        .find(|s| s.name == "SearchResult")
+        .expect("Should find union");
+    assert_eq!(union.kind, "type");
+    assert_eq!(union.signature.as_deref(), Some("User | Post"));
+
+    let input = symbols
+        .iter()
+        .find(|s| s.name == "CreatePostInput")
+        .expect("Should find input type");
+    assert_eq!(input.kind, "struct");
+}
+
+#[test]
+fn test_graphql_operations() {
+    let (storage, path_str) = index_lang_fixtures(api_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "queries.gql")
+        .expect("get_file_symbols failed");
+
+    let profile = symbols
+        .iter()
+        .find(|s| s.name == "GetProfile")
+        .expect("Should find query GetProfile");
+    assert_eq!(profile.kind, "fn");
+    assert_eq!(profile.signature.as_deref(), Some("query ($id: ID!)"));
+    assert_eq!(
+        profile.doc_comment.as_deref(),
+        Some("Header data for the profile page.")
+    );
+    let deps = storage
+        .get_dependencies(&path_str, profile.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "UserFields" && r.ref_kind == "call"),
+        "Fragment spreads should be referenced"
+    );
+
+    let create = symbols
+        .iter()
+        .find(|s| s.name == "CreatePost")
+        .expect("Should find mutation CreatePost");
+    let deps = storage
+        .get_dependencies(&path_str, create.id)
+        .expect("get_dependencies failed");
+    let input = deps
+        .iter()
+        .find(|r| r.to_name == "CreatePostInput")
+        .expect("Should reference the input type");
+    assert!(input.to_symbol_id.is_some(), "Should resolve to the schema");
+
+    let fragment = symbols
+        .iter()
+        .find(|s| s.name == "UserFields")
+        .expect("Should find fragment");
+    assert_eq!(fragment.signature.as_deref(), Some("fragment on User"));
+}
+
+#[test]
+fn test_openapi_operations_and_schemas() {
+    let (storage, path_str) = index_lang_fixtures(api_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "openapi.yaml")
+        .expect("get_file_symbols failed");
+    assert!(
+        !symbols.iter().any(|s| s.kind == "key"),
+        "OpenAPI documents should not be indexed as raw keys"
+    );
+
+    let get_user = symbols
+        .iter()
+        .find(|s| s.name == "getUser")
+        .expect("Should find operation by operationId");
+    assert_eq!(get_user.kind, "fn");
+    assert_eq!(get_user.signature.as_deref(), Some("GET /users/{id}"));
+    assert_eq!(
+        get_user.doc_comment.as_deref(),
+        Some("Fetch a single user.")
+    );
+    let deps: Vec<String> = storage
+        .get_dependencies(&path_str, get_user.id)
+        .expect("get_dependencies failed")
+        .into_iter()
+        .map(|r| r.to_name)
+        .collect();
+    for target in ["User", "NotFound", "UserId"] {
+        assert!(
+            deps.contains(&target.to_string()),
+            "Missing ref to {target}"
+        );
+    }
+
+    assert!(
+        symbols.iter().any(|s| s.name == "DELETE /users/{id}"),
+        "Operations without an operationId are named by route"
+    );
+
+    let user = symbols
+        .iter()
+        .find(|s| s.name == "User" && s.kind == "struct")
+        .expect("Should find schema User");
+    assert_eq!(
+        user.doc_comment.as_deref(),
+        Some("A registered account.\nEmails are unique.")
+    );
+    let prop_sig = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name && s.parent_symbol_id == Some(user.id))
+            .and_then(|s| s.signature.as_deref())
+    };
+    assert_eq!(prop_sig("role"), Some("Role"));
+    assert_eq!(prop_sig("tags"), Some("string[]"));
+
+    let role = symbols
+        .iter()
+        .find(|s| s.name == "Role")
+        .expect("Should find enum schema");
+    assert_eq!(role.kind, "enum");
+
+    let not_found = symbols
+        .iter()
+        .find(|s| s.name == "NotFound")
+        .expect("Should find response component");
+    assert_eq!(not_found.signature.as_deref(), Some("response"));
+}
+
+#[test]
+fn test_swagger_json() {
+    let (storage, path_str) = index_lang_fixtures(api_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "petstore.json")
+        .expect("get_file_symbols failed");
+
+    let create = symbols
+        .iter()
+        .find(|s| s.name == "createPet")
+        .expect("Should find operation");
+    assert_eq!(create.signature.as_deref(), Some("POST /pets"));
+    assert_eq!(
+        create.doc_comment.as_deref(),
+        Some("Adds a pet to the store.")
+    );
+
+    let pet = symbols
+        .iter()
+        .find(|s| s.name == "Pet")
+        .expect("Should find definition Pet");
+    assert_eq!(pet.kind, "struct");
+    let deps = storage
+        .get_dependencies(&path_str, create.id)
+        .expect("get_dependencies failed");
+    let pet_ref = deps
+        .iter()
+        .find(|r| r.to_name == "Pet")
+        .expect("Operation should reference Pet");
+    assert_eq!(pet_ref.to_symbol_id, Some(pet.id));
+}
+
+// ==================== Shell/Dockerfile Tests ====================
+
+#[test]
+fn test_shell_index_repository() {
+    let path = shell_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(
+        stats.files_total, 6,
+        "Should index 4 scripts plus Dockerfile and Containerfile.dev"
+    );
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+}
+
+#[test]
+fn test_shell_functions_and_exports() {
+    let (storage, path_str) = index_lang_fixtures(shell_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "scripts/common.sh")
+        .expect("get_file_symbols failed");
+
+    let script = symbols
+        .iter()
+        .find(|s| s.name == "common.sh")
+        .expect("Should find the script symbol");
+    assert_eq!(script.kind, "fn");
+    assert_eq!(
+        script.doc_comment.as_deref(),
+        Some("Shared helpers for the deploy scripts."),
+        "Header comment after the shebang documents the script"
+    );
+
+    let log = symbols
+        .iter()
+        .find(|s| s.name == "log")
+        .expect("Should find function 'log'");
+    assert_eq!(log.kind, "fn");
+    assert_eq!(
+        log.doc_comment.as_deref(),
+        Some("Prints a prefixed log line.")
+    );
+    assert!(
+        symbols.iter().any(|s| s.name == "die" && s.kind == "fn"),
+        "`function die {{ }}` form should be extracted"
+    );
+
+    let env = symbols
+        .iter()
+        .find(|s| s.name == "DEPLOY_ENV")
+        .expect("Should find exported variable");
+    assert_eq!(env.kind, "var");
+    assert_eq!(env.signature.as_deref(), Some("${DEPLOY_ENV:-staging}"));
+    assert!(
+        !symbols.iter().any(|s| s.name == "OLD_FLAG"),
+        "`export -n` un-exports"
+    );
+    assert!(
+        !symbols.iter().any(|s| s.name == "LOG_PREFIX"),
+        "readonly variables are not exported"
+    );
+}
+
+#[test]
+fn test_shell_sources_and_script_calls() {
+    let (storage, path_str) = index_lang_fixtures(shell_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "scripts/deploy.sh")
+        .expect("get_file_symbols failed");
+
+    let imports = symbols
+        .iter()
+        .find(|s| s.name == "_imports")
+        .expect("Should find _imports");
+    let import_refs = storage
+        .get_dependencies(&path_str, imports.id)
+        .expect("get_dependencies failed");
+    let common = import_refs
+        .iter()
+        .find(|r| r.to_name == "common.sh" && r.ref_kind == "import")
+        .expect("`source \"$(dirname \"$0\")/common.sh\"` should import common.sh");
+    assert!(
+        common.to_symbol_id.is_some(),
+        "Should resolve to the script"
+    );
+    assert!(
+        import_refs.iter().any(|r| r.to_name == "env.sh"),
+        "`.` imports too"
+    );
+
+    let build = symbols
+        .iter()
+        .find(|s| s.name == "build_image")
+        .expect("Should find build_image");
+    let deps = storage
+        .get_dependencies(&path_str, build.id)
+        .expect("get_dependencies failed");
+    let migrate = deps
+        .iter()
+        .find(|r| r.to_name == "migrate.sh" && r.ref_kind == "call")
+        .expect("Running ./scripts/migrate.sh should be a call");
+    assert!(migrate.to_symbol_id.is_some());
+    assert!(
+        deps.iter()
+            .any(|r| r.to_name == "log" && r.to_symbol_id.is_some()),
+        "Functions from sourced scripts should resolve"
+    );
+    assert!(
+        !deps.iter().any(|r| r.to_name == "echo"),
+        "Builtins are not calls"
+    );
+
+    let main = symbols
+        .iter()
+        .find(|s| s.name == "main")
+        .expect("Should find main");
+    let deps = storage
+        .get_dependencies(&path_str, main.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter().any(|r| r.to_name == "notify.zsh"),
+        "`zsh scripts/notify.zsh` should call the script"
+    );
+
+    let script = symbols
+        .iter()
+        .find(|s| s.name == "deploy.sh")
+        .expect("Should find the script symbol");
+    let deps = storage
+        .get_dependencies(&path_str, script.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter().any(|r| r.to_name == "main"),
+        "Top-level commands belong to the script"
+    );
+}
+
+#[test]
+fn test_dockerfile_stages() {
+    let (storage, path_str) = index_lang_fixtures(shell_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "docker/Dockerfile")
+        .expect("get_file_symbols failed");
+
+    let arg = symbols
+        .iter()
+        .find(|s| s.name == "NODE_VERSION")
+        .expect("Should find global ARG");
+    assert_eq!(arg.parent_symbol_id, None);
+    assert_eq!(arg.signature.as_deref(), Some("20"));
+
+    let deps_stage = symbols
+        .iter()
+        .find(|s| s.name == "deps")
+        .expect("Should find stage 'deps'");
+    assert_eq!(deps_stage.kind, "mod");
+    assert_eq!(
+        deps_stage.signature.as_deref(),
+        Some("FROM node:${NODE_VERSION}-alpine")
+    );
+    assert_eq!(
+        deps_stage.doc_comment.as_deref(),
+        Some("Install dependencies once.")
+    );
+    assert_eq!((deps_stage.start_line, deps_stage.end_line), (5, 8));
+
+    let build = symbols
+        .iter()
+        .find(|s| s.name == "build")
+        .expect("Should find stage 'build'");
+    let build_deps = storage
+        .get_dependencies(&path_str, build.id)
+        .expect("get_dependencies failed");
+    assert!(
+        build_deps
+            .iter()
+            .any(|r| r.to_name == "deps" && r.ref_kind == "extends"),
+        "FROM an earlier stage should extend it"
+    );
+    assert!(
+        symbols
+            .iter()
+            .any(|s| s.name == "NODE_ENV" && s.parent_symbol_id == Some(build.id)),
+        "ENV should be a stage variable"
+    );
+
+    let runtime = symbols
+        .iter()
+        .find(|s| s.name == "runtime")
+        .expect("Should find stage 'runtime'");
+    let copies: Vec<String> = storage
+        .get_dependencies(&path_str, runtime.id)
+        .expect("get_dependencies failed")
+        .into_iter()
+        .filter(|r| r.ref_kind == "import")
+        .map(|r| r.to_name)
+        .collect();
+    assert_eq!(copies, vec!["build", "deps"]);
+
+    // An unnamed stage built on `build` is named by its index, not after it
+    assert_eq!(symbols.iter().filter(|s| s.name == "build").count(), 1);
+    let test = symbols
+        .iter()
+        .find(|s| s.name == "3")
+        .expect("Should find stage '3'");
+    let test_deps = storage
+        .get_dependencies(&path_str, test.id)
+        .expect("get_dependencies failed");
+    assert!(
+        test_deps
+            .iter()
+            .any(|r| r.to_name == "build" && r.to_symbol_id == Some(build.id)),
+        "FROM build should extend the build stage, got: {test_deps:?}"
+    );
+}
+
+#[test]
+fn test_dockerfile_detected_by_file_name() {
+    let (storage, path_str) = index_lang_fixtures(shell_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "docker/Containerfile.dev")
+        .expect("get_file_symbols failed");
+    let stages: Vec<_> = symbols.iter().filter(|s| s.kind == "mod").collect();
+    let names: Vec<&str> = stages.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["0", "1"], "Unnamed stages are named by index");
+    assert_eq!(stages[1].signature.as_deref(), Some("FROM golang:1.22"));
+    let deps: Vec<_> = storage
+        .get_dependencies(&path_str, stages[1].id)
+        .expect("get_dependencies failed")
+        .into_iter()
+        .map(|r| (r.ref_kind, r.to_name, r.to_symbol_id))
+        .collect();
+    // Reusing an image isn't building on its stage, and COPY from an external
+    // image has no stage ref
+    assert_eq!(
+        deps,
+        vec![("import".to_string(), "0".to_string(), Some(stages[0].id))]
+    );
+
+    // Incremental updates pick the extractor the same way
+    let fresh = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let stats = Indexer::new()
+        .update_files(
+            &path_str,
+            &["docker/Dockerfile".to_string()],
+            &fresh,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("update_files failed");
+    assert_eq!(stats.files_changed, 1, "Should update the Dockerfile");
+    assert!(stats.symbols_count > 0, "Should extract its stages");
+}
+
+// ==================== SQL Tests ====================
+
+#[test]
+fn test_sql_index_repository() {
+    let path = sql_fixtures_path();
+    let path_str = path.to_str().unwrap();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let indexer = Indexer::new();
+
+    let stats = indexer
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("Indexing failed");
+
+    assert_eq!(stats.files_total, 2, "Should index both migrations");
+    assert!(stats.symbols_count > 0, "Should extract symbols");
+}
+
+#[test]
+fn test_sql_tables_and_columns() {
+    let (storage, path_str) = index_lang_fixtures(sql_fixtures_path());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "migrations/001_init.sql")
+        .expect("get_file_symbols failed");
+    let table = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name && s.kind == "struct")
+            .unwrap_or_else(|| panic!("Should find table '{name}'"))
+    };
+    let columns = |table_id: i64| {
+        symbols
+            .iter()
+            .filter(|s| s.parent_symbol_id == Some(table_id) && s.kind == "var")
+            .collect::<Vec<_>>()
+    };
+
+    let users = table("users");
+    assert_eq!(
+        users.doc_comment.as_deref(),
+        Some("Accounts that can sign in.")
+    );
+    let user_columns = columns(users.id);
+    assert_eq!(
+        user_columns
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["id", "email", "created_at", "display_name"],
+        "ALTER TABLE in the same file should add its column"
+    );
+    assert_eq!(
+        user_columns[1].signature.as_deref(),
+        Some("VARCHAR(255) NOT NULL UNIQUE")
+    );
+
+    let posts = table("posts");
+    assert_eq!(
+        posts.signature.as_deref(),
+        Some("public.posts"),
+        "Schema-qualified tables keep the full name as signature"
+    );
+    assert_eq!(columns(posts.id).len(), 3);
+
+    let index = symbols
+        .iter()
+        .find(|s| s.name == "idx_posts_title")
+        .expect("Should find the index");
+    assert_eq!(index.signature.as_deref(), Some("UNIQUE ON posts (title)"));
+}
+
+#[test]
+fn test_sql_foreign_keys_and_views() {
+    let (storage, path_str) = index_lang_fixtures(sql_fixtures_path());
+
+    let init = storage
+        .get_file_symbols(&path_str, "migrations/001_init.sql")
+        .expect("get_file_symbols failed");
+    let users = init
+        .iter()
+        .find(|s| s.name == "users")
+        .expect("Should find users");
+    let posts = init
+        .iter()
+        .find(|s| s.name == "posts")
+        .expect("Should find posts");
+
+    let deps = storage
+        .get_dependencies(&path_str, posts.id)
+        .expect("get_dependencies failed");
+    let fks: Vec<_> = deps.iter().filter(|r| r.ref_kind == "type_ref").collect();
+    assert_eq!(fks.len(), 1, "Both foreign keys target users");
+    assert_eq!(fks[0].to_symbol_id, Some(users.id));
+
+    let views = storage
+        .get_file_symbols(&path_str, "migrations/002_views.sql")
+        .expect("get_file_symbols failed");
+    let find = |name: &str| {
+        views
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+    };
+
+    let view = find("post_authors");
+    assert_eq!(view.kind, "type");
+    assert_eq!(
+        view.doc_comment.as_deref(),
+        Some("Posts with their author's email.")
+    );
+    let deps = storage
+        .get_dependencies(&path_str, view.id)
+        .expect("get_dependencies failed");
+    let mut tables: Vec<_> = deps
+        .iter()
+        .filter(|r| r.ref_kind == "type_ref")
+        .map(|r| (r.to_name.as_str(), r.to_symbol_id))
+        .collect();
+    tables.sort();
+    assert_eq!(
+        tables,
+        vec![("posts", Some(posts.id)), ("users", Some(users.id))],
+        "Views should reference the tables they select from"
+    );
+
+    let function = find("touch_updated_at");
+    assert_eq!(function.kind, "fn");
+    assert_eq!(function.signature.as_deref(), Some("() RETURNS trigger"));
+
+    let trigger = find("posts_touch");
+    assert_eq!(trigger.signature.as_deref(), Some("BEFORE UPDATE ON posts"));
+    let deps = storage
+        .get_dependencies(&path_str, trigger.id)
+        .expect("get_dependencies failed");
+    assert!(
+        deps.iter()
+            .any(|r| r.ref_kind == "type_ref" && r.to_symbol_id == Some(posts.id)),
+        "Trigger should reference its table"
+    );
+    assert!(
+        deps.iter()
+            .any(|r| r.ref_kind == "call" && r.to_symbol_id == Some(function.id)),
+        "Trigger should call its function"
     );
 }
 
+// ==================== Query Pack Tests ====================
+
+/// One line per symbol of a file with its parent, signature, doc and sorted refs,
+/// for comparing extractors.
+fn symbol_outline(storage: &SqliteStorage, path_str: &str, file: &str) -> Vec<String> {
+    let symbols = storage
+        .get_file_symbols(path_str, file)
+        .expect("get_file_symbols failed");
+    symbols
+        .iter()
+        .map(|s| {
+            let parent = s
+                .parent_symbol_id
+                .and_then(|id| symbols.iter().find(|p| p.id == id))
+                .map(|p| p.name.as_str());
+            let mut refs: Vec<String> = storage
+                .get_dependencies(path_str, s.id)
+                .expect("get_dependencies failed")
+                .iter()
+                .map(|r| format!("{} {}", r.ref_kind, r.to_name))
+                .collect();
+            refs.sort();
+            format!(
+                "{} {} L{}-{} parent={:?} sig={:?} doc={:?} refs={:?}",
+                s.kind, s.name, s.start_line, s.end_line, parent, s.signature, s.doc_comment, refs
+            )
+        })
+        .collect()
+}
+
 #[test]
-fn test_search_finds_pascal_case_class() {
-    let (storage, path_str) = index_fixtures();
+fn test_query_pack_matches_python_extractor() {
+    let (builtin, path_str) = index_lang_fixtures(python_fixtures_path());
+
+    let queries = QueriesConfig {
+        languages: vec![QueryLanguageConfig {
+            name: "python".to_string(),
+            grammar: None,
+            grammar_path: None,
+            extensions: vec!["py".to_string()],
+            filenames: vec![],
+            query: Some("../queries/python.scm".to_string()),
+        }],
+        ..QueriesConfig::default()
+    };
+    let packed = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    Indexer::new()
+        .index(
+            &path_str,
+            &packed,
+            &IndexerConfig::default(),
+            &QueryPacks::load(Path::new(&path_str), &queries),
+        )
+        .expect("Indexing failed");
 
-    // Searching for "repository" should find "UserRepository" and "AdminUserRepository"
-    let results = storage
-        .search_symbols(&path_str, "repository", 20)
-        .expect("search failed");
-    let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
-    assert!(
-        names.contains(&"UserRepository"),
-        "Searching 'repository' should find 'UserRepository', got: {:?}",
-        names
+    // The module symbol is named after the file's path, which a pack can't capture
+    let expected: Vec<String> = symbol_outline(&builtin, &path_str, "sample.py")
+        .into_iter()
+        .filter(|line| !line.starts_with("mod sample "))
+        .collect();
+    assert!(!expected.is_empty());
+    assert_eq!(
+        symbol_outline(&packed, &path_str, "sample.py"),
+        expected,
+        "The query pack should extract what PythonExtractor does"
     );
 }
 
 #[test]
-fn test_search_finds_snake_case_parts() {
-    let (storage, path_str) = index_lang_fixtures(python_fixtures_path());
+fn test_query_pack_declares_new_language() {
+    let path = query_fixtures_path().join("starlark");
+    let path_str = path.to_str().unwrap().to_string();
+    let config = Config::load(&path_str).expect("Failed to load fixture config");
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    Indexer::new()
+        .index(
+            &path_str,
+            &storage,
+            &config.indexer,
+            &QueryPacks::load(Path::new(&path_str), &config.indexer.queries),
+        )
+        .expect("Indexing failed");
 
-    // Searching for "retries" should find "MAX_RETRIES"
-    let results = storage
-        .search_symbols(&path_str, "retries", 20)
-        .expect("search failed");
-    let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+    let overview = storage
+        .get_overview(&path_str)
+        .expect("get_overview failed");
     assert!(
-        names.contains(&"MAX_RETRIES"),
-        "Searching 'retries' should find 'MAX_RETRIES', got: {:?}",
-        names
+        overview.languages.contains(&("starlark".to_string(), 2)),
+        "defs.bzl and BUILD (matched by file name) should be starlark, got {:?}",
+        overview.languages
     );
-}
 
-#[test]
-fn test_search_prefix_on_subwords() {
-    let (storage, path_str) = index_fixtures();
+    let defs = storage
+        .get_file_symbols(&path_str, "defs.bzl")
+        .expect("get_file_symbols failed");
+    let macro_fn = defs
+        .iter()
+        .find(|s| s.name == "go_service")
+        .expect("Should find the macro");
+    assert_eq!(macro_fn.kind, "fn");
+    assert_eq!(
+        macro_fn.signature.as_deref(),
+        Some("(name, srcs, deps = [])")
+    );
+    assert_eq!(
+        macro_fn.doc_comment.as_deref(),
+        Some("Builds a Go binary and its container image.")
+    );
 
-    // Prefix search "repo*" should find UserRepository via name_tokens
-    let results = storage
-        .search_symbols(&path_str, "repo*", 20)
-        .expect("search failed");
-    let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+    let build = storage
+        .get_file_symbols(&path_str, "BUILD")
+        .expect("get_file_symbols failed");
+    let server = build
+        .iter()
+        .find(|s| s.name == "server")
+        .expect("Should find the target");
+    assert_eq!(server.kind, "const");
+    assert_eq!(server.doc_comment.as_deref(), Some("The API server."));
+    let deps = storage
+        .get_dependencies(&path_str, server.id)
+        .expect("get_dependencies failed");
+    let call = deps
+        .iter()
+        .find(|r| r.to_name == "go_service")
+        .expect("Target should call its rule");
+    assert_eq!(call.ref_kind, "call");
+    assert_eq!(call.to_symbol_id, Some(macro_fn.id));
+
+    let imports = build
+        .iter()
+        .find(|s| s.name == "_imports")
+        .expect("Top-level imports should get an _imports module");
+    let deps = storage
+        .get_dependencies(&path_str, imports.id)
+        .expect("get_dependencies failed");
     assert!(
-        names.iter().any(|n| n.contains("Repository")),
-        "Prefix search 'repo*' should find Repository symbols, got: {:?}",
-        names
+        deps.iter()
+            .any(|r| r.ref_kind == "import" && r.to_name == "go_service")
     );
 }
 
-// ==================== Markdown Tests ====================
-
 #[test]
-fn test_markdown_index_repository() {
-    let path = markdown_fixtures_path();
-    let path_str = path.to_str().unwrap();
-    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
-    let indexer = Indexer::new();
+fn test_query_pack_directory_replaces_builtin() {
+    let dir = empty_dir();
+    let queries_dir = dir.path().join(".ctxhelpr/queries");
+    std::fs::create_dir_all(&queries_dir).unwrap();
+    // Unlike PythonExtractor, this pack also indexes nested functions
+    std::fs::write(
+        queries_dir.join("python.scm"),
+        "(function_definition name: (identifier) @name) @definition.function",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("app.py"),
+        "def outer():\n    def inner():\n        pass\n",
+    )
+    .unwrap();
 
-    let stats = indexer
-        .index(path_str, &storage, &[], u64::MAX)
+    let path_str = dir.path().to_str().unwrap().to_string();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    let config = IndexerConfig::default();
+    Indexer::new()
+        .index(
+            &path_str,
+            &storage,
+            &config,
+            &QueryPacks::load(dir.path(), &config.queries),
+        )
         .expect("Indexing failed");
 
-    assert_eq!(stats.files_total, 1, "Should index 1 Markdown file");
-    assert!(stats.symbols_count > 0, "Should extract sections");
+    let symbols = storage
+        .get_file_symbols(&path_str, "app.py")
+        .expect("get_file_symbols failed");
+    let outer = symbols
+        .iter()
+        .find(|s| s.name == "outer")
+        .expect("Should find outer");
+    let inner = symbols
+        .iter()
+        .find(|s| s.name == "inner")
+        .expect("The pack should index nested functions");
+    assert_eq!(inner.parent_symbol_id, Some(outer.id));
+    let overview = storage
+        .get_overview(&path_str)
+        .expect("get_overview failed");
+    assert_eq!(overview.languages, vec![("python".to_string(), 1)]);
 }
 
 #[test]
-fn test_markdown_heading_extraction() {
-    let (storage, path_str) = index_lang_fixtures(markdown_fixtures_path());
+fn test_invalid_query_pack_falls_back_to_builtin() {
+    let dir = empty_dir();
+    let queries_dir = dir.path().join(".ctxhelpr/queries");
+    std::fs::create_dir_all(&queries_dir).unwrap();
+    std::fs::write(
+        queries_dir.join("python.scm"),
+        "(function_definition name: (identifier) @name) @definition.gadget",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("app.py"),
+        "def outer():\n    def inner():\n        pass\n",
+    )
+    .unwrap();
 
-    let symbols = storage
-        .get_file_symbols(&path_str, "sample.md")
-        .expect("get_file_symbols failed");
+    let path_str = dir.path().to_str().unwrap().to_string();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    Indexer::new()
+        .index(
+            &path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
+        .expect("A broken pack should not fail indexing");
 
-    let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
-    assert!(
-        names.contains(&"Project Overview"),
-        "Should find 'Project Overview' heading, got: {:?}",
-        names
-    );
-    assert!(
-        names.contains(&"Installation"),
-        "Should find 'Installation' heading, got: {:?}",
-        names
+    let names: Vec<String> = storage
+        .get_file_symbols(&path_str, "app.py")
+        .expect("get_file_symbols failed")
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+    assert_eq!(
+        names,
+        vec!["app", "outer"],
+        "PythonExtractor should be used instead"
     );
 }
 
 #[test]
-fn test_markdown_heading_hierarchy() {
-    let (storage, path_str) = index_lang_fixtures(markdown_fixtures_path());
+fn test_missing_grammar_library_falls_back_to_builtin() {
+    let dir = empty_dir();
+    std::fs::write(
+        dir.path().join("app.py"),
+        "def outer():\n    def inner():\n        pass\n",
+    )
+    .unwrap();
+    let queries = QueriesConfig {
+        languages: vec![QueryLanguageConfig {
+            name: "snake".to_string(),
+            grammar: Some("python".to_string()),
+            grammar_path: Some("grammars/python.so".to_string()),
+            extensions: vec!["py".to_string()],
+            filenames: vec![],
+            query: Some(
+                query_fixtures_path()
+                    .join("python.scm")
+                    .to_str()
+                    .unwrap()
+                    .to_string(),
+            ),
+        }],
+        ..QueriesConfig::default()
+    };
+
+    let path_str = dir.path().to_str().unwrap().to_string();
+    let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+    Indexer::new()
+        .index(
+            &path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::load(Path::new(&path_str), &queries),
+        )
+        .expect("A missing grammar should not fail indexing");
 
-    let symbols = storage
-        .get_file_symbols(&path_str, "sample.md")
-        .expect("get_file_symbols failed");
+    let overview = storage
+        .get_overview(&path_str)
+        .expect("get_overview failed");
+    assert_eq!(overview.languages, vec![("python".to_string(), 1)]);
+    let names: Vec<String> = storage
+        .get_file_symbols(&path_str, "app.py")
+        .expect("get_file_symbols failed")
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+    assert_eq!(names, vec!["app", "outer"]);
+}
 
-    // H1 "Project Overview" should be top-level
-    let overview = symbols
+/// Builds tree-sitter-json's generated parser into a shared library at `out`.
+#[cfg(unix)]
+fn build_json_grammar(out: &Path) {
+    let metadata = std::process::Command::new(env!("CARGO"))
+        .args(["metadata", "--format-version", "1", "--offline"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("cargo metadata failed");
+    let metadata: serde_json::Value =
+        serde_json::from_slice(&metadata.stdout).expect("cargo metadata output");
+    let manifest = metadata["packages"]
+        .as_array()
+        .unwrap()
         .iter()
-        .find(|s| s.name == "Project Overview")
-        .expect("Should find 'Project Overview'");
-    assert_eq!(overview.kind, "section");
-    assert!(
-        overview.parent_symbol_id.is_none(),
-        "H1 should be top-level (no parent)"
-    );
+        .find(|p| p["name"] == "tree-sitter-json")
+        .and_then(|p| p["manifest_path"].as_str())
+        .expect("tree-sitter-json should be a dependency");
+    let src = Path::new(manifest).parent().unwrap().join("src");
+
+    std::fs::create_dir_all(out.parent().unwrap()).unwrap();
+    let status = std::process::Command::new(std::env::var("CC").unwrap_or("cc".to_string()))
+        .args(["-shared", "-fPIC", "-I"])
+        .arg(&src)
+        .arg(src.join("parser.c"))
+        .arg("-o")
+        .arg(out)
+        .status()
+        .expect("A C compiler is needed to build the test grammar");
+    assert!(status.success(), "Building the JSON grammar failed");
+}
 
-    // H2 "Installation" should be a child of H1
-    let installation = symbols
-        .iter()
-        .find(|s| s.name == "Installation")
-        .expect("Should find 'Installation'");
+/// The opt-in and location checks are unit tests of the grammar loader; this one
+/// loads a real grammar end to end.
+#[test]
+#[cfg(unix)]
+#[ignore = "builds tree-sitter-json with `cargo metadata --offline` and a C compiler"]
+fn test_native_grammar_needs_opt_in_and_allowed_location() {
+    let outer = empty_dir();
+    let repo = outer.path().join("repo");
+    build_json_grammar(&outer.path().join("grammars/json.so"));
+    std::fs::create_dir_all(repo.join("grammars")).unwrap();
+    std::fs::copy(
+        outer.path().join("grammars/json.so"),
+        repo.join("grammars/json.so"),
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join("keys.scm"),
+        "(pair key: (string (string_content) @name)) @definition.key",
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join("settings.jsonc"),
+        r#"{"name": "demo", "port": 8080}"#,
+    )
+    .unwrap();
+
+    let path_str = repo.to_str().unwrap().to_string();
+    let keys_with = |grammar_path: &str, allow: bool, grammar_dirs: Vec<String>| {
+        let queries = QueriesConfig {
+            languages: vec![QueryLanguageConfig {
+                name: "jsonc".to_string(),
+                grammar: Some("json".to_string()),
+                grammar_path: Some(grammar_path.to_string()),
+                extensions: vec!["jsonc".to_string()],
+                filenames: vec![],
+                query: Some("keys.scm".to_string()),
+            }],
+            allow_native_grammars: allow,
+            grammar_dirs,
+            ..QueriesConfig::default()
+        };
+        let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
+        Indexer::new()
+            .index(
+                &path_str,
+                &storage,
+                &IndexerConfig::default(),
+                &QueryPacks::load(&repo, &queries),
+            )
+            .expect("Indexing failed");
+        storage
+            .get_file_symbols(&path_str, "settings.jsonc")
+            .expect("get_file_symbols failed")
+            .into_iter()
+            .map(|s| s.name)
+            .collect::<Vec<_>>()
+    };
+    let outside = outer.path().join("grammars").to_str().unwrap().to_string();
+
+    // Without the opt-in no extractor claims `.jsonc`
+    assert!(keys_with("grammars/json.so", false, vec![]).is_empty());
     assert_eq!(
-        installation.parent_symbol_id,
-        Some(overview.id),
-        "H2 'Installation' should be child of H1 'Project Overview'"
+        keys_with("grammars/json.so", true, vec![]),
+        ["name", "port"]
+    );
+    // Loaded once per process, so a second run reuses it
+    assert_eq!(
+        keys_with("grammars/json.so", true, vec![]),
+        ["name", "port"]
     );
 
-    // H3 "Prerequisites" should be a child of H2 "Installation"
-    let prereqs = symbols
-        .iter()
-        .find(|s| s.name == "Prerequisites")
-        .expect("Should find 'Prerequisites'");
+    // Outside the repo only from an allowed directory
+    assert!(keys_with("../grammars/json.so", true, vec![]).is_empty());
+    assert!(keys_with(&format!("{outside}/json.so"), true, vec![]).is_empty());
     assert_eq!(
-        prereqs.parent_symbol_id,
-        Some(installation.id),
-        "H3 'Prerequisites' should be child of H2 'Installation'"
+        keys_with("../grammars/json.so", true, vec![outside]),
+        ["name", "port"]
     );
 }
 
@@ -1102,13 +5608,18 @@ fn test_minified_files_skipped_by_default() {
     let indexer = Indexer::new();
 
     let stats = indexer
-        .index(path_str, &storage, &[], u64::MAX)
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("Indexing failed");
 
     // vendor.min.js exists in fixtures but should be skipped
     assert_eq!(
-        stats.files_total, 9,
-        "Should index 9 files (vendor.min.js should be skipped)"
+        stats.files_total, 11,
+        "Should index 11 files (vendor.min.js should be skipped)"
     );
 }
 
@@ -1119,15 +5630,18 @@ fn test_custom_ignore_patterns() {
     let storage = SqliteStorage::open_memory().expect("Failed to create in-memory DB");
     let indexer = Indexer::new();
 
-    let ignore = vec!["*.ts".to_string()];
+    let config = IndexerConfig {
+        ignore: vec!["*.ts".to_string(), "*.tsx".to_string()],
+        ..IndexerConfig::default()
+    };
     let stats = indexer
-        .index(path_str, &storage, &ignore, u64::MAX)
+        .index(path_str, &storage, &config, &QueryPacks::default())
         .expect("Indexing failed");
 
-    // All .ts files should be ignored, only .min.js remains (which is also skipped by default)
+    // All .ts/.tsx files should be ignored, only .min.js remains (which is also skipped by default)
     assert_eq!(
         stats.files_total, 0,
-        "All .ts files should be ignored by custom pattern"
+        "All .ts/.tsx files should be ignored by custom pattern"
     );
 }
 
@@ -1143,8 +5657,11 @@ fn test_update_files_skips_ignored() {
             &path_str,
             &["vendor.min.js".to_string(), "simple.ts".to_string()],
             &storage,
-            &["*.min.js".to_string()],
-            u64::MAX,
+            &IndexerConfig {
+                ignore: ["*.min.js".to_string()].to_vec(),
+                ..IndexerConfig::default()
+            },
+            &QueryPacks::default(),
         )
         .expect("update_files failed");
 
@@ -1594,6 +6111,367 @@ fn test_dotted_name_resolves_this_only() {
     );
 }
 
+// ==================== Scope-Aware Resolution Tests ====================
+
+#[test]
+fn test_resolution_prefers_file_then_imports_then_module() {
+    let (storage, path_str) = index_lang_fixtures(resolution_fixtures_path());
+
+    let calls_of = |file: &str, name: &str| -> Vec<(String, String, String)> {
+        let symbols = storage
+            .get_file_symbols(&path_str, file)
+            .expect("get_file_symbols failed");
+        let sym = symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"));
+        let mut calls: Vec<_> = storage
+            .get_dependencies(&path_str, sym.id)
+            .expect("get_dependencies failed")
+            .into_iter()
+            .filter(|r| r.ref_kind == "call")
+            .map(|r| {
+                let target = r
+                    .to_symbol_id
+                    .map(|id| storage.get_symbol_detail(&path_str, id).unwrap())
+                    .map(|t| format!("{}:{}", t.file_rel_path, t.start_line))
+                    .unwrap_or_default();
+                (r.to_name, target, r.resolution.unwrap_or_default())
+            })
+            .collect();
+        calls.sort();
+        calls
+    };
+    let call = |name: &str, target: &str, resolution: &str| {
+        (name.to_string(), target.to_string(), resolution.to_string())
+    };
+
+    // Every name here is defined more than once
+    assert_eq!(
+        calls_of("app/main.ts", "start"),
+        vec![
+            call("format", "app/main.ts:9", "file"),
+            call("helper", "app/helpers.ts:1", "module"),
+            call("run", "jobs/worker.ts:1", "import"),
+        ]
+    );
+
+    // Qualified calls bind to a member of the named type, `self.` calls to one of
+    // the caller's own
+    assert_eq!(
+        calls_of("tasks/shapes.rs", "origin"),
+        vec![call("Point::new", "tasks/shapes.rs:16", "file")]
+    );
+    assert_eq!(
+        calls_of("tasks/shapes.rs", "length"),
+        vec![call("self.norm", "tasks/shapes.rs:20", "file")]
+    );
+}
+
+#[test]
+fn test_resolution_confidence_and_uncertain_refs() {
+    let (storage, path_str) = index_lang_fixtures(resolution_fixtures_path());
+    let fmt = ctxhelpr::output::CompactFormatter::new(&ctxhelpr::config::OutputConfig::default());
+
+    let symbols = storage
+        .get_file_symbols(&path_str, "app/main.ts")
+        .expect("get_file_symbols failed");
+    let id_of = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("Should find '{name}'"))
+            .id
+    };
+
+    let deps = storage
+        .get_dependencies(&path_str, id_of("start"))
+        .expect("get_dependencies failed");
+    let output =
+        ctxhelpr::output::OutputFormatter::format_dependencies(&fmt, id_of("start"), &deps);
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let dep = |name: &str| {
+        parsed["deps"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|d| d["to_n"] == name && d["kind"] == "call")
+            .unwrap_or_else(|| panic!("Should depend on '{name}'"))
+            .clone()
+    };
+
+    // A same-file pick is certain; a same-module one less so
+    assert_eq!(dep("format")["via"], "file");
+    assert_eq!(dep("format")["conf"], "high");
+    assert_eq!(dep("format")["cands"], 2);
+    assert_eq!(dep("helper")["via"], "module");
+    assert_eq!(dep("helper")["conf"], "medium");
+    assert_eq!(dep("helper")["cands"], 2);
+    // A TypeScript named import keeps only the name, so both `run`s fit it
+    assert_eq!(dep("run")["via"], "import");
+    assert_eq!(dep("run")["conf"], "low");
+
+    // `schedule` is defined in two other directories, `teardown` nowhere
+    let uncertain = storage
+        .get_uncertain_refs(&path_str, None, Some("app/main.ts"), None)
+        .expect("get_uncertain_refs failed");
+    let names: Vec<_> = uncertain
+        .iter()
+        .map(|r| (r.to_name.as_str(), r.ref_kind.as_str()))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("run", "import"),
+            ("run", "call"),
+            ("schedule", "call"),
+            ("teardown", "call")
+        ]
+    );
+    assert_eq!(uncertain[2].confidence.as_deref(), Some("low"));
+    assert_eq!(uncertain[2].resolution.as_deref(), Some("repo"));
+    assert_eq!(uncertain[2].candidates, Some(2));
+    assert!(uncertain[3].to_symbol_id.is_none());
+
+    // Ambiguous and unresolved refs one kind at a time
+    let only = |only: storage::Uncertainty| -> Vec<(String, String)> {
+        storage
+            .get_uncertain_refs(&path_str, None, Some("app/main.ts"), Some(only))
+            .expect("get_uncertain_refs failed")
+            .into_iter()
+            .map(|r| (r.to_name, r.ref_kind))
+            .collect()
+    };
+    let pairs = |names: &[(&str, &str)]| -> Vec<(String, String)> {
+        names
+            .iter()
+            .map(|(n, k)| (n.to_string(), k.to_string()))
+            .collect()
+    };
+    assert_eq!(
+        only(storage::Uncertainty::Ambiguous),
+        pairs(&[("run", "import"), ("run", "call"), ("schedule", "call")])
+    );
+    assert_eq!(
+        only(storage::Uncertainty::Unresolved),
+        pairs(&[("teardown", "call")])
+    );
+
+    // By symbol, only the refs from inside it
+    let by_symbol = storage
+        .get_uncertain_refs(&path_str, Some(id_of("stop")), None, None)
+        .expect("get_uncertain_refs failed");
+    let names: Vec<_> = by_symbol.iter().map(|r| r.to_name.as_str()).collect();
+    assert_eq!(names, vec!["schedule", "teardown"]);
+
+    let output = ctxhelpr::output::OutputFormatter::format_uncertain_refs(
+        &fmt,
+        Some(id_of("stop")),
+        None,
+        &by_symbol,
+    );
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(parsed["id"], id_of("stop"));
+    assert_eq!(parsed["f"], "app/main.ts");
+    let refs = parsed["refs"].as_array().unwrap();
+    assert_eq!(refs[0]["to_n"], "schedule");
+    assert_eq!(refs[0]["from_n"], "stop");
+    assert_eq!(refs[0]["conf"], "low");
+    assert_eq!(refs[0]["cands"], 2);
+    assert_eq!(refs[1]["to_n"], "teardown");
+    assert_eq!(refs[1]["external"], true);
+    assert!(refs[1].get("fi").is_none());
+}
+
+#[test]
+fn test_resolution_ignores_data_keys_and_other_languages() {
+    let dir = empty_dir();
+    for (file, source) in [
+        (
+            "src/app.ts",
+            "export function start() {\n  return build();\n}\n",
+        ),
+        ("src/config.json", "{\"build\": {\"target\": \"es2020\"}}\n"),
+        ("lib/util.ts", "export function build() {\n  return 1;\n}\n"),
+        ("analysis.ipynb", &notebook_with_code("import runtime\n")),
+        ("Dockerfile", "FROM python:3.12 AS runtime\n"),
+    ] {
+        let path = dir.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, source).unwrap();
+    }
+    let (storage, path_str) = index_lang_fixtures(dir.path().to_path_buf());
+    let target = |file: &str, to_name: &str| {
+        let r = storage
+            .get_file_symbols(&path_str, file)
+            .expect("get_file_symbols failed")
+            .iter()
+            .flat_map(|s| storage.get_dependencies(&path_str, s.id).unwrap())
+            .find(|r| r.to_name == to_name)
+            .unwrap_or_else(|| panic!("{file} should refer to {to_name}"));
+        let file = r.to_symbol_id.map(|id| {
+            storage
+                .get_symbol_detail(&path_str, id)
+                .expect("get_symbol_detail failed")
+                .file_rel_path
+        });
+        (file, r.resolution)
+    };
+
+    // The JSON key next to the caller isn't a function
+    assert_eq!(
+        target("src/app.ts", "build"),
+        (Some("lib/util.ts".to_string()), Some("repo".to_string()))
+    );
+    // A Dockerfile stage isn't a Python module
+    assert_eq!(target("analysis.ipynb", "runtime"), (None, None));
+}
+
+fn notebook_with_code(code: &str) -> String {
+    serde_json::json!({
+        "cells": [{"cell_type": "code", "source": code, "metadata": {}, "outputs": []}],
+        "metadata": {},
+        "nbformat": 4,
+        "nbformat_minor": 5
+    })
+    .to_string()
+}
+
+#[test]
+fn test_update_files_reranks_refs_to_added_and_removed_symbols() {
+    let dir = empty_dir();
+    for (file, source) in [
+        (
+            "app/main.ts",
+            "export function run() {\n  return format(1);\n}\n",
+        ),
+        (
+            "lib/format.ts",
+            "export function format(n: number) {\n  return String(n);\n}\n",
+        ),
+    ] {
+        let path = dir.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, source).unwrap();
+    }
+    let (storage, path_str) = index_lang_fixtures(dir.path().to_path_buf());
+    let update = |file: &str, source: &str| {
+        std::fs::write(dir.path().join(file), source).unwrap();
+        Indexer::new()
+            .update_files(
+                &path_str,
+                &[file.to_string()],
+                &storage,
+                &IndexerConfig::default(),
+                &QueryPacks::default(),
+            )
+            .expect("update_files failed");
+    };
+    let format_call = || {
+        let symbols = storage
+            .get_file_symbols(&path_str, "app/main.ts")
+            .expect("get_file_symbols failed");
+        let run = symbols.iter().find(|s| s.name == "run").unwrap();
+        let r = storage
+            .get_dependencies(&path_str, run.id)
+            .expect("get_dependencies failed")
+            .into_iter()
+            .find(|r| r.to_name == "format" && r.ref_kind == "call")
+            .expect("run should call format");
+        let file = r.to_symbol_id.map(|id| {
+            storage
+                .get_symbol_detail(&path_str, id)
+                .expect("get_symbol_detail failed")
+                .file_rel_path
+        });
+        (file, r.resolution, r.confidence, r.candidates)
+    };
+    let expect = |file: &str, via: &str, conf: &str, cands: i64| {
+        (
+            Some(file.to_string()),
+            Some(via.to_string()),
+            Some(conf.to_string()),
+            Some(cands),
+        )
+    };
+
+    assert_eq!(format_call(), expect("lib/format.ts", "repo", "high", 1));
+
+    // A definition next to the caller wins, though the caller didn't change
+    update(
+        "app/util.ts",
+        "export function format(n: number) {\n  return `${n}`;\n}\n",
+    );
+    assert_eq!(format_call(), expect("app/util.ts", "module", "medium", 2));
+
+    // A same-file definition shadows both
+    update(
+        "app/main.ts",
+        "function format(n: number) {\n  return n;\n}\nexport function run() {\n  return format(1);\n}\n",
+    );
+    assert_eq!(format_call(), expect("app/main.ts", "file", "high", 3));
+
+    // Removing it, and then the module's, falls back each time
+    update(
+        "app/main.ts",
+        "export function run() {\n  return format(1);\n}\n",
+    );
+    assert_eq!(format_call(), expect("app/util.ts", "module", "medium", 2));
+    storage
+        .delete_files_by_rel_paths(&path_str, &["app/util.ts".to_string()])
+        .expect("delete_files_by_rel_paths failed");
+    assert_eq!(format_call(), expect("lib/format.ts", "repo", "high", 1));
+}
+
+#[test]
+fn test_python_import_and_doc_link_candidates() {
+    let dir = empty_dir();
+    for (file, source) in [
+        ("main.py", "from pkg.util import helper\n"),
+        ("a/pkg/util.py", "def helper():\n    pass\n"),
+        ("b/pkg/util.py", "def helper():\n    pass\n"),
+        (
+            "c/tools.py",
+            "def first():\n    pass\n\ndef second():\n    pass\n",
+        ),
+        ("README.md", "# Readme\n\nSee [the tools](c/tools.py).\n"),
+    ] {
+        let path = dir.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, source).unwrap();
+    }
+    let (storage, path_str) = index_lang_fixtures(dir.path().to_path_buf());
+    let refs_from = |file: &str, kind: &str| {
+        storage
+            .get_file_symbols(&path_str, file)
+            .expect("get_file_symbols failed")
+            .iter()
+            .flat_map(|s| storage.get_dependencies(&path_str, s.id).unwrap())
+            .filter(|r| r.ref_kind == kind)
+            .map(|r| (r.to_name, r.resolution, r.confidence, r.candidates))
+            .collect::<Vec<_>>()
+    };
+    let ref_info = |name: &str, conf: &str, cands: i64| {
+        (
+            name.to_string(),
+            Some("import".to_string()),
+            Some(conf.to_string()),
+            Some(cands),
+        )
+    };
+
+    // Both `util` modules fit `pkg.util` equally well
+    assert_eq!(
+        refs_from("main.py", "import"),
+        vec![ref_info("pkg.util.helper", "low", 2)]
+    );
+    // A link means its file; each of the file's top-level symbols is a candidate
+    assert_eq!(
+        refs_from("README.md", "mention"),
+        vec![ref_info("c/tools.py", "high", 3)]
+    );
+}
+
 // ==================== Auto-Index Helper Tests ====================
 
 #[test]
@@ -1670,7 +6548,12 @@ fn test_gitignore_respected() {
     let path_str = root.to_str().unwrap();
 
     let stats = indexer
-        .index(path_str, &storage, &[], u64::MAX)
+        .index(
+            path_str,
+            &storage,
+            &IndexerConfig::default(),
+            &QueryPacks::default(),
+        )
         .expect("Indexing failed");
 
     // Should only index the tracked file, not the gitignored one